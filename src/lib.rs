@@ -1,3 +1,42 @@
 mod ndarray;
 
+// Links the BLAS implementation picked with the `openblas-*` features
+#[cfg(any(feature = "openblas-static", feature = "openblas-system"))]
+extern crate blas_src;
+
 pub use ndarray::*;
+
+pub use num_complex;
+
+#[cfg(feature = "allocator")]
+pub use allocator_api2;
+#[cfg(feature = "approx")]
+pub use approx;
+#[cfg(feature = "arrow")]
+pub use arrow;
+#[cfg(feature = "bytemuck")]
+pub use bytemuck;
+#[cfg(feature = "half")]
+pub use half;
+#[cfg(feature = "hdf5")]
+pub use hdf5_pure;
+#[cfg(feature = "image")]
+pub use image;
+#[cfg(feature = "nalgebra")]
+pub use nalgebra;
+#[cfg(feature = "numpy")]
+pub use numpy;
+#[cfg(any(feature = "numpy", feature = "dlpack"))]
+pub use pyo3;
+#[cfg(feature = "rand")]
+pub use rand;
+#[cfg(feature = "rand")]
+pub use rand_distr;
+#[cfg(feature = "rayon")]
+pub use rayon;
+#[cfg(feature = "safetensors")]
+pub use safetensors;
+#[cfg(feature = "gpu")]
+pub use wgpu;
+#[cfg(feature = "simd")]
+pub use wide;
@@ -1,84 +1,327 @@
-use std::{
-    borrow::Cow,
-    ops::{Index, Range},
-};
-
-use crate::Array;
-
-impl<'a, T: Clone, const D: usize> Array<'a, T, D> {
-    pub fn slice(&'a self, slice: &[Range<usize>; D]) -> Array<'a, T, D> {
-        let mut shape = self.shape.clone();
-        let strides = self.strides.clone();
-        let mut idx_maps = self.idx_maps.clone();
-
-        slice.iter().enumerate().for_each(|(axis, range)| {
-            if range.end > self.shape[axis] {
-                panic!(
-                    "Range: [{},{}) is out of bounds for axis: {}",
-                    range.start, range.end, axis
-                )
-            }
-        });
+use std::ops::{Index, IndexMut};
+
+use arrayvec::ArrayVec;
+
+use crate::{ArrayBase, IndexError, Slice, SliceError, Storage, StorageMut, View, ViewMut};
+
+impl<T, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn slice<I: Into<Slice> + Clone>(&self, slice: &[I; D]) -> View<'_, T, D> {
+        self.view().slice_move(slice)
+    }
+
+    pub fn slice_axis<I: Into<Slice>>(&self, axis: usize, slice: I) -> View<'_, T, D> {
+        self.view().slice_axis_move(axis, slice)
+    }
+
+    pub fn try_slice<I: Into<Slice> + Clone>(
+        &self,
+        slice: &[I; D],
+    ) -> Result<View<'_, T, D>, SliceError> {
+        self.view().try_slice_move(slice)
+    }
+
+    pub fn try_slice_axis<I: Into<Slice>>(
+        &self,
+        axis: usize,
+        slice: I,
+    ) -> Result<View<'_, T, D>, SliceError> {
+        self.view().try_slice_axis_move(axis, slice)
+    }
+
+    pub fn split_at(&self, axis: usize, index: usize) -> (View<'_, T, D>, View<'_, T, D>) {
+        if axis >= D {
+            panic!("Axis out of bound: {} >= {}", axis, D);
+        }
+
+        if index > self.shape[axis] {
+            panic!(
+                "Index out of bounds for axis {}: {} > {}",
+                axis, index, self.shape[axis]
+            );
+        }
+
+        (
+            self.slice_axis(axis, 0..index),
+            self.slice_axis(axis, index..self.shape[axis]),
+        )
+    }
 
-        for axis in 0..D {
-            idx_maps[axis].append_b((slice[axis].start) as isize);
-            shape[axis] = slice[axis].end - slice[axis].start;
+    pub fn split(&self, axis: usize, n: usize) -> Vec<View<'_, T, D>> {
+        if axis >= D {
+            panic!("Axis out of bound: {} >= {}", axis, D);
         }
 
-        Array {
-            vec: Cow::from(&*self.vec),
-            shape,
-            strides,
-            idx_maps,
+        if n == 0 {
+            panic!("Number of parts must be greater than zero");
         }
+
+        // The first `len % n` parts get one element more than the rest
+        let len = self.shape[axis];
+        let (part_len, remainder) = (len / n, len % n);
+
+        let mut start = 0;
+        (0..n)
+            .map(|part| {
+                let end = start + part_len + if part < remainder { 1 } else { 0 };
+                let view = self.slice_axis(axis, start..end);
+
+                start = end;
+
+                view
+            })
+            .collect()
     }
 
     pub fn get(&self, indices: [usize; D]) -> Option<&T> {
-        if indices
+        self.try_get(indices).ok()
+    }
+
+    pub fn try_get(&self, indices: [usize; D]) -> Result<&T, IndexError> {
+        self.check_index(&indices)?;
+
+        Ok(&self.data.as_slice()[self.offset_of(&indices)])
+    }
+
+    /// # Safety
+    ///
+    /// Every index must be smaller than the length of its axis.
+    pub unsafe fn get_unchecked(&self, indices: [usize; D]) -> &T {
+        debug_assert!(self.is_in_bounds(&indices));
+
+        self.data.as_slice().get_unchecked(self.offset_of(&indices))
+    }
+}
+
+impl<T, S: StorageMut<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn slice_mut<I: Into<Slice> + Clone>(&mut self, slice: &[I; D]) -> ViewMut<'_, T, D> {
+        self.view_mut().slice_move(slice)
+    }
+
+    pub fn slice_axis_mut<I: Into<Slice>>(&mut self, axis: usize, slice: I) -> ViewMut<'_, T, D> {
+        self.view_mut().slice_axis_move(axis, slice)
+    }
+
+    pub fn get_mut(&mut self, indices: [usize; D]) -> Option<&mut T> {
+        self.try_get_mut(indices).ok()
+    }
+
+    pub fn try_get_mut(&mut self, indices: [usize; D]) -> Result<&mut T, IndexError> {
+        self.check_index(&indices)?;
+
+        let offset = self.offset_of(&indices);
+
+        Ok(&mut self.data.as_mut_slice()[offset])
+    }
+
+    /// # Safety
+    ///
+    /// Every index must be smaller than the length of its axis.
+    pub unsafe fn get_unchecked_mut(&mut self, indices: [usize; D]) -> &mut T {
+        debug_assert!(self.is_in_bounds(&indices));
+
+        let offset = self.offset_of(&indices);
+
+        self.data.as_mut_slice().get_unchecked_mut(offset)
+    }
+
+    pub fn multi_slice_mut<I: Into<Slice> + Clone, const N: usize>(
+        &mut self,
+        slices: [&[I; D]; N],
+    ) -> [ViewMut<'_, T, D>; N] {
+        // Only the layouts are sliced until the regions are known to be disjoint
+        let layouts: ArrayVec<ArrayBase<(), D>, N> = IntoIterator::into_iter(slices)
+            .map(|slice| {
+                ArrayBase {
+                    data: (),
+                    shape: self.shape,
+                    strides: self.strides,
+                    offset: self.offset,
+                }
+                .slice_move(slice)
+            })
+            .collect();
+
+        // Positions of the first and last element of each view in the buffer
+        let spans: ArrayVec<Option<(usize, usize)>, N> = layouts
             .iter()
-            .enumerate()
-            .any(|(axis, idx)| *idx >= self.shape[axis])
-        {
-            return None;
+            .map(|layout| {
+                if layout.shape.contains(&0) {
+                    return None;
+                }
+
+                let (mut first, mut last) = (layout.offset as isize, layout.offset as isize);
+                for (&len, &stride) in layout.shape.iter().zip(&layout.strides) {
+                    let extent = (len - 1) as isize * stride;
+
+                    if extent < 0 {
+                        first += extent;
+                    } else {
+                        last += extent;
+                    }
+                }
+
+                Some((first as usize, last as usize))
+            })
+            .collect();
+
+        // Every view gets its own part of the buffer, so the views may not interleave in
+        // memory, like the column blocks of a row-major array do, even if they share no
+        // element
+        for (view, span) in spans.iter().enumerate() {
+            for (other, other_span) in spans.iter().enumerate().skip(view + 1) {
+                if let (Some((first, last)), Some((other_first, other_last))) = (span, other_span) {
+                    if first <= other_last && other_first <= last {
+                        panic!(
+                            "Slices {} and {} overlap or interleave in memory and can not be borrowed mutably at once",
+                            view, other
+                        );
+                    }
+                }
+            }
         }
 
-        let index = indices
-            .iter()
-            .enumerate()
-            .fold(0, |acc, (axis, axis_index)| {
-                acc + self.idx_maps[axis].map(*axis_index) * self.strides[axis]
-            });
+        let ptr = self.data.as_mut_slice().as_mut_ptr();
+
+        let views: ArrayVec<_, N> = layouts
+            .into_iter()
+            .zip(spans.iter())
+            .map(|(layout, span)| {
+                // Each view borrows the span between its first and last element
+                let (first, len, offset) = match *span {
+                    Some((first, last)) => (first, last - first + 1, layout.offset - first),
+                    None => (0, 0, 0),
+                };
+
+                // SAFETY: every span lies inside the data of `self`, which stays
+                // mutably borrowed for as long as the views live, and no two spans
+                // share a position of the buffer.
+                let data = unsafe { std::slice::from_raw_parts_mut(ptr.add(first), len) };
+
+                ArrayBase {
+                    data,
+                    shape: layout.shape,
+                    strides: layout.strides,
+                    offset,
+                }
+            })
+            .collect();
+
+        views.into_inner().unwrap_or_else(|_| unreachable!())
+    }
+}
+
+impl<S, const D: usize> ArrayBase<S, D> {
+    // Reports the first axis whose index is out of bounds
+    fn check_index(&self, indices: &[usize; D]) -> Result<(), IndexError> {
+        match (0..D).find(|&axis| indices[axis] >= self.shape[axis]) {
+            Some(axis) => Err(IndexError {
+                axis,
+                index: indices[axis],
+                len: self.shape[axis],
+            }),
+            None => Ok(()),
+        }
+    }
+
+    pub fn slice_move<I: Into<Slice> + Clone>(self, slice: &[I; D]) -> Self {
+        self.try_slice_move(slice)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    pub fn slice_axis_move<I: Into<Slice>>(self, axis: usize, slice: I) -> Self {
+        self.try_slice_axis_move(axis, slice)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    pub fn try_slice_move<I: Into<Slice> + Clone>(
+        mut self,
+        slice: &[I; D],
+    ) -> Result<Self, SliceError> {
+        for (axis, axis_slice) in slice.iter().enumerate() {
+            self = self.try_slice_axis_move(axis, axis_slice.clone())?;
+        }
+
+        Ok(self)
+    }
 
-        self.vec.get(index)
+    pub fn try_slice_axis_move<I: Into<Slice>>(
+        mut self,
+        axis: usize,
+        slice: I,
+    ) -> Result<Self, SliceError> {
+        if axis >= D {
+            return Err(SliceError::AxisOutOfBounds { axis, ndim: D });
+        }
+
+        let (start, len, step) = resolve_slice(axis, slice.into(), self.shape[axis])?;
+
+        if len > 0 {
+            self.offset = (self.offset as isize + start as isize * self.strides[axis]) as usize;
+        }
+
+        self.shape[axis] = len;
+        self.strides[axis] *= step as isize;
+
+        Ok(self)
     }
 }
 
-impl<'a, T: Clone, const D: usize> Index<[usize; D]> for Array<'a, T, D> {
+// The first index, the number of indices and the step of `slice` along an axis of `len`
+pub(crate) fn resolve_slice(
+    axis: usize,
+    slice: Slice,
+    len: usize,
+) -> Result<(usize, usize, usize), SliceError> {
+    let bounds = slice
+        .start
+        .resolve(len)
+        .zip(slice.end.map_or(Some(len), |end| end.resolve(len)));
+
+    let (start, end) = match bounds {
+        Some((start, end)) if start <= end && end <= len => (start, end),
+        _ => return Err(SliceError::OutOfBounds { axis, slice, len }),
+    };
+
+    if slice.step == 0 {
+        return Err(SliceError::ZeroStep { axis });
+    }
+
+    Ok((start, (end - start).div_ceil(slice.step), slice.step))
+}
+
+impl<T, S: Storage<Elem = T>, const D: usize> Index<[usize; D]> for ArrayBase<S, D> {
     type Output = T;
 
     fn index(&self, indices: [usize; D]) -> &Self::Output {
-        if indices
-            .iter()
-            .enumerate()
-            .any(|(axis, idx)| *idx >= self.shape[axis])
-        {
-            panic!("Index out of bound");
+        if !self.is_in_bounds(&indices) {
+            panic!(
+                "Index {:?} is out of bounds for array of shape {:?}",
+                indices, self.shape
+            );
         }
 
-        let index = indices
-            .iter()
-            .enumerate()
-            .fold(0, |acc, (axis, axis_index)| {
-                acc + self.idx_maps[axis].map(*axis_index) * self.strides[axis]
-            });
+        &self.data.as_slice()[self.offset_of(&indices)]
+    }
+}
+
+impl<T, S: StorageMut<Elem = T>, const D: usize> IndexMut<[usize; D]> for ArrayBase<S, D> {
+    fn index_mut(&mut self, indices: [usize; D]) -> &mut Self::Output {
+        if !self.is_in_bounds(&indices) {
+            panic!(
+                "Index {:?} is out of bounds for array of shape {:?}",
+                indices, self.shape
+            );
+        }
+
+        let offset = self.offset_of(&indices);
 
-        &self.vec[index]
+        &mut self.data.as_mut_slice()[offset]
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use crate::{s, Array, IndexError, Slice, SliceBound, SliceError};
 
     #[test]
     fn index_array() {
@@ -95,6 +338,102 @@ mod tests {
         assert_eq!(array[[1, 2]], 6);
     }
 
+    #[test]
+    fn index_mut_array() {
+        let mut array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        array[[1, 0]] = 40;
+        array[[0, 2]] *= 10;
+
+        let mut view = array.slice_mut(&[Slice::from(..), Slice::from(1..).step_by(2)]);
+        view[[1, 0]] = 50;
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 2, 30, 40, 50, 6]
+        );
+    }
+
+    #[test]
+    fn get_and_get_mut() {
+        let mut array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        assert_eq!(array.get([1, 1]), Some(&5));
+        assert_eq!(array.get([1, 3]), None);
+
+        *array.get_mut([0, 1]).unwrap() = 20;
+
+        assert_eq!(array[[0, 1]], 20);
+        assert!(array.get_mut([2, 0]).is_none());
+    }
+
+    #[test]
+    fn get_unchecked() {
+        // 1 2 3
+        // 4 5 6
+        let mut array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+        let mut sum = 0;
+
+        for i in 0..2 {
+            for j in 0..3 {
+                // SAFETY: `i` and `j` are within the shape of the array
+                unsafe {
+                    sum += *array.get_unchecked([i, j]);
+                    *array.get_unchecked_mut([i, j]) *= 2;
+                }
+            }
+        }
+
+        let flipped = array.flip(1);
+
+        assert_eq!(sum, 21);
+        // SAFETY: [1, 0] is within the shape of the view
+        assert_eq!(unsafe { *flipped.get_unchecked([1, 0]) }, 12);
+    }
+
+    #[test]
+    fn try_get() {
+        let mut array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        assert_eq!(array.try_get([0, 2]), Ok(&3));
+        assert_eq!(
+            array.try_get([1, 7]),
+            Err(IndexError {
+                axis: 1,
+                index: 7,
+                len: 3
+            })
+        );
+        assert_eq!(
+            array.try_get_mut([4, 7]).err(),
+            Some(IndexError {
+                axis: 0,
+                index: 4,
+                len: 2
+            })
+        );
+        assert_eq!(
+            array.try_get([4, 7]).unwrap_err().to_string(),
+            "Index 4 is out of bounds for axis 0 of length 2"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Index [2, 1] is out of bounds for array of shape [2, 3]")]
+    fn index_out_of_bounds() {
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        let _ = array[[2, 1]];
+    }
+
+    #[test]
+    #[should_panic(expected = "Index [0, 3] is out of bounds for array of shape [2, 3]")]
+    fn index_mut_out_of_bounds() {
+        let mut array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        array[[0, 3]] = 0;
+    }
+
     #[test]
     fn slicing() {
         // 2-D array:
@@ -128,4 +467,312 @@ mod tests {
             vec![11, 10, 7, 6]
         );
     }
+
+    #[test]
+    fn slice_axis() {
+        // 2-D array:
+        // 1 2 3
+        // 4 5 6
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        let columns = array.slice_axis(1, 1..3);
+
+        assert_eq!(columns.shape(), &[2, 2]);
+        assert_eq!(
+            columns.flat().copied().collect::<Vec<usize>>(),
+            vec![2, 3, 5, 6]
+        );
+    }
+
+    #[test]
+    fn split_at() {
+        // 2-D array:
+        // 1  2  3  4
+        // 5  6  7  8
+        // 9 10 11 12
+        let array = Array::init((1..13).collect(), [3, 4]);
+
+        let (top, bottom) = array.split_at(0, 1);
+
+        assert_eq!(top.shape(), &[1, 4]);
+        assert_eq!(bottom.shape(), &[2, 4]);
+        assert_eq!(
+            bottom.flat().copied().collect::<Vec<usize>>(),
+            vec![5, 6, 7, 8, 9, 10, 11, 12]
+        );
+
+        let flipped = array.flip(1);
+        let (left, right) = flipped.split_at(1, 3);
+
+        assert_eq!(
+            left.flat().copied().collect::<Vec<usize>>(),
+            vec![4, 3, 2, 8, 7, 6, 12, 11, 10]
+        );
+        assert_eq!(right.flat().copied().collect::<Vec<usize>>(), vec![1, 5, 9]);
+
+        let (empty, all) = array.split_at(1, 0);
+
+        assert_eq!(empty.flat().len(), 0);
+        assert_eq!(all.flat().len(), 12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_at_out_of_bounds() {
+        let array = Array::init((1..13).collect::<Vec<usize>>(), [3, 4]);
+
+        array.split_at(0, 4);
+    }
+
+    #[test]
+    fn split() {
+        let array = Array::arange(0..10);
+
+        let parts = array.split(0, 3);
+
+        assert_eq!(
+            parts
+                .iter()
+                .map(|part| part.flat().copied().collect())
+                .collect::<Vec<Vec<usize>>>(),
+            vec![vec![0, 1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]
+        );
+
+        let parts = array.split(0, 12);
+
+        assert_eq!(parts.len(), 12);
+        assert_eq!(parts[9][[0]], 9);
+        assert_eq!(parts[10].shape(), &[0]);
+    }
+
+    #[test]
+    fn slicing_with_step() {
+        let array = Array::arange(0..10);
+
+        let even = array.slice(&[Slice::from(0..10).step_by(2)]);
+
+        assert_eq!(even.shape(), &[5]);
+        assert_eq!(even.strides(), &[2]);
+        assert_eq!(
+            even.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 2, 4, 6, 8]
+        );
+        assert_eq!(
+            even.flat().rev().copied().collect::<Vec<usize>>(),
+            vec![8, 6, 4, 2, 0]
+        );
+
+        let odd = array.slice_axis(0, Slice::new(SliceBound::Start(1), None, 3));
+
+        assert_eq!(odd.flat().copied().collect::<Vec<usize>>(), vec![1, 4, 7]);
+        assert_eq!(
+            array
+                .slice_axis(0, Slice::new(4.into(), Some(5.into()), 10))
+                .shape(),
+            &[1]
+        );
+        assert_eq!(
+            array
+                .slice_axis(0, Slice::new(5.into(), Some(5.into()), 2))
+                .shape(),
+            &[0]
+        );
+    }
+
+    #[test]
+    fn slicing_with_step_2d() {
+        // 0  1  2  3
+        // 4  5  6  7
+        // 8  9 10 11
+        let array = Array::init((0..12).collect(), [3, 4]);
+
+        let flipped = array.flip(1);
+        let view = flipped.slice(&[Slice::from(..).step_by(2), Slice::from(..).step_by(2)]);
+
+        assert_eq!(view.strides(), &[8, -2]);
+        assert_eq!(
+            view.flat().copied().collect::<Vec<usize>>(),
+            vec![3, 1, 11, 9]
+        );
+
+        // stepping twice multiplies the steps
+        let every_fourth = view.slice_axis(1, Slice::from(..).step_by(2));
+        assert_eq!(
+            every_fourth.flat().copied().collect::<Vec<usize>>(),
+            vec![3, 11]
+        );
+
+        let reshaped = view.to_owned();
+        assert_eq!(reshaped.strides(), &[2, 1]);
+        assert_eq!(view.sum(), 24);
+    }
+
+    #[test]
+    fn slice_move_with_step() {
+        let mut array = Array::arange(0..6).slice_move(&[Slice::from(1..).step_by(2)]);
+
+        for val in array.flat_mut() {
+            *val *= 10;
+        }
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![10, 30, 50]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn slicing_with_zero_step() {
+        let array = Array::arange(0..10);
+
+        array.slice(&[Slice::from(..).step_by(0)]);
+    }
+
+    #[test]
+    fn slicing_from_end() {
+        // 0  1  2
+        // 3  4  5
+        // 6  7  8
+        // 9 10 11
+        let array = Array::init((0..12).collect(), [4, 3]);
+
+        let last_rows = array.slice(&[Slice::last(3), Slice::from(..)]);
+
+        assert_eq!(last_rows.shape(), &[3, 3]);
+        assert_eq!(last_rows[[0, 0]], 3);
+
+        // all but the last column, every other row counted from the second to last
+        let view = array.slice(&[
+            Slice::new(SliceBound::End(2), None, 1),
+            Slice::new(0.into(), Some(SliceBound::End(1)), 1),
+        ]);
+
+        assert_eq!(
+            view.flat().copied().collect::<Vec<usize>>(),
+            vec![6, 7, 9, 10]
+        );
+        assert_eq!(
+            array.slice(&[Slice::last(4), Slice::last(0)]).shape(),
+            &[4, 0]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn slicing_from_end_out_of_bounds() {
+        let array = Array::init((0..12).collect::<Vec<usize>>(), [4, 3]);
+
+        array.slice(&[Slice::last(5), Slice::from(..)]);
+    }
+
+    #[test]
+    fn slice_mut() {
+        // 0  1  2  3
+        // 4  5  6  7
+        // 8  9 10 11
+        let mut array = Array::init((0..12).collect(), [3, 4]);
+
+        let mut view = array.slice_mut(&crate::s![..2, 1..;2]);
+
+        assert_eq!(view.shape(), &[2, 2]);
+        view.map_inplace(|x| x * 100);
+
+        view.slice_axis_mut(0, 1..).flat_mut().for_each(|x| *x += 1);
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 100, 2, 300, 4, 501, 6, 701, 8, 9, 10, 11]
+        );
+    }
+
+    #[test]
+    fn multi_slice_mut() {
+        // 0  1  2  3
+        // 4  5  6  7
+        // 8  9 10 11
+        let mut array = Array::init((0..12).collect(), [3, 4]);
+
+        let [mut top, mut middle, mut bottom, empty] =
+            array.multi_slice_mut([&s![..1, ..], &s![1..2, 1..;2], &s![2.., ..], &s![1..1, ..]]);
+
+        top.map_inplace(|x| x * 10);
+        middle -= 1;
+        bottom.flat_mut().for_each(|x| *x = 0);
+
+        assert_eq!(middle.shape(), &[1, 2]);
+        assert_eq!(empty.shape(), &[0, 4]);
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 10, 20, 30, 4, 4, 6, 6, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Slices 0 and 1 overlap or interleave")]
+    fn multi_slice_mut_interleaved() {
+        let mut array = Array::init((0..6).collect::<Vec<usize>>(), [6]);
+
+        array.multi_slice_mut([&s![..;2], &s![1..;2]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Slices 0 and 1 overlap or interleave")]
+    fn multi_slice_mut_column_blocks() {
+        let mut array = Array::init((0..12).collect::<Vec<usize>>(), [3, 4]);
+
+        array.multi_slice_mut([&s![.., ..2], &s![.., 2..]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Slices 0 and 1 overlap")]
+    fn multi_slice_mut_overlapping() {
+        let mut array = Array::init((0..12).collect::<Vec<usize>>(), [3, 4]);
+
+        array.multi_slice_mut([&s![..2, ..], &s![1.., 1..2]]);
+    }
+
+    #[test]
+    fn try_slice() {
+        let array = Array::init((0..12).collect::<Vec<usize>>(), [3, 4]);
+
+        let view = array.try_slice(&s![1.., ..;2]).unwrap();
+
+        assert_eq!(
+            view.flat().copied().collect::<Vec<usize>>(),
+            vec![4, 6, 8, 10]
+        );
+        assert_eq!(
+            array.try_slice(&s![..2, 1..5]).err(),
+            Some(SliceError::OutOfBounds {
+                axis: 1,
+                slice: Slice::from(1..5),
+                len: 4
+            })
+        );
+        assert_eq!(
+            array.try_slice(&[Slice::last(4), Slice::from(..)]).err(),
+            Some(SliceError::OutOfBounds {
+                axis: 0,
+                slice: Slice::last(4),
+                len: 3
+            })
+        );
+        assert_eq!(
+            array.try_slice(&s![.., ..;0]).err(),
+            Some(SliceError::ZeroStep { axis: 1 })
+        );
+        assert_eq!(
+            array.try_slice_axis(2, ..).err(),
+            Some(SliceError::AxisOutOfBounds { axis: 2, ndim: 2 })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "is out of bounds for axis 1 of length 4")]
+    fn slice_out_of_bounds() {
+        let array = Array::init((0..12).collect::<Vec<usize>>(), [3, 4]);
+
+        array.slice(&[0..3, 2..5]);
+    }
 }
@@ -0,0 +1,162 @@
+use allocator_api2::alloc::Allocator;
+use allocator_api2::vec::Vec;
+use num_traits::{One, Zero};
+
+use super::iter::Indices;
+use super::{checked_len, default_strides, len_of};
+use crate::{ArrayBase, ShapeError, Storage, StorageMut};
+
+impl<T, A: Allocator> Storage for Vec<T, A> {
+    type Elem = T;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T, A: Allocator> StorageMut for Vec<T, A> {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+// An owned array whose elements are allocated by `A`, such as an arena or an allocator of
+// pinned or hugepage-backed memory
+pub type ArrayIn<T, A, const D: usize> = ArrayBase<Vec<T, A>, D>;
+
+impl<T, A: Allocator, const D: usize> ArrayIn<T, A, D> {
+    pub fn init_in(vec: Vec<T, A>, shape: [usize; D]) -> Self {
+        Self::try_init_in(vec, shape).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    pub fn try_init_in(vec: Vec<T, A>, shape: [usize; D]) -> Result<Self, ShapeError> {
+        let expected = checked_len(&shape)?;
+
+        if expected != vec.len() {
+            return Err(ShapeError::IncompatibleSize {
+                len: vec.len(),
+                expected,
+            });
+        }
+
+        Ok(ArrayBase {
+            data: vec,
+            shape,
+            strides: default_strides(&shape),
+            offset: 0,
+        })
+    }
+
+    pub fn from_shape_fn_in<F: FnMut([usize; D]) -> T>(
+        shape: [usize; D],
+        mut f: F,
+        alloc: A,
+    ) -> Self {
+        let mut vec = Vec::with_capacity_in(len_of(&shape), alloc);
+
+        let mut indices = Indices::init(shape);
+        while let Some(idx) = indices.next_front() {
+            vec.push(f(idx));
+        }
+
+        ArrayIn::init_in(vec, shape)
+    }
+
+    pub fn allocator(&self) -> &A {
+        self.data.allocator()
+    }
+}
+
+impl<T: Clone, A: Allocator, const D: usize> ArrayIn<T, A, D> {
+    pub fn full_in(val: T, shape: [usize; D], alloc: A) -> Self {
+        let len = len_of(&shape);
+
+        let mut vec = Vec::with_capacity_in(len, alloc);
+        vec.resize(len, val);
+
+        ArrayIn::init_in(vec, shape)
+    }
+}
+
+impl<T: Clone + Zero, A: Allocator, const D: usize> ArrayIn<T, A, D> {
+    pub fn zeros_in(shape: [usize; D], alloc: A) -> Self {
+        ArrayIn::full_in(T::zero(), shape, alloc)
+    }
+}
+
+impl<T: Clone + One, A: Allocator, const D: usize> ArrayIn<T, A, D> {
+    pub fn ones_in(shape: [usize; D], alloc: A) -> Self {
+        ArrayIn::full_in(T::one(), shape, alloc)
+    }
+}
+
+impl<T: Clone, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    // Copies the elements in row-major order into memory of `alloc`
+    pub fn to_owned_in<A: Allocator>(&self, alloc: A) -> ArrayIn<T, A, D> {
+        let mut vec = Vec::with_capacity_in(len_of(&self.shape), alloc);
+        vec.extend(self.flat().cloned());
+
+        ArrayIn::init_in(vec, self.shape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::alloc::Layout;
+    use std::cell::Cell;
+    use std::ptr::NonNull;
+
+    use allocator_api2::alloc::{AllocError, Global};
+
+    use super::*;
+    use crate::{s, Array};
+
+    // Counts the bytes it hands out
+    struct Counting<'a>(&'a Cell<usize>);
+
+    unsafe impl Allocator for Counting<'_> {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            self.0.set(self.0.get() + layout.size());
+
+            Global.allocate(layout)
+        }
+
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            self.0.set(self.0.get() - layout.size());
+
+            Global.deallocate(ptr, layout)
+        }
+    }
+
+    #[test]
+    fn allocates_in() {
+        let bytes = Cell::new(0);
+
+        {
+            let mut array =
+                ArrayIn::from_shape_fn_in([3, 4], |[i, j]| (i * 4 + j) as f64, Counting(&bytes));
+
+            assert_eq!(bytes.get(), 96);
+            assert_eq!(
+                array,
+                Array::from_shape_fn([3, 4], |[i, j]| (i * 4 + j) as f64)
+            );
+
+            array.slice_mut(&s![.., 1..]).map_inplace(|val| val * 2.0);
+
+            assert_eq!(array.t().sum(), 120.0);
+            assert_eq!(
+                &array + &ArrayIn::ones_in([1, 4], Global),
+                array.map(|val| val + 1.0)
+            );
+
+            let copy = array.slice(&s![..;2, ..]).to_owned_in(Counting(&bytes));
+
+            assert_eq!(bytes.get(), 160);
+            assert_eq!(copy, array.slice(&s![..;2, ..]));
+        }
+
+        assert_eq!(bytes.get(), 0);
+        assert!(ArrayIn::<i32, _, 2>::try_init_in(Vec::new_in(Global), [2, 1]).is_err());
+    }
+}
@@ -0,0 +1,86 @@
+use approx::{AbsDiffEq, RelativeEq};
+
+use crate::{ArrayBase, Storage};
+
+impl<T, S1, S2, const D: usize> AbsDiffEq<ArrayBase<S2, D>> for ArrayBase<S1, D>
+where
+    T: AbsDiffEq,
+    T::Epsilon: Clone,
+    S1: Storage<Elem = T>,
+    S2: Storage<Elem = T>,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &ArrayBase<S2, D>, epsilon: Self::Epsilon) -> bool {
+        self.shape == other.shape
+            && self
+                .flat()
+                .zip(other.flat())
+                .all(|(lhs, rhs)| lhs.abs_diff_eq(rhs, epsilon.clone()))
+    }
+}
+
+impl<T, S1, S2, const D: usize> RelativeEq<ArrayBase<S2, D>> for ArrayBase<S1, D>
+where
+    T: RelativeEq,
+    T::Epsilon: Clone,
+    S1: Storage<Elem = T>,
+    S2: Storage<Elem = T>,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &ArrayBase<S2, D>,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.shape == other.shape
+            && self
+                .flat()
+                .zip(other.flat())
+                .all(|(lhs, rhs)| lhs.relative_eq(rhs, epsilon.clone(), max_relative.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::{assert_abs_diff_eq, assert_relative_eq, assert_relative_ne, AbsDiffEq};
+
+    use crate::Array;
+
+    #[test]
+    fn abs_diff_eq() {
+        let array = Array::init(vec![1.0, 2.0, 3.0], [3]);
+        let close = Array::init(vec![1.0 + 1e-9, 2.0, 3.0 - 1e-9], [3]);
+
+        assert_abs_diff_eq!(array, close, epsilon = 1e-6);
+        assert!(!array.abs_diff_eq(&close, 1e-12));
+    }
+
+    #[test]
+    fn relative_eq() {
+        let array = Array::linspace(0.0, 1.0, 11).mapv(|x| x * 1e6);
+        let sum = Array::arange((0..11).map(|x| x as f64 * 0.1 * 1e6));
+
+        assert_relative_eq!(array, sum);
+        assert_relative_eq!(array.view(), sum, max_relative = 1e-12);
+        assert_relative_ne!(array, sum.mapv(|x| x * 1.001));
+    }
+
+    #[test]
+    fn approx_eq_layouts() {
+        let array = Array::init(vec![0.1 + 0.2, 0.6, 0.5, 1.0], [2, 2]);
+        let transposed = Array::init(vec![0.3, 0.5, 0.6, 1.0], [2, 2]);
+
+        assert_relative_eq!(array.t(), transposed);
+        assert_relative_ne!(array, transposed);
+        assert_relative_ne!(array, Array::init(vec![0.3, 0.6, 0.5, 1.0], [4, 1]));
+    }
+}
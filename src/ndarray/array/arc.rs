@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use crate::{Array, ArrayBase, Storage, StorageMut};
+
+impl<T> Storage for Arc<Vec<T>> {
+    type Elem = T;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+// Writing to shared elements clones all of them first, so the other owners never see the
+// change
+impl<T: Clone> StorageMut for Arc<Vec<T>> {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        Arc::make_mut(self).as_mut_slice()
+    }
+}
+
+// An array whose elements are shared by all of its clones, which are cheap to make and can
+// be sent to other threads
+pub type ArcArray<T, const D: usize> = ArrayBase<Arc<Vec<T>>, D>;
+
+impl<T, const D: usize> Array<T, D> {
+    pub fn into_shared(self) -> ArcArray<T, D> {
+        ArrayBase {
+            data: Arc::new(self.data),
+            shape: self.shape,
+            strides: self.strides,
+            offset: self.offset,
+        }
+    }
+}
+
+impl<T, const D: usize> ArcArray<T, D> {
+    // Whether no other array shares the elements, so writing to them does not clone them
+    pub fn is_unique(&self) -> bool {
+        Arc::strong_count(&self.data) == 1
+    }
+}
+
+impl<T: Clone, const D: usize> ArcArray<T, D> {
+    // Takes the elements without cloning them unless they are shared
+    pub fn into_owned(self) -> Array<T, D> {
+        ArrayBase {
+            data: Arc::try_unwrap(self.data).unwrap_or_else(|data| (*data).clone()),
+            shape: self.shape,
+            strides: self.strides,
+            offset: self.offset,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+    use crate::s;
+
+    #[test]
+    fn copy_on_write() {
+        let mut array = Array::from_shape_fn([3, 4], |[i, j]| i * 4 + j).into_shared();
+        let shared = array.clone();
+
+        assert!(Arc::ptr_eq(&array.data, &shared.data));
+        assert!(!array.is_unique());
+
+        let sums: Vec<usize> = (0..3)
+            .map(|row| {
+                let shared = shared.clone();
+
+                thread::spawn(move || shared.slice(&s![row..row + 1, ..]).sum())
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect();
+
+        assert_eq!(sums, vec![6, 22, 38]);
+
+        array.slice_mut(&s![.., ..;2]).map_inplace(|_| 0);
+        array[[2, 3]] = 100;
+
+        assert!(array.is_unique() && shared.is_unique());
+        assert_eq!(shared, Array::from_shape_fn([3, 4], |[i, j]| i * 4 + j));
+        assert_eq!(array.sum(), 1 + 3 + 5 + 7 + 9 + 100);
+
+        let transposed = shared.t().to_owned();
+
+        assert_eq!(shared.reversed_axes().into_owned(), transposed);
+    }
+}
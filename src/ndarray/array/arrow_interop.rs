@@ -0,0 +1,294 @@
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+
+use arrow::array::{Array as _, ArrowPrimitiveType, FixedSizeListArray, PrimitiveArray};
+use arrow::buffer::{Buffer, ScalarBuffer};
+use arrow::datatypes::{
+    ArrowNativeType, DataType, Field, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type,
+    Int8Type, UInt16Type, UInt32Type, UInt64Type, UInt8Type,
+};
+use arrow::error::ArrowError;
+use arrow::tensor::Tensor;
+
+use crate::{Array, ArrayBase, View};
+
+// Maps an element type to the arrow type whose values it stores
+pub trait ArrowElement: ArrowNativeType {
+    type ArrowType: ArrowPrimitiveType<Native = Self>;
+}
+
+macro_rules! impl_arrow_element {
+    ($($ty:ty => $arrow_ty:ty),*) => {
+        $(impl ArrowElement for $ty {
+            type ArrowType = $arrow_ty;
+        })*
+    };
+}
+
+impl_arrow_element!(
+    i8 => Int8Type, i16 => Int16Type, i32 => Int32Type, i64 => Int64Type,
+    u8 => UInt8Type, u16 => UInt16Type, u32 => UInt32Type, u64 => UInt64Type,
+    f32 => Float32Type, f64 => Float64Type
+);
+
+#[derive(Debug)]
+pub enum ArrowConversionError {
+    Arrow(ArrowError),
+    DtypeMismatch { expected: DataType, found: DataType },
+    RankMismatch { expected: usize, found: usize },
+    Nulls,
+}
+
+impl fmt::Display for ArrowConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArrowConversionError::Arrow(error) => error.fmt(f),
+            ArrowConversionError::DtypeMismatch { expected, found } => write!(
+                f,
+                "Data type of the arrow values does not match the array: {} != {}",
+                found, expected
+            ),
+            ArrowConversionError::RankMismatch { expected, found } => write!(
+                f,
+                "Number of axes of the tensor does not match the array: {} != {}",
+                found, expected
+            ),
+            ArrowConversionError::Nulls => write!(f, "Arrays can not hold null values"),
+        }
+    }
+}
+
+impl Error for ArrowConversionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ArrowConversionError::Arrow(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ArrowError> for ArrowConversionError {
+    fn from(error: ArrowError) -> Self {
+        ArrowConversionError::Arrow(error)
+    }
+}
+
+impl<T: ArrowElement, const D: usize> Array<T, D> {
+    // Arrays that are C or F contiguous over their whole vector are moved into the
+    // tensor without copying, the others are copied in row-major order.
+    pub fn into_arrow_tensor(self) -> Tensor<'static, T::ArrowType> {
+        let len = self.data.len();
+        let transposed = self.view().reversed_axes();
+
+        let tensor = if self.contiguous_range() == Some(0..len) {
+            Tensor::new_row_major(Buffer::from_vec(self.data), Some(self.shape.to_vec()), None)
+        } else if transposed.contiguous_range() == Some(0..len) {
+            Tensor::new_column_major(Buffer::from_vec(self.data), Some(self.shape.to_vec()), None)
+        } else {
+            let vec: Vec<T> = self.flat().copied().collect();
+
+            Tensor::new_row_major(Buffer::from_vec(vec), Some(self.shape.to_vec()), None)
+        };
+
+        tensor.expect("Shape of the array must be valid for a tensor")
+    }
+
+    pub fn from_arrow_tensor(
+        tensor: &Tensor<'_, T::ArrowType>,
+    ) -> Result<Self, ArrowConversionError> {
+        Ok(View::from_arrow_tensor(tensor)?.to_owned())
+    }
+}
+
+impl<'a, T: ArrowElement, const D: usize> View<'a, T, D> {
+    // Borrows the buffer of the tensor, whose strides are counted in bytes
+    pub fn from_arrow_tensor(
+        tensor: &'a Tensor<'_, T::ArrowType>,
+    ) -> Result<Self, ArrowConversionError> {
+        let rank_mismatch = || ArrowConversionError::RankMismatch {
+            expected: D,
+            found: tensor.ndim(),
+        };
+
+        // Arrow may describe scalars with a missing shape
+        let shape = match tensor.shape() {
+            Some(shape) => <[usize; D]>::try_from(shape.as_slice()).map_err(|_| rank_mismatch())?,
+            None if D == 0 => [0; D],
+            None => return Err(rank_mismatch()),
+        };
+
+        let size = std::mem::size_of::<T>();
+        let mut strides = [0; D];
+        if let Some(byte_strides) = tensor.strides() {
+            for (stride, byte_stride) in strides.iter_mut().zip(byte_strides) {
+                *stride = (byte_stride / size) as isize;
+            }
+        }
+
+        Ok(ArrayBase {
+            data: tensor.data().typed_data::<T>(),
+            shape,
+            strides,
+            offset: 0,
+        })
+    }
+}
+
+impl<T: ArrowElement> Array<T, 2> {
+    // Every row becomes a list of the values in that row
+    pub fn into_fixed_size_list(self) -> FixedSizeListArray {
+        let [rows, cols] = self.shape;
+
+        let vec = if self.contiguous_range() == Some(0..self.data.len()) {
+            self.data
+        } else {
+            self.flat().copied().collect()
+        };
+
+        let values = PrimitiveArray::<T::ArrowType>::new(ScalarBuffer::from(vec), None);
+        let field = Field::new_list_field(T::ArrowType::DATA_TYPE, false);
+        let size = i32::try_from(cols).expect("Number of columns does not fit in an i32");
+
+        FixedSizeListArray::try_new_with_length(Arc::new(field), size, Arc::new(values), None, rows)
+            .expect("Values must match the shape of the array")
+    }
+
+    pub fn from_fixed_size_list(list: &FixedSizeListArray) -> Result<Self, ArrowConversionError> {
+        Ok(View::from_fixed_size_list(list)?.to_owned())
+    }
+}
+
+impl<'a, T: ArrowElement> View<'a, T, 2> {
+    pub fn from_fixed_size_list(
+        list: &'a FixedSizeListArray,
+    ) -> Result<Self, ArrowConversionError> {
+        let values = list
+            .values()
+            .as_any()
+            .downcast_ref::<PrimitiveArray<T::ArrowType>>()
+            .ok_or_else(|| ArrowConversionError::DtypeMismatch {
+                expected: T::ArrowType::DATA_TYPE,
+                found: list.value_type(),
+            })?;
+
+        if list.null_count() > 0 || values.null_count() > 0 {
+            return Err(ArrowConversionError::Nulls);
+        }
+
+        let cols = list.value_length() as usize;
+
+        Ok(ArrayBase {
+            data: values.values(),
+            shape: [list.len(), cols],
+            strides: [cols as isize, 1],
+            offset: 0,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tensor_round_trip() {
+        let array = Array::arange(0..6i32).reshape([2, 3]).unwrap();
+
+        let tensor = array.clone().into_arrow_tensor();
+
+        assert_eq!(tensor.shape(), Some(&vec![2, 3]));
+        assert!(tensor.is_row_major().unwrap());
+        assert_eq!(Array::<i32, 2>::from_arrow_tensor(&tensor).unwrap(), array);
+    }
+
+    #[test]
+    fn tensor_without_copy() {
+        let array = Array::init(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], [2, 3]);
+        let ptr = array.data.as_ptr();
+
+        let tensor = array.into_arrow_tensor();
+        let view = View::<f64, 2>::from_arrow_tensor(&tensor).unwrap();
+
+        assert_eq!(tensor.data().as_ptr(), ptr as *const u8);
+        assert_eq!(view.data.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn tensor_layouts() {
+        let array = Array::arange(0..6i64).reshape([2, 3]).unwrap();
+
+        let tensor = array.clone().reversed_axes().into_arrow_tensor();
+
+        assert!(tensor.is_column_major().unwrap());
+        assert_eq!(
+            View::<i64, 2>::from_arrow_tensor(&tensor).unwrap(),
+            array.t()
+        );
+
+        let tensor = array.clone().slice_move(&[0..2, 1..3]).into_arrow_tensor();
+
+        assert!(tensor.is_row_major().unwrap());
+        assert_eq!(
+            Array::<i64, 2>::from_arrow_tensor(&tensor).unwrap(),
+            Array::init(vec![1, 2, 4, 5], [2, 2])
+        );
+    }
+
+    #[test]
+    fn tensor_rank_mismatch() {
+        let tensor = Array::<f32, 2>::zeros([2, 2]).into_arrow_tensor();
+
+        assert!(matches!(
+            Array::<f32, 3>::from_arrow_tensor(&tensor),
+            Err(ArrowConversionError::RankMismatch {
+                expected: 3,
+                found: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn fixed_size_list_round_trip() {
+        let array = Array::arange(0..6u8).reshape([3, 2]).unwrap();
+
+        let list = array.clone().into_fixed_size_list();
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.value_length(), 2);
+        assert_eq!(Array::<u8, 2>::from_fixed_size_list(&list).unwrap(), array);
+
+        let list = array.clone().reversed_axes().into_fixed_size_list();
+
+        assert_eq!(
+            View::<u8, 2>::from_fixed_size_list(&list).unwrap(),
+            array.t()
+        );
+
+        // Slices of the list only see their own rows
+        assert_eq!(
+            Array::<u8, 2>::from_fixed_size_list(&list.slice(1, 1)).unwrap(),
+            Array::init(vec![1, 3, 5], [1, 3])
+        );
+    }
+
+    #[test]
+    fn fixed_size_list_errors() {
+        let list = Array::<f32, 2>::zeros([2, 2]).into_fixed_size_list();
+
+        assert!(matches!(
+            Array::<f64, 2>::from_fixed_size_list(&list),
+            Err(ArrowConversionError::DtypeMismatch { .. })
+        ));
+
+        let values = PrimitiveArray::<Float64Type>::from(vec![Some(1.0), None]);
+        let field = Arc::new(Field::new_list_field(DataType::Float64, true));
+        let list = FixedSizeListArray::new(field, 2, Arc::new(values), None);
+
+        assert!(matches!(
+            Array::<f64, 2>::from_fixed_size_list(&list),
+            Err(ArrowConversionError::Nulls)
+        ));
+    }
+}
@@ -0,0 +1,156 @@
+use std::convert::TryFrom;
+use std::os::raw::c_int;
+
+use cblas_sys::{
+    cblas_dgemm, cblas_sgemm, CblasNoTrans, CblasRowMajor, CblasTrans, CBLAS_TRANSPOSE,
+};
+
+use super::type_id;
+use crate::{Array, ArrayBase, Storage};
+
+// Describes how a 2-D array can be handed to gemm: whether it has to be
+// transposed, its leading dimension and the offset of its first element.
+fn gemm_layout<T, S: Storage<Elem = T>>(
+    array: &ArrayBase<S, 2>,
+) -> Option<(CBLAS_TRANSPOSE, c_int, usize)> {
+    let [rows, cols] = array.shape;
+    let [row_stride, col_stride] = array.strides;
+
+    let (trans, lda) = if col_stride == 1 && row_stride >= cols.max(1) as isize {
+        (CblasNoTrans, row_stride)
+    } else if row_stride == 1 && col_stride >= rows.max(1) as isize {
+        (CblasTrans, col_stride)
+    } else {
+        return None;
+    };
+
+    let lda = c_int::try_from(lda).ok()?;
+
+    Some((trans, lda, array.offset))
+}
+
+pub(crate) fn try_gemm<T, S1, S2>(
+    lhs: &ArrayBase<S1, 2>,
+    rhs: &ArrayBase<S2, 2>,
+) -> Option<Array<T, 2>>
+where
+    T: Clone,
+    S1: Storage<Elem = T>,
+    S2: Storage<Elem = T>,
+{
+    let is_f32 = type_id::is::<T, f32>();
+    let is_f64 = type_id::is::<T, f64>();
+
+    if !is_f32 && !is_f64 {
+        return None;
+    }
+
+    let [m, k] = lhs.shape;
+    let n = rhs.shape[1];
+
+    if m == 0 || n == 0 || k == 0 {
+        return None;
+    }
+
+    let (trans_a, lda, lhs_offset) = gemm_layout(lhs)?;
+    let (trans_b, ldb, rhs_offset) = gemm_layout(rhs)?;
+
+    let m_int = c_int::try_from(m).ok()?;
+    let n_int = c_int::try_from(n).ok()?;
+    let k_int = c_int::try_from(k).ok()?;
+
+    let a = lhs.data.as_slice()[lhs_offset..].as_ptr();
+    let b = rhs.data.as_slice()[rhs_offset..].as_ptr();
+
+    let mut vec: Vec<T> = Vec::with_capacity(m * n);
+    let c = vec.as_mut_ptr();
+
+    // SAFETY: `T` is exactly `f32` or `f64`, so the pointer casts are
+    // no-ops. `gemm_layout` only accepts layouts whose elements all live
+    // inside the backing slices, and gemm writes every one of the `m * n`
+    // elements of `c` (beta is zero, so `c` is never read) before the
+    // length is set.
+    unsafe {
+        if is_f32 {
+            cblas_sgemm(
+                CblasRowMajor,
+                trans_a,
+                trans_b,
+                m_int,
+                n_int,
+                k_int,
+                1.0,
+                a as *const f32,
+                lda,
+                b as *const f32,
+                ldb,
+                0.0,
+                c as *mut f32,
+                n_int,
+            );
+        } else {
+            cblas_dgemm(
+                CblasRowMajor,
+                trans_a,
+                trans_b,
+                m_int,
+                n_int,
+                k_int,
+                1.0,
+                a as *const f64,
+                lda,
+                b as *const f64,
+                ldb,
+                0.0,
+                c as *mut f64,
+                n_int,
+            );
+        }
+
+        vec.set_len(m * n);
+    }
+
+    Some(Array::init(vec, [m, n]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gemm_layout() {
+        let array = Array::init((0..12).map(|x| x as f64).collect(), [3, 4]);
+
+        assert!(matches!(
+            super::gemm_layout(&array),
+            Some((CblasNoTrans, 4, 0))
+        ));
+        assert!(matches!(
+            super::gemm_layout(&array.t()),
+            Some((CblasTrans, 4, 0))
+        ));
+        assert!(matches!(
+            super::gemm_layout(&array.slice(&[1..3, 1..3])),
+            Some((CblasNoTrans, 4, 5))
+        ));
+        assert!(super::gemm_layout(&array.flip(0)).is_none());
+    }
+
+    #[test]
+    fn gemm_matches_fallback() {
+        let lhs = Array::init((0..6).map(|x| x as f32).collect(), [2, 3]);
+        let rhs = Array::init((0..6).map(|x| x as f32).collect(), [2, 3]);
+
+        let product = try_gemm(&lhs, &rhs.t()).unwrap();
+
+        assert_eq!(
+            product.flat().copied().collect::<Vec<f32>>(),
+            vec![5.0, 14.0, 14.0, 50.0]
+        );
+        assert!(try_gemm(
+            &Array::init(vec![1, 2], [1, 2]),
+            &Array::init(vec![1, 2], [2, 1])
+        )
+        .is_none());
+    }
+}
@@ -0,0 +1,287 @@
+use arrayvec::ArrayVec;
+
+use super::{checked_len, len_of};
+use crate::{ArrayBase, BroadcastError, Storage, View};
+
+impl<T, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn broadcast_to(&self, shape: [usize; D]) -> Result<View<'_, T, D>, BroadcastError> {
+        self.view().broadcast_move(shape)
+    }
+}
+
+impl<'a, T, const D: usize> View<'a, T, D> {
+    pub(crate) fn broadcast_move(mut self, shape: [usize; D]) -> Result<Self, BroadcastError> {
+        let error = BroadcastError {
+            lhs: self.shape.to_vec(),
+            rhs: shape.to_vec(),
+        };
+
+        for (axis, len) in shape.iter().enumerate() {
+            if self.shape[axis] == *len {
+                continue;
+            }
+
+            if self.shape[axis] != 1 {
+                return Err(error);
+            }
+
+            self.shape[axis] = *len;
+            self.strides[axis] = 0;
+        }
+
+        // A broadcast view can not have more elements than an array could
+        if checked_len(&self.shape).is_err() {
+            return Err(error);
+        }
+
+        Ok(self)
+    }
+}
+
+pub fn broadcast_shape<const D: usize>(
+    lhs: &[usize; D],
+    rhs: &[usize; D],
+) -> Result<[usize; D], BroadcastError> {
+    let mut shape = [0; D];
+
+    for axis in 0..D {
+        shape[axis] = match (lhs[axis], rhs[axis]) {
+            (l, r) if l == r => l,
+            (1, r) => r,
+            (l, 1) => l,
+            _ => {
+                return Err(BroadcastError {
+                    lhs: lhs.to_vec(),
+                    rhs: rhs.to_vec(),
+                })
+            }
+        };
+    }
+
+    Ok(shape)
+}
+
+pub(crate) fn broadcast_pair<'a, 'b, T, U, S1, S2, const D: usize>(
+    lhs: &'a ArrayBase<S1, D>,
+    rhs: &'b ArrayBase<S2, D>,
+) -> (View<'a, T, D>, View<'b, U, D>)
+where
+    S1: Storage<Elem = T>,
+    S2: Storage<Elem = U>,
+{
+    let broadcast = broadcast_shape(lhs.shape(), rhs.shape())
+        .and_then(|shape| Ok((lhs.broadcast_to(shape)?, rhs.broadcast_to(shape)?)));
+
+    broadcast.unwrap_or_else(|error| panic!("{}", error))
+}
+
+pub fn meshgrid<'a, 'b, T, U, S1, S2>(
+    x: &'a ArrayBase<S1, 1>,
+    y: &'b ArrayBase<S2, 1>,
+) -> (View<'a, T, 2>, View<'b, U, 2>)
+where
+    S1: Storage<Elem = T>,
+    S2: Storage<Elem = U>,
+{
+    let shape = [y.shape[0], x.shape[0]];
+
+    (grid_axis(x, 1, shape), grid_axis(y, 0, shape))
+}
+
+pub fn meshgrid_nd<'a, T, S: Storage<Elem = T>, const D: usize>(
+    axes: [&'a ArrayBase<S, 1>; D],
+) -> [View<'a, T, D>; D] {
+    let mut shape = [0; D];
+    for (len, array) in shape.iter_mut().zip(axes.iter()) {
+        *len = array.shape[0];
+    }
+
+    let grids: ArrayVec<_, D> = axes
+        .iter()
+        .enumerate()
+        .map(|(axis, array)| grid_axis(*array, axis, shape))
+        .collect();
+
+    grids.into_inner().unwrap_or_else(|_| unreachable!())
+}
+
+// Lays the 1-D `array` along `axis` of `shape`, repeating it over all other axes
+fn grid_axis<T, S: Storage<Elem = T>, const D: usize>(
+    array: &ArrayBase<S, 1>,
+    axis: usize,
+    shape: [usize; D],
+) -> View<'_, T, D> {
+    len_of(&shape);
+
+    let mut strides = [0; D];
+    strides[axis] = array.strides[0];
+
+    ArrayBase {
+        data: array.data.as_slice(),
+        shape,
+        strides,
+        offset: array.offset,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Array;
+
+    #[test]
+    fn broadcast_to() {
+        // 2-D array:
+        // 1 2 3
+        let array = Array::init(vec![1, 2, 3], [1, 3]);
+
+        // 1 2 3
+        // 1 2 3
+        let broadcast = array.broadcast_to([2, 3]).unwrap();
+
+        assert_eq!(broadcast.shape(), &[2, 3]);
+        assert_eq!(
+            broadcast.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 2, 3, 1, 2, 3]
+        );
+        assert!(array.broadcast_to([2, 4]).is_err());
+    }
+
+    #[test]
+    fn broadcast_overflow() {
+        let array = Array::init(vec![1, 2, 3], [1, 3]);
+
+        assert!(array.broadcast_to([usize::MAX, 3]).is_err());
+    }
+
+    #[test]
+    fn broadcast_slice() {
+        // 2-D array:
+        // 1 2 3
+        // 4 5 6
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        // 4 5 6
+        let row = array.slice(&[1..2, 0..3]);
+
+        // 4 5 6
+        // 4 5 6
+        // 4 5 6
+        let broadcast = row.broadcast_to([3, 3]).unwrap();
+
+        assert_eq!(
+            broadcast.flat().copied().collect::<Vec<usize>>(),
+            vec![4, 5, 6, 4, 5, 6, 4, 5, 6]
+        );
+    }
+
+    #[test]
+    fn broadcast_large() {
+        let array = Array::init(vec![1, 2, 3], [1, 3]);
+
+        let broadcast = array.broadcast_to([500, 3]).unwrap();
+
+        assert_eq!(broadcast.shape(), &[500, 3]);
+        assert_eq!(broadcast.strides(), &[0, 1]);
+        assert_eq!(broadcast.flat().len(), 1500);
+        assert_eq!(broadcast.flat().copied().sum::<usize>(), 3000);
+        assert_eq!(broadcast[[499, 2]], 3);
+        assert!(std::ptr::eq(&broadcast[[499, 1]], &array[[0, 1]]));
+
+        assert_eq!(
+            broadcast
+                .flat()
+                .rev()
+                .take(4)
+                .copied()
+                .collect::<Vec<usize>>(),
+            vec![3, 2, 1, 3]
+        );
+        assert_eq!(broadcast.indexed_iter().nth(1000), Some(([333, 1], &2)));
+        assert!(broadcast
+            .axis_view(0)
+            .all(|row| row.flat().copied().collect::<Vec<usize>>() == vec![1, 2, 3]));
+        assert_eq!(
+            broadcast
+                .axis_view(1)
+                .nth(2)
+                .unwrap()
+                .flat()
+                .copied()
+                .sum::<usize>(),
+            1500
+        );
+
+        let owned = broadcast.to_owned();
+
+        assert_eq!(owned.strides(), &[3, 1]);
+        assert_eq!(owned.sum(), 3000);
+    }
+
+    #[test]
+    fn broadcast_column() {
+        // 2-D array:
+        // 1
+        // 2
+        let array = Array::init(vec![1, 2], [2, 1]);
+
+        let broadcast = array.broadcast_to([2, 4]).unwrap();
+        let transposed = broadcast.t();
+
+        assert_eq!(
+            transposed.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 2, 1, 2, 1, 2, 1, 2]
+        );
+    }
+
+    #[test]
+    fn broadcast_shapes() {
+        assert_eq!(broadcast_shape(&[3, 1], &[1, 4]), Ok([3, 4]));
+        assert_eq!(broadcast_shape(&[2, 3], &[2, 3]), Ok([2, 3]));
+        assert_eq!(
+            broadcast_shape(&[2, 3], &[3, 2]),
+            Err(BroadcastError {
+                lhs: vec![2, 3],
+                rhs: vec![3, 2]
+            })
+        );
+    }
+
+    #[test]
+    fn meshgrid() {
+        let x = Array::init(vec![1, 2, 3], [3]);
+        let y = Array::init(vec![10, 20], [2]);
+
+        let (xx, yy) = super::meshgrid(&x, &y);
+
+        assert_eq!(xx.shape(), &[2, 3]);
+        assert_eq!(yy.shape(), &[2, 3]);
+        assert_eq!(
+            xx.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 2, 3, 1, 2, 3]
+        );
+        assert_eq!(
+            yy.flat().copied().collect::<Vec<usize>>(),
+            vec![10, 10, 10, 20, 20, 20]
+        );
+        assert!(std::ptr::eq(&xx[[1, 2]], &x[[2]]));
+    }
+
+    #[test]
+    fn meshgrid_nd() {
+        let x = Array::init(vec![1, 2], [2]);
+        let y = Array::init(vec![3, 4, 5], [3]);
+        let z = Array::init(vec![6, 7], [2]).flip_move(0);
+
+        let [xx, yy, zz] = super::meshgrid_nd([&x, &y, &z]);
+
+        assert_eq!(xx.shape(), &[2, 3, 2]);
+        assert_eq!(xx[[1, 2, 0]], 2);
+        assert_eq!(yy[[1, 2, 0]], 5);
+        assert_eq!(zz[[1, 2, 0]], 7);
+        assert_eq!(
+            zz.flat().copied().collect::<Vec<usize>>(),
+            vec![7, 6, 7, 6, 7, 6, 7, 6, 7, 6, 7, 6]
+        );
+    }
+}
@@ -1,15 +1,73 @@
-use std::{
-    borrow::Cow,
-    ops::{Add, Div, Mul, Sub},
-};
+use std::ops::{Add, Div, Mul, Sub};
 
-use num_traits::{FromPrimitive, One, Zero};
+use num_traits::{Float, FromPrimitive, One, Zero};
 
-use crate::Array;
+use super::iter::Indices;
+use crate::{Array, ArrayBase, Storage};
 
-impl<'a, T: Clone + Ord, const D: usize> Array<'a, T, D> {
+impl<T, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn fold_axis<const R: usize, B, F>(&self, axis: usize, init: B, mut fold: F) -> Array<B, R>
+    where
+        B: Clone,
+        F: FnMut(B, &T) -> B,
+    {
+        if axis >= D {
+            panic!("Axis out of bound: {} >= {}", axis, D);
+        }
+
+        if R + 1 != D {
+            panic!(
+                "Rank of the result must be one less than rank of the array: {} != {}",
+                R,
+                D - 1
+            );
+        }
+
+        let mut shape = [0; R];
+        for (out_axis, len) in shape.iter_mut().enumerate() {
+            *len = self.shape[if out_axis < axis {
+                out_axis
+            } else {
+                out_axis + 1
+            }];
+        }
+
+        let data = self.data.as_slice();
+        let mut vec = Vec::with_capacity(shape.iter().product());
+
+        let mut out_indices = Indices::init(shape);
+        while let Some(out_idx) = out_indices.next_front() {
+            let mut indices = [0; D];
+            for (out_axis, idx) in out_idx.iter().enumerate() {
+                indices[if out_axis < axis {
+                    out_axis
+                } else {
+                    out_axis + 1
+                }] = *idx;
+            }
+
+            let mut acc = init.clone();
+            for idx in 0..self.shape[axis] {
+                indices[axis] = idx;
+
+                acc = fold(acc, &data[self.offset_of(&indices)]);
+            }
+
+            vec.push(acc);
+        }
+
+        Array::init(vec, shape)
+    }
+}
+
+impl<T: Clone + PartialOrd, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
     pub fn max(&self) -> Option<T> {
-        self.flat().max().cloned()
+        self.flat()
+            .fold(None, |max, val| match max {
+                Some(max) if val <= max => Some(max),
+                _ => Some(val),
+            })
+            .cloned()
     }
 
     pub fn arg_max(&self) -> Vec<usize> {
@@ -26,18 +84,59 @@ impl<'a, T: Clone + Ord, const D: usize> Array<'a, T, D> {
         positions
     }
 
+    pub fn argmax(&self) -> Option<[usize; D]> {
+        self.indexed_iter()
+            .fold(
+                None,
+                |max: Option<([usize; D], &T)>, (indices, val)| match max {
+                    Some(max) if val <= max.1 => Some(max),
+                    _ => Some((indices, val)),
+                },
+            )
+            .map(|(indices, _)| indices)
+    }
+
+    pub fn argmax_axis<const R: usize>(&self, axis: usize) -> Array<usize, R> {
+        self.fold_axis(
+            axis,
+            (0, None),
+            |(idx, max): (usize, Option<(usize, T)>), val| {
+                let max = match max {
+                    Some(max) if val <= &max.1 => Some(max),
+                    _ => Some((idx, val.clone())),
+                };
+
+                (idx + 1, max)
+            },
+        )
+        .mapv(|(_, max)| max.expect("Can not find max of an empty axis").0)
+    }
+
     pub fn max_across(&self, axis: usize) -> Vec<Option<T>> {
         self.axis_view(axis).map(|view| view.max()).collect()
     }
 
     pub fn arg_max_across(&self, axis: usize) -> Vec<Option<usize>> {
         self.axis_view(axis)
-            .map(|view| view.arg_max().get(0).copied())
+            .map(|view| view.arg_max().first().copied())
             .collect()
     }
 
+    pub fn max_axis<const R: usize>(&self, axis: usize) -> Array<T, R> {
+        self.fold_axis(axis, None, |max: Option<T>, val| match max {
+            Some(max) if val <= &max => Some(max),
+            _ => Some(val.clone()),
+        })
+        .mapv(|max| max.expect("Can not find max of an empty axis"))
+    }
+
     pub fn min(&self) -> Option<T> {
-        self.flat().min().cloned()
+        self.flat()
+            .fold(None, |min, val| match min {
+                Some(min) if val >= min => Some(min),
+                _ => Some(val),
+            })
+            .cloned()
     }
 
     pub fn arg_min(&self) -> Vec<usize> {
@@ -54,39 +153,68 @@ impl<'a, T: Clone + Ord, const D: usize> Array<'a, T, D> {
         positions
     }
 
+    pub fn argmin(&self) -> Option<[usize; D]> {
+        self.indexed_iter()
+            .fold(
+                None,
+                |min: Option<([usize; D], &T)>, (indices, val)| match min {
+                    Some(min) if val >= min.1 => Some(min),
+                    _ => Some((indices, val)),
+                },
+            )
+            .map(|(indices, _)| indices)
+    }
+
+    pub fn argmin_axis<const R: usize>(&self, axis: usize) -> Array<usize, R> {
+        self.fold_axis(
+            axis,
+            (0, None),
+            |(idx, min): (usize, Option<(usize, T)>), val| {
+                let min = match min {
+                    Some(min) if val >= &min.1 => Some(min),
+                    _ => Some((idx, val.clone())),
+                };
+
+                (idx + 1, min)
+            },
+        )
+        .mapv(|(_, min)| min.expect("Can not find min of an empty axis").0)
+    }
+
     pub fn min_across(&self, axis: usize) -> Vec<Option<T>> {
         self.axis_view(axis).map(|view| view.min()).collect()
     }
 
     pub fn arg_min_across(&self, axis: usize) -> Vec<Option<usize>> {
         self.axis_view(axis)
-            .map(|view| view.arg_min().get(0).copied())
+            .map(|view| view.arg_min().first().copied())
             .collect()
     }
 
-    pub fn clip(&self, min: &T, max: &T) -> Array<'a, T, D> {
-        let vec: Vec<T> = self
-            .vec
-            .iter()
-            .map(|val| val.clamp(min, max).clone())
-            .collect();
-
-        let shape = self.shape.clone();
-        let strides = self.strides.clone();
-        let idx_maps = self.idx_maps.clone();
+    pub fn min_axis<const R: usize>(&self, axis: usize) -> Array<T, R> {
+        self.fold_axis(axis, None, |min: Option<T>, val| match min {
+            Some(min) if val >= &min => Some(min),
+            _ => Some(val.clone()),
+        })
+        .mapv(|min| min.expect("Can not find min of an empty axis"))
+    }
 
-        Array {
-            vec: Cow::from(vec),
-            shape,
-            strides,
-            idx_maps,
-        }
+    pub fn clip(&self, min: &T, max: &T) -> Array<T, D> {
+        self.map(|val| {
+            if val < min {
+                min.clone()
+            } else if val > max {
+                max.clone()
+            } else {
+                val.clone()
+            }
+        })
     }
 }
 
-impl<'a, T, const D: usize> Array<'a, T, D>
+impl<T, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D>
 where
-    T: Clone + Ord + Sub<Output = T>,
+    T: Clone + PartialOrd + Sub<Output = T>,
 {
     pub fn ptp(&self) -> Option<T> {
         self.max().and_then(|max| self.min().map(|min| max - min))
@@ -97,20 +225,106 @@ where
     }
 }
 
-impl<'a, T, const D: usize> Array<'a, T, D>
+impl<T, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D>
 where
     T: Clone + Add<Output = T> + Zero,
 {
     pub fn sum(&self) -> T {
-        self.flat().fold(T::zero(), |acc, val| acc + val.clone())
+        #[cfg(feature = "half")]
+        if let Some(sum) = super::half_precision::try_widened_sum(self) {
+            return sum;
+        }
+
+        #[cfg(feature = "simd")]
+        if let Some(sum) = super::simd::try_sum(self) {
+            return sum;
+        }
+
+        pairwise_sum(self.flat().cloned())
     }
 
     pub fn sum_across(&self, axis: usize) -> Vec<T> {
         self.axis_view(axis).map(|view| view.sum()).collect()
     }
+
+    pub fn sum_axis<const R: usize>(&self, axis: usize) -> Array<T, R> {
+        #[cfg(feature = "half")]
+        if let Some(sums) = super::half_precision::try_widened_sum_axis(self, axis) {
+            return sums;
+        }
+
+        self.fold_axis(axis, T::zero(), |acc, val| acc + val.clone())
+    }
+}
+
+impl<T: Clone, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn accumulate_axis<F: FnMut(&T, &T) -> T>(&self, axis: usize, mut f: F) -> Array<T, D> {
+        if axis >= D {
+            panic!("Axis out of bound: {} >= {}", axis, D);
+        }
+
+        let mut array = self.to_owned();
+
+        let stride = array.strides[axis] as usize;
+        let len = array.shape[axis];
+
+        for pos in 0..array.data.len() {
+            if pos / stride % len != 0 {
+                array.data[pos] = f(&array.data[pos - stride], &array.data[pos]);
+            }
+        }
+
+        array
+    }
+}
+
+impl<T: Clone + Add<Output = T>, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn cumsum(&self, axis: usize) -> Array<T, D> {
+        self.accumulate_axis(axis, |acc, val| acc.clone() + val.clone())
+    }
+}
+
+impl<T: Clone + Mul<Output = T>, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn cumprod(&self, axis: usize) -> Array<T, D> {
+        self.accumulate_axis(axis, |acc, val| acc.clone() * val.clone())
+    }
 }
 
-impl<'a, T, const D: usize> Array<'a, T, D>
+pub(crate) fn pairwise_sum<T: Add<Output = T> + Zero, I: Iterator<Item = T>>(mut iter: I) -> T {
+    const BLOCK_LEN: usize = 128;
+
+    // Sums of consecutive blocks paired with the number of merges they went through.
+    // Only sums of equal depth are merged, so the stack never grows beyond log2(n).
+    let mut partials: Vec<(T, usize)> = vec![];
+
+    loop {
+        let mut block_len = 0;
+        let block_sum = iter
+            .by_ref()
+            .take(BLOCK_LEN)
+            .inspect(|_| block_len += 1)
+            .fold(T::zero(), |acc, val| acc + val);
+
+        if block_len == 0 {
+            break;
+        }
+
+        let mut partial = (block_sum, 0);
+        while matches!(partials.last(), Some((_, depth)) if *depth == partial.1) {
+            let (lhs, depth) = partials.pop().unwrap();
+
+            partial = (lhs + partial.0, depth + 1);
+        }
+        partials.push(partial);
+    }
+
+    partials
+        .into_iter()
+        .rev()
+        .fold(T::zero(), |acc, (partial, _)| partial + acc)
+}
+
+impl<T, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D>
 where
     T: Clone + Mul<Output = T> + One,
 {
@@ -121,9 +335,13 @@ where
     pub fn prod_across(&self, axis: usize) -> Vec<T> {
         self.axis_view(axis).map(|view| view.prod()).collect()
     }
+
+    pub fn prod_axis<const R: usize>(&self, axis: usize) -> Array<T, R> {
+        self.fold_axis(axis, T::one(), |acc, val| acc * val.clone())
+    }
 }
 
-impl<'a, T, const D: usize> Array<'a, T, D>
+impl<T, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D>
 where
     T: Clone + Add<Output = T> + FromPrimitive + Div<T, Output = T> + Zero,
 {
@@ -134,22 +352,153 @@ where
     pub fn mean_across(&self, axis: usize) -> Vec<T> {
         self.axis_view(axis).map(|view| view.mean()).collect()
     }
+
+    pub fn mean_axis<const R: usize>(&self, axis: usize) -> Array<T, R> {
+        // `sum_axis` checks the axis before its length is read
+        let sums = self.sum_axis(axis);
+        let len = T::from_usize(self.shape[axis]).unwrap();
+
+        sums.mapv(|sum| sum / len.clone())
+    }
+}
+
+impl<T, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D>
+where
+    T: Clone + Sub<Output = T> + FromPrimitive + Div<T, Output = T> + Mul<Output = T> + Zero,
+{
+    pub fn var(&self, ddof: usize) -> T {
+        self.flat()
+            .fold(Welford::init(), |welford, val| welford.push(val.clone()))
+            .var(ddof)
+    }
+
+    pub fn var_across(&self, axis: usize, ddof: usize) -> Vec<T> {
+        self.axis_view(axis).map(|view| view.var(ddof)).collect()
+    }
+
+    pub fn var_axis<const R: usize>(&self, axis: usize, ddof: usize) -> Array<T, R> {
+        self.fold_axis(axis, Welford::init(), |welford, val| {
+            welford.push(val.clone())
+        })
+        .map(|welford| welford.var(ddof))
+    }
+}
+
+impl<T: Float + FromPrimitive, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn std(&self, ddof: usize) -> T {
+        self.var(ddof).sqrt()
+    }
+
+    pub fn std_axis<const R: usize>(&self, axis: usize, ddof: usize) -> Array<T, R> {
+        self.var_axis(axis, ddof).mapv(T::sqrt)
+    }
+}
+
+impl<T: Float + FromPrimitive, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    fn non_nan<'a>(&'a self) -> impl Iterator<Item = T> + 'a
+    where
+        T: 'a,
+    {
+        self.flat().copied().filter(|val| !val.is_nan())
+    }
+
+    pub fn nansum(&self) -> T {
+        pairwise_sum(self.non_nan())
+    }
+
+    pub fn nansum_axis<const R: usize>(&self, axis: usize) -> Array<T, R> {
+        self.fold_axis(
+            axis,
+            T::zero(),
+            |acc, val| {
+                if val.is_nan() {
+                    acc
+                } else {
+                    acc + *val
+                }
+            },
+        )
+    }
+
+    pub fn nanprod(&self) -> T {
+        self.non_nan().fold(T::one(), |acc, val| acc * val)
+    }
+
+    pub fn nanmax(&self) -> Option<T> {
+        self.non_nan().fold(None, |max, val| match max {
+            Some(max) if val <= max => Some(max),
+            _ => Some(val),
+        })
+    }
+
+    pub fn nanmin(&self) -> Option<T> {
+        self.non_nan().fold(None, |min, val| match min {
+            Some(min) if val >= min => Some(min),
+            _ => Some(val),
+        })
+    }
+
+    pub fn nanmean(&self) -> T {
+        let (count, sum) = self
+            .non_nan()
+            .fold((0, T::zero()), |(count, sum), val| (count + 1, sum + val));
+
+        sum / T::from_usize(count).unwrap()
+    }
+
+    pub fn nanmean_axis<const R: usize>(&self, axis: usize) -> Array<T, R> {
+        self.fold_axis(axis, (0, T::zero()), |(count, sum), val| {
+            if val.is_nan() {
+                (count, sum)
+            } else {
+                (count + 1, sum + *val)
+            }
+        })
+        .mapv(|(count, sum)| sum / T::from_usize(count).unwrap())
+    }
+
+    pub fn nanvar(&self, ddof: usize) -> T {
+        self.non_nan()
+            .fold(Welford::init(), |welford, val| welford.push(val))
+            .var(ddof)
+    }
+
+    pub fn nanstd(&self, ddof: usize) -> T {
+        self.nanvar(ddof).sqrt()
+    }
+}
+
+#[derive(Clone)]
+struct Welford<T> {
+    count: usize,
+    mean: T,
+    m2: T,
 }
 
-impl<'a, T, const D: usize> Array<'a, T, D>
+impl<T> Welford<T>
 where
     T: Clone + Sub<Output = T> + FromPrimitive + Div<T, Output = T> + Mul<Output = T> + Zero,
 {
-    pub fn var(&self) -> T {
-        let mean = self.mean();
+    fn init() -> Self {
+        Welford {
+            count: 0,
+            mean: T::zero(),
+            m2: T::zero(),
+        }
+    }
+
+    fn push(self, val: T) -> Self {
+        let count = self.count + 1;
 
-        self.flat().fold(T::zero(), |acc, val| {
-            acc + (val.clone() - mean.clone()) * (val.clone() - mean.clone())
-        }) / T::from_usize(self.shape().iter().product()).unwrap()
+        let delta = val.clone() - self.mean.clone();
+        let mean = self.mean + delta.clone() / T::from_usize(count).unwrap();
+        let m2 = self.m2 + delta * (val - mean.clone());
+
+        Welford { count, mean, m2 }
     }
 
-    pub fn var_across(&self, axis: usize) -> Vec<T> {
-        self.axis_view(axis).map(|view| view.var()).collect()
+    fn var(&self, ddof: usize) -> T {
+        self.m2.clone() / T::from_usize(self.count.saturating_sub(ddof)).unwrap()
     }
 }
 
@@ -167,16 +516,94 @@ mod tests {
         assert_eq!(array.max().unwrap(), 3);
     }
 
+    #[test]
+    fn max_float() {
+        // 2-D array:
+        // 0.5 -1.5
+        // 2.5  1.0
+        let array = Array::init(vec![0.5, -1.5, 2.5, 1.0], [2, 2]);
+
+        assert_eq!(array.max(), Some(2.5));
+        assert_eq!(array.min(), Some(-1.5));
+        assert_eq!(array.ptp(), Some(4.0));
+    }
+
+    #[test]
+    fn max_empty() {
+        let array: Array<i32, 2> = Array::init(vec![], [0, 2]);
+
+        assert_eq!(array.max(), None);
+        assert_eq!(array.min(), None);
+    }
+
+    #[test]
+    fn sum_view() {
+        // 1 2 3
+        // 4 5 6
+        let array = Array::arange(1..7).reshape([2, 3]).unwrap();
+
+        assert_eq!(array.slice(&[0..2, 1..3]).sum(), 16);
+        assert_eq!(array.slice(&[1..2, 0..3]).prod(), 120);
+    }
+
     #[test]
     fn arg_max() {
         // 2-D array:
         // 0 1
         // 2 3
-        let array = Array::init(vec![0, 1, 2 , 3], [2, 2]);
+        let array = Array::init(vec![0, 1, 2, 3], [2, 2]);
 
         assert_eq!(array.arg_max()[0], 3);
     }
 
+    #[test]
+    fn argmax() {
+        // 2-D array:
+        // 4 9 2
+        // 6 9 7
+        let array = Array::init(vec![4, 9, 2, 6, 9, 7], [2, 3]);
+
+        assert_eq!(array.argmax(), Some([0, 1]));
+        assert_eq!(array.t().argmax(), Some([1, 0]));
+        assert_eq!(array.argmin(), Some([0, 2]));
+
+        let empty: Array<i32, 2> = Array::init(vec![], [2, 0]);
+        assert_eq!(empty.argmax(), None);
+    }
+
+    #[test]
+    fn argmax_axis() {
+        // 2-D array:
+        // 4 9 2
+        // 6 9 7
+        let array = Array::init(vec![4, 9, 2, 6, 9, 7], [2, 3]);
+
+        assert_eq!(
+            array
+                .argmax_axis::<1>(0)
+                .flat()
+                .copied()
+                .collect::<Vec<usize>>(),
+            vec![1, 0, 1]
+        );
+        assert_eq!(
+            array
+                .argmax_axis::<1>(1)
+                .flat()
+                .copied()
+                .collect::<Vec<usize>>(),
+            vec![1, 1]
+        );
+        assert_eq!(
+            array
+                .argmin_axis::<1>(1)
+                .flat()
+                .copied()
+                .collect::<Vec<usize>>(),
+            vec![2, 0]
+        );
+    }
+
     #[test]
     fn max_across() {
         // 2-D array:
@@ -275,7 +702,7 @@ mod tests {
     fn sum() {
         // 1 2
         // 3 4
-        let array = Array::arange(1..5).reshape([2, 2]);
+        let array = Array::arange(1..5).reshape([2, 2]).unwrap();
 
         assert_eq!(array.sum(), 10);
     }
@@ -284,17 +711,139 @@ mod tests {
     fn sum_across() {
         // 1 2
         // 3 4
-        let array = Array::arange(1..5).reshape([2, 2]);
+        let array = Array::arange(1..5).reshape([2, 2]).unwrap();
 
         assert_eq!(array.sum_across(0), vec![3, 7]);
         assert_eq!(array.sum_across(1), vec![4, 6]);
     }
 
+    #[test]
+    fn sum_axis() {
+        // 1 2 3
+        // 4 5 6
+        let array = Array::arange(1..7).reshape([2, 3]).unwrap();
+
+        let sum: Array<i32, 1> = array.sum_axis(0);
+        assert_eq!(sum.flat().copied().collect::<Vec<i32>>(), vec![5, 7, 9]);
+
+        let sum: Array<i32, 1> = array.sum_axis(1);
+        assert_eq!(sum.flat().copied().collect::<Vec<i32>>(), vec![6, 15]);
+    }
+
+    #[test]
+    fn sum_axis_3d() {
+        let array = Array::arange(0..24).reshape([2, 3, 4]).unwrap();
+
+        let sum: Array<i32, 2> = array.sum_axis(1);
+
+        assert_eq!(sum.shape(), &[2, 4]);
+        assert_eq!(
+            sum.flat().copied().collect::<Vec<i32>>(),
+            vec![12, 15, 18, 21, 48, 51, 54, 57]
+        );
+
+        let sum: Array<i32, 0> = sum.sum_axis::<1>(0).sum_axis(0);
+        assert_eq!(sum[[]], 276);
+    }
+
+    #[test]
+    fn min_max_axis() {
+        // 4 9 2 10
+        // 6 9 7 12
+        let array = Array::init(vec![4, 9, 2, 10, 6, 9, 7, 12], [2, 4]);
+
+        assert_eq!(
+            array
+                .t()
+                .max_axis::<1>(1)
+                .flat()
+                .copied()
+                .collect::<Vec<i32>>(),
+            vec![6, 9, 7, 12]
+        );
+        assert_eq!(
+            array.min_axis::<1>(1).flat().copied().collect::<Vec<i32>>(),
+            vec![2, 6]
+        );
+    }
+
+    #[test]
+    fn prod_axis() {
+        // 1 2
+        // 3 4
+        let array = Array::arange(1..5).reshape([2, 2]).unwrap();
+
+        assert_eq!(
+            array
+                .prod_axis::<1>(0)
+                .flat()
+                .copied()
+                .collect::<Vec<i32>>(),
+            vec![3, 8]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn sum_axis_wrong_rank() {
+        let array = Array::arange(1..7).reshape([2, 3]).unwrap();
+
+        let _: Array<i32, 2> = array.sum_axis(0);
+    }
+
+    #[test]
+    fn cumsum() {
+        // 1 2 3
+        // 4 5 6
+        let array = Array::arange(1..7).reshape([2, 3]).unwrap();
+
+        assert_eq!(
+            array.cumsum(0).flat().copied().collect::<Vec<i32>>(),
+            vec![1, 2, 3, 5, 7, 9]
+        );
+        assert_eq!(
+            array.cumsum(1).flat().copied().collect::<Vec<i32>>(),
+            vec![1, 3, 6, 4, 9, 15]
+        );
+    }
+
+    #[test]
+    fn cumsum_view() {
+        // 1 2 3
+        // 4 5 6
+        let array = Array::arange(1..7).reshape([2, 3]).unwrap();
+
+        // 3 6
+        // 2 5
+        // 1 4
+        let flipped = array.t();
+        let flipped = flipped.flip(0);
+
+        assert_eq!(
+            flipped.cumsum(0).flat().copied().collect::<Vec<i32>>(),
+            vec![3, 6, 5, 11, 6, 15]
+        );
+    }
+
+    #[test]
+    fn cumprod() {
+        let array = Array::arange(1..9).reshape([2, 2, 2]).unwrap();
+
+        assert_eq!(
+            array.cumprod(2).flat().copied().collect::<Vec<i32>>(),
+            vec![1, 2, 3, 12, 5, 30, 7, 56]
+        );
+        assert_eq!(
+            array.cumprod(0).flat().copied().collect::<Vec<i32>>(),
+            vec![1, 2, 3, 4, 5, 12, 21, 32]
+        );
+    }
+
     #[test]
     fn prod() {
         // 1 2
         // 3 4
-        let array = Array::arange(1..5).reshape([2, 2]);
+        let array = Array::arange(1..5).reshape([2, 2]).unwrap();
 
         assert_eq!(array.prod(), 24);
     }
@@ -303,7 +852,7 @@ mod tests {
     fn prod_across() {
         // 1 2
         // 3 4
-        let array = Array::arange(1..5).reshape([2, 2]);
+        let array = Array::arange(1..5).reshape([2, 2]).unwrap();
 
         assert_eq!(array.prod_across(0), vec![2, 12]);
         assert_eq!(array.prod_across(1), vec![3, 8]);
@@ -313,7 +862,7 @@ mod tests {
     fn mean() {
         // 1 2
         // 3 4
-        let array = Array::arange(1..5).reshape([2, 2]);
+        let array = Array::arange(1..5).reshape([2, 2]).unwrap();
 
         assert_eq!(array.mean(), 2);
     }
@@ -322,7 +871,7 @@ mod tests {
     fn mean_across() {
         // 1 2
         // 3 4
-        let array = Array::arange(1..5).reshape([2, 2]);
+        let array = Array::arange(1..5).reshape([2, 2]).unwrap();
 
         assert_eq!(array.mean_across(0), vec![1, 3]);
         assert_eq!(array.mean_across(1), vec![2, 3]);
@@ -334,7 +883,182 @@ mod tests {
         // 3 4
         let array = Array::init(vec![1.0, 2.0, 3.0, 4.0], [2, 2]);
 
-        assert_eq!(array.var(), 1.25);
+        assert_eq!(array.var(0), 1.25);
+        assert_eq!(array.var(1), 5.0 / 3.0);
+    }
+
+    #[test]
+    fn var_stable() {
+        // a large offset makes the naive sum of squares lose all significant digits
+        let array = Array::init(vec![1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0], [4]);
+
+        assert_eq!(array.var(0), 22.5);
+        assert_eq!(array.var(1), 30.0);
+    }
+
+    #[test]
+    fn std() {
+        // 1 2
+        // 3 4
+        let array = Array::init(vec![1.0, 2.0, 3.0, 4.0], [2, 2]);
+
+        assert_eq!(array.std(0), 1.25_f64.sqrt());
+        assert_eq!(
+            array
+                .std_axis::<1>(0, 1)
+                .flat()
+                .copied()
+                .collect::<Vec<f64>>(),
+            vec![2.0_f64.sqrt(), 2.0_f64.sqrt()]
+        );
+    }
+
+    #[test]
+    fn var_axis() {
+        // 1 2 3
+        // 4 6 8
+        let array = Array::init(vec![1.0, 2.0, 3.0, 4.0, 6.0, 8.0], [2, 3]);
+
+        assert_eq!(
+            array
+                .var_axis::<1>(0, 0)
+                .flat()
+                .copied()
+                .collect::<Vec<f64>>(),
+            vec![2.25, 4.0, 6.25]
+        );
+        assert_eq!(
+            array
+                .var_axis::<1>(1, 1)
+                .flat()
+                .copied()
+                .collect::<Vec<f64>>(),
+            vec![1.0, 4.0]
+        );
+    }
+
+    #[test]
+    fn nan_reductions() {
+        // 1   NaN 3
+        // NaN 5   6
+        let array = Array::init(vec![1.0, f64::NAN, 3.0, f64::NAN, 5.0, 6.0], [2, 3]);
+
+        assert!(array.sum().is_nan());
+        assert_eq!(array.nansum(), 15.0);
+        assert_eq!(array.nanprod(), 90.0);
+        assert_eq!(array.nanmax(), Some(6.0));
+        assert_eq!(array.nanmin(), Some(1.0));
+        assert_eq!(array.nanmean(), 3.75);
+        assert_eq!(array.nanvar(0), 3.6875);
+        assert_eq!(array.nanstd(0), 3.6875_f64.sqrt());
+    }
+
+    #[test]
+    fn nan_reductions_all_nan() {
+        let array = Array::init(vec![f64::NAN, f64::NAN], [2]);
+
+        assert_eq!(array.nansum(), 0.0);
+        assert_eq!(array.nanmax(), None);
+        assert!(array.nanmean().is_nan());
+    }
+
+    #[test]
+    fn nan_reductions_axis() {
+        // 1   NaN 3
+        // NaN 5   6
+        let array = Array::init(vec![1.0, f64::NAN, 3.0, f64::NAN, 5.0, 6.0], [2, 3]);
+
+        assert_eq!(
+            array
+                .nansum_axis::<1>(0)
+                .flat()
+                .copied()
+                .collect::<Vec<f64>>(),
+            vec![1.0, 5.0, 9.0]
+        );
+        assert_eq!(
+            array
+                .nanmean_axis::<1>(1)
+                .flat()
+                .copied()
+                .collect::<Vec<f64>>(),
+            vec![2.0, 5.5]
+        );
+    }
+
+    #[test]
+    fn mean_axis() {
+        // 1 2 3
+        // 4 6 8
+        let array = Array::init(vec![1.0, 2.0, 3.0, 4.0, 6.0, 8.0], [2, 3]);
+
+        assert_eq!(
+            array
+                .mean_axis::<1>(0)
+                .flat()
+                .copied()
+                .collect::<Vec<f64>>(),
+            vec![2.5, 4.0, 5.5]
+        );
+        assert_eq!(
+            array
+                .mean_axis::<1>(1)
+                .flat()
+                .copied()
+                .collect::<Vec<f64>>(),
+            vec![2.0, 6.0]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Axis out of bound: 2 >= 2")]
+    fn mean_axis_out_of_bounds() {
+        Array::<f64, 2>::zeros([2, 3]).mean_axis::<1>(2);
+    }
+
+    #[test]
+    fn sum_pairwise() {
+        let array = Array::init(vec![0.1; 10_000], [100, 100]);
+
+        assert!((array.sum() - 1000.0).abs() < 1e-9);
+        assert_eq!(Array::arange(0..1000).sum(), 499500);
+    }
+
+    #[test]
+    fn sum_borrowed_elements() {
+        // Elements that borrow their unit
+        #[derive(Clone, Debug, PartialEq)]
+        struct Quantity<'a>(i32, &'a str);
+
+        impl<'a> Add for Quantity<'a> {
+            type Output = Quantity<'a>;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                Quantity(
+                    self.0 + rhs.0,
+                    if self.1.is_empty() { rhs.1 } else { self.1 },
+                )
+            }
+        }
+
+        impl Zero for Quantity<'_> {
+            fn zero() -> Self {
+                Quantity(0, "")
+            }
+
+            fn is_zero(&self) -> bool {
+                self.0 == 0
+            }
+        }
+
+        let unit = String::from("m");
+        let array = Array::from_shape_fn([2, 2], |[i, j]| Quantity((i * 2 + j) as i32, &unit));
+
+        assert_eq!(array.sum(), Quantity(6, &unit));
+        assert_eq!(
+            array.sum_axis::<1>(0),
+            Array::init(vec![Quantity(2, &unit), Quantity(4, &unit)], [2])
+        );
     }
 
     #[test]
@@ -343,7 +1067,7 @@ mod tests {
         // 3 4
         let array = Array::init(vec![1.0, 2.0, 3.0, 4.0], [2, 2]);
 
-        assert_eq!(array.var_across(0), vec![0.25, 0.25]);
-        assert_eq!(array.var_across(1), vec![1.0, 1.0]);
+        assert_eq!(array.var_across(0, 0), vec![0.25, 0.25]);
+        assert_eq!(array.var_across(1, 0), vec![1.0, 1.0]);
     }
 }
@@ -0,0 +1,685 @@
+use std::collections::{HashMap, VecDeque};
+use std::error::Error;
+use std::fmt;
+use std::iter::FusedIterator;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use num_traits::Zero;
+
+use super::access::resolve_slice;
+use super::iter::Indices;
+use super::len_of;
+use crate::{Array, NpyElement, NpyError, Slice, SliceError, View, Zip};
+
+// Chunks kept in memory when no other capacity is given
+const DEFAULT_CAPACITY: usize = 16;
+
+// Loads the chunk at a position of the grid of chunks. Chunks at the far end of an axis
+// hold what is left of the axis, the others hold a full chunk.
+pub trait ChunkLoader<T, const D: usize> {
+    type Error;
+
+    fn load(&self, chunk: [usize; D]) -> Result<Array<T, D>, Self::Error>;
+}
+
+impl<T, E, F, const D: usize> ChunkLoader<T, D> for F
+where
+    F: Fn([usize; D]) -> Result<Array<T, D>, E>,
+{
+    type Error = E;
+
+    fn load(&self, chunk: [usize; D]) -> Result<Array<T, D>, E> {
+        self(chunk)
+    }
+}
+
+// Reads every chunk from its own `.npy` file in a directory, named after its position in
+// the grid like `3.0.12.npy`
+#[derive(Debug, Clone)]
+pub struct NpyChunks {
+    dir: PathBuf,
+}
+
+impl NpyChunks {
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        NpyChunks { dir: dir.into() }
+    }
+
+    pub fn path<const D: usize>(&self, chunk: [usize; D]) -> PathBuf {
+        let name: Vec<String> = chunk.iter().map(|idx| idx.to_string()).collect();
+
+        self.dir.join(format!("{}.npy", name.join(".")))
+    }
+}
+
+impl<T: NpyElement, const D: usize> ChunkLoader<T, D> for NpyChunks {
+    type Error = NpyError;
+
+    fn load(&self, chunk: [usize; D]) -> Result<Array<T, D>, NpyError> {
+        Array::read_npy(self.path(chunk))
+    }
+}
+
+#[derive(Debug)]
+pub enum ChunkError<E> {
+    Load(E),
+    Shape {
+        chunk: Vec<usize>,
+        expected: Vec<usize>,
+        found: Vec<usize>,
+    },
+}
+
+impl<E: fmt::Display> fmt::Display for ChunkError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChunkError::Load(error) => write!(f, "Chunk could not be loaded: {}", error),
+            ChunkError::Shape {
+                chunk,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Chunk {:?} has shape: {:?} instead of: {:?}",
+                chunk, found, expected
+            ),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ChunkError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ChunkError::Load(error) => Some(error),
+            ChunkError::Shape { .. } => None,
+        }
+    }
+}
+
+// The least recently used chunk is dropped first. Chunks still borrowed elsewhere stay
+// alive until they are released.
+struct ChunkCache<T, const D: usize> {
+    chunks: HashMap<[usize; D], Arc<Array<T, D>>>,
+    order: VecDeque<[usize; D]>,
+}
+
+// An array stored as a grid of chunks of a fixed shape, which are only loaded once a part
+// of them is read
+pub struct ChunkedArray<T, L, const D: usize> {
+    loader: L,
+    shape: [usize; D],
+    chunk_shape: [usize; D],
+    capacity: usize,
+    cache: Mutex<ChunkCache<T, D>>,
+}
+
+impl<T, L: ChunkLoader<T, D>, const D: usize> ChunkedArray<T, L, D> {
+    pub fn new(loader: L, shape: [usize; D], chunk_shape: [usize; D]) -> Self {
+        len_of(&shape);
+
+        if chunk_shape.contains(&0) {
+            panic!("Chunks of shape: {:?} are empty", chunk_shape);
+        }
+
+        ChunkedArray {
+            loader,
+            shape,
+            chunk_shape,
+            capacity: DEFAULT_CAPACITY,
+            cache: Mutex::new(ChunkCache {
+                chunks: HashMap::new(),
+                order: VecDeque::new(),
+            }),
+        }
+    }
+
+    // Number of chunks kept in memory
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+
+        self
+    }
+
+    pub fn shape(&self) -> &[usize; D] {
+        &self.shape
+    }
+
+    pub fn chunk_shape(&self) -> &[usize; D] {
+        &self.chunk_shape
+    }
+
+    pub fn grid_shape(&self) -> [usize; D] {
+        std::array::from_fn(|axis| self.shape[axis].div_ceil(self.chunk_shape[axis]))
+    }
+
+    pub fn chunk(&self, chunk: [usize; D]) -> Result<Arc<Array<T, D>>, ChunkError<L::Error>> {
+        let grid = self.grid_shape();
+        if (0..D).any(|axis| chunk[axis] >= grid[axis]) {
+            panic!(
+                "Chunk {:?} is out of bounds for a grid of shape {:?}",
+                chunk, grid
+            );
+        }
+
+        if let Some(array) = self.cached(chunk) {
+            return Ok(array);
+        }
+
+        // The lock is not held while loading, so other threads can read cached chunks
+        let array = self.loader.load(chunk).map_err(ChunkError::Load)?;
+
+        let mut expected = [0; D];
+        for axis in 0..D {
+            let start = chunk[axis] * self.chunk_shape[axis];
+            expected[axis] = self.chunk_shape[axis].min(self.shape[axis] - start);
+        }
+
+        if array.shape != expected {
+            return Err(ChunkError::Shape {
+                chunk: chunk.to_vec(),
+                expected: expected.to_vec(),
+                found: array.shape.to_vec(),
+            });
+        }
+
+        let array = Arc::new(array);
+        self.insert(chunk, array.clone());
+
+        Ok(array)
+    }
+
+    fn cached(&self, chunk: [usize; D]) -> Option<Arc<Array<T, D>>> {
+        let mut cache = self.cache.lock().unwrap();
+        let array = cache.chunks.get(&chunk)?.clone();
+
+        let position = cache.order.iter().position(|cached| *cached == chunk)?;
+        cache.order.remove(position);
+        cache.order.push_back(chunk);
+
+        Some(array)
+    }
+
+    fn insert(&self, chunk: [usize; D], array: Arc<Array<T, D>>) {
+        let mut cache = self.cache.lock().unwrap();
+
+        if cache.chunks.insert(chunk, array).is_none() {
+            cache.order.push_back(chunk);
+        }
+
+        while cache.order.len() > self.capacity {
+            let evicted = cache.order.pop_front().unwrap();
+            cache.chunks.remove(&evicted);
+        }
+    }
+
+    pub fn get(&self, indices: [usize; D]) -> Result<T, ChunkError<L::Error>>
+    where
+        T: Clone,
+    {
+        if (0..D).any(|axis| indices[axis] >= self.shape[axis]) {
+            panic!(
+                "Index {:?} is out of bounds for array of shape {:?}",
+                indices, self.shape
+            );
+        }
+
+        let mut chunk = [0; D];
+        let mut local = [0; D];
+        for axis in 0..D {
+            chunk[axis] = indices[axis] / self.chunk_shape[axis];
+            local[axis] = indices[axis] % self.chunk_shape[axis];
+        }
+
+        Ok(self.chunk(chunk)?[local].clone())
+    }
+
+    pub fn flat(&self) -> ChunkedFlat<'_, T, L, D> {
+        self.view().flat()
+    }
+
+    pub fn view(&self) -> ChunkedView<'_, T, L, D> {
+        ChunkedView {
+            array: self,
+            origin: [0; D],
+            shape: self.shape,
+            steps: [1; D],
+        }
+    }
+
+    pub fn slice<I: Into<Slice> + Clone>(&self, slice: &[I; D]) -> ChunkedView<'_, T, L, D> {
+        self.view().slice(slice)
+    }
+
+    pub fn try_slice<I: Into<Slice> + Clone>(
+        &self,
+        slice: &[I; D],
+    ) -> Result<ChunkedView<'_, T, L, D>, SliceError> {
+        self.view().try_slice(slice)
+    }
+}
+
+// A strided region of a chunked array. Nothing is loaded until its elements are read.
+pub struct ChunkedView<'a, T, L, const D: usize> {
+    array: &'a ChunkedArray<T, L, D>,
+    origin: [usize; D],
+    shape: [usize; D],
+    steps: [usize; D],
+}
+
+impl<'a, T, L, const D: usize> Clone for ChunkedView<'a, T, L, D> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T, L, const D: usize> Copy for ChunkedView<'a, T, L, D> {}
+
+impl<'a, T, L: ChunkLoader<T, D>, const D: usize> ChunkedView<'a, T, L, D> {
+    pub fn shape(&self) -> &[usize; D] {
+        &self.shape
+    }
+
+    pub fn slice<I: Into<Slice> + Clone>(&self, slice: &[I; D]) -> Self {
+        self.try_slice(slice)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    pub fn try_slice<I: Into<Slice> + Clone>(&self, slice: &[I; D]) -> Result<Self, SliceError> {
+        let mut view = *self;
+
+        for (axis, axis_slice) in slice.iter().enumerate() {
+            let (start, len, step) =
+                resolve_slice(axis, axis_slice.clone().into(), self.shape[axis])?;
+
+            view.origin[axis] += start * self.steps[axis];
+            view.shape[axis] = len;
+            view.steps[axis] *= step;
+        }
+
+        Ok(view)
+    }
+
+    pub fn get(&self, indices: [usize; D]) -> Result<T, ChunkError<L::Error>>
+    where
+        T: Clone,
+    {
+        if (0..D).any(|axis| indices[axis] >= self.shape[axis]) {
+            panic!(
+                "Index {:?} is out of bounds for array of shape {:?}",
+                indices, self.shape
+            );
+        }
+
+        let mut global = [0; D];
+        for axis in 0..D {
+            global[axis] = self.origin[axis] + indices[axis] * self.steps[axis];
+        }
+
+        self.array.get(global)
+    }
+
+    // Elements of the view in row-major order. Every chunk is loaded through the cache once
+    // the iterator reaches it, and the one holding the current element is kept until the
+    // iterator leaves it.
+    pub fn flat(&self) -> ChunkedFlat<'a, T, L, D> {
+        ChunkedFlat {
+            view: *self,
+            indices: Indices::init(self.shape),
+            current: None,
+            failed: false,
+        }
+    }
+
+    // Visits the part of the view inside every chunk it touches, in row-major order of the
+    // chunks, with the indices of its first element in the view. Only one chunk has to be
+    // in memory at a time.
+    pub fn for_each_chunk<F>(&self, mut f: F) -> Result<(), ChunkError<L::Error>>
+    where
+        F: FnMut([usize; D], View<'_, T, D>),
+    {
+        if self.shape.contains(&0) {
+            return Ok(());
+        }
+
+        let parts: Vec<Vec<AxisPart>> = (0..D).map(|axis| self.axis_parts(axis)).collect();
+
+        let mut counts = [0; D];
+        for axis in 0..D {
+            counts[axis] = parts[axis].len();
+        }
+
+        let mut indices = Indices::init(counts);
+        while let Some(idx) = indices.next_front() {
+            let mut chunk = [0; D];
+            let mut start = [0; D];
+            let mut slice = [Slice::from(..); D];
+
+            for axis in 0..D {
+                let part = &parts[axis][idx[axis]];
+
+                chunk[axis] = part.chunk;
+                start[axis] = part.start;
+                slice[axis] = Slice::from(part.local.clone()).step_by(self.steps[axis]);
+            }
+
+            let array = self.array.chunk(chunk)?;
+
+            f(start, array.slice(&slice));
+        }
+
+        Ok(())
+    }
+
+    // The chunks along `axis` that hold elements of the view
+    fn axis_parts(&self, axis: usize) -> Vec<AxisPart> {
+        let chunk_len = self.array.chunk_shape[axis];
+        let (origin, step, len) = (self.origin[axis], self.steps[axis], self.shape[axis]);
+        let last = origin + (len - 1) * step;
+
+        (origin / chunk_len..=last / chunk_len)
+            .filter_map(|chunk| {
+                let chunk_start = chunk * chunk_len;
+                let chunk_end = chunk_start + chunk_len;
+
+                // Indices in the view of the first element in this chunk and past the last one
+                let start = chunk_start.saturating_sub(origin).div_ceil(step);
+                let end = (chunk_end - origin).div_ceil(step).min(len);
+
+                if start >= end {
+                    return None;
+                }
+
+                let local_start = origin + start * step - chunk_start;
+
+                Some(AxisPart {
+                    chunk,
+                    start,
+                    local: local_start..local_start + (end - start - 1) * step + 1,
+                })
+            })
+            .collect()
+    }
+
+    // Loads every chunk the view touches and copies its elements into a new array
+    pub fn to_array(&self) -> Result<Array<T, D>, ChunkError<L::Error>>
+    where
+        T: Clone + Zero,
+    {
+        let mut array = Array::zeros(self.shape);
+
+        self.for_each_chunk(|start, part| {
+            let mut slice = [Slice::from(..); D];
+            for axis in 0..D {
+                slice[axis] = Slice::from(start[axis]..start[axis] + part.shape[axis]);
+            }
+
+            Zip::from(&mut array.slice_mut(&slice))
+                .and(part)
+                .for_each(|val, part_val| *val = part_val.clone());
+        })?;
+
+        Ok(array)
+    }
+}
+
+// Stops after the first chunk that fails to load
+pub struct ChunkedFlat<'a, T, L, const D: usize> {
+    view: ChunkedView<'a, T, L, D>,
+    indices: Indices<[usize; D]>,
+    current: Option<([usize; D], Arc<Array<T, D>>)>,
+    failed: bool,
+}
+
+impl<'a, T: Clone, L: ChunkLoader<T, D>, const D: usize> Iterator for ChunkedFlat<'a, T, L, D> {
+    type Item = Result<T, ChunkError<L::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        let idx = self.indices.next_front()?;
+        let chunk_shape = self.view.array.chunk_shape;
+
+        let mut chunk = [0; D];
+        let mut local = [0; D];
+        for axis in 0..D {
+            let global = self.view.origin[axis] + idx[axis] * self.view.steps[axis];
+
+            chunk[axis] = global / chunk_shape[axis];
+            local[axis] = global % chunk_shape[axis];
+        }
+
+        let loaded = matches!(&self.current, Some((current, _)) if *current == chunk);
+        if !loaded {
+            match self.view.array.chunk(chunk) {
+                Ok(array) => self.current = Some((chunk, array)),
+                Err(error) => {
+                    self.failed = true;
+                    self.current = None;
+
+                    return Some(Err(error));
+                }
+            }
+        }
+
+        let (_, array) = self.current.as_ref()?;
+
+        Some(Ok(array[local].clone()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = if self.failed {
+            0
+        } else {
+            self.indices.remaining
+        };
+
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: Clone, L: ChunkLoader<T, D>, const D: usize> ExactSizeIterator
+    for ChunkedFlat<'a, T, L, D>
+{
+}
+
+impl<'a, T: Clone, L: ChunkLoader<T, D>, const D: usize> FusedIterator
+    for ChunkedFlat<'a, T, L, D>
+{
+}
+
+struct AxisPart {
+    chunk: usize,
+    // Index in the view of the first element inside the chunk
+    start: usize,
+    // Indices inside the chunk, to be taken with the step of the view
+    local: std::ops::Range<usize>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+    use crate::s;
+
+    fn chunked(
+        shape: [usize; 2],
+        chunk_shape: [usize; 2],
+    ) -> ChunkedArray<usize, impl ChunkLoader<usize, 2, Error = ()>, 2> {
+        let loader = move |[i, j]: [usize; 2]| {
+            let (top, left) = (i * chunk_shape[0], j * chunk_shape[1]);
+            let rows = chunk_shape[0].min(shape[0] - top);
+            let cols = chunk_shape[1].min(shape[1] - left);
+
+            Ok(Array::from_shape_fn([rows, cols], |[r, c]| {
+                (top + r) * shape[1] + left + c
+            }))
+        };
+
+        ChunkedArray::new(loader, shape, chunk_shape)
+    }
+
+    #[test]
+    fn slices_across_chunks() {
+        let chunked = chunked([10, 7], [3, 4]);
+        let array = Array::from_shape_fn([10, 7], |[i, j]| i * 7 + j);
+
+        assert_eq!(chunked.grid_shape(), [4, 2]);
+        assert_eq!(chunked.get([9, 6]).unwrap(), 69);
+        assert_eq!(chunked.view().to_array().unwrap(), array);
+
+        let slices = [
+            s![1..9, 2..7],
+            s![..;4, 1..;3],
+            s![2..3, ..],
+            s![..0, ..],
+            s![1..;5, ..;6],
+        ];
+        for slice in slices.iter() {
+            let view = chunked.slice(slice);
+
+            assert_eq!(view.to_array().unwrap(), array.slice(slice));
+        }
+
+        let view = chunked.slice(&s![1.., ..;2]).slice(&s![..;3, 1..]);
+
+        assert_eq!(
+            view.to_array().unwrap(),
+            array.slice(&s![1.., ..;2]).slice(&s![..;3, 1..])
+        );
+        assert_eq!(view.get([2, 1]).unwrap(), array[[7, 4]]);
+        assert!(chunked.try_slice(&s![..11, ..]).is_err());
+    }
+
+    #[test]
+    fn loads_on_demand() {
+        let loads = Cell::new(0);
+        let loader = |[i]: [usize; 1]| {
+            loads.set(loads.get() + 1);
+
+            Ok::<_, ()>(Array::from_shape_fn([10], |[j]| i * 10 + j))
+        };
+        let chunked = ChunkedArray::new(loader, [100], [10]).with_capacity(2);
+
+        assert_eq!(
+            chunked.slice(&s![15..25]).to_array().unwrap().shape(),
+            &[10]
+        );
+        assert_eq!(loads.get(), 2);
+
+        chunked.get([12]).unwrap();
+        chunked.get([21]).unwrap();
+        assert_eq!(loads.get(), 2);
+
+        // Evicts chunk 1, the least recently used
+        chunked.get([35]).unwrap();
+        chunked.get([28]).unwrap();
+        assert_eq!(loads.get(), 3);
+        chunked.get([19]).unwrap();
+        assert_eq!(loads.get(), 4);
+
+        let mut visited = vec![];
+        chunked
+            .slice(&s![5..60;10])
+            .for_each_chunk(|start, part| visited.push((start, part.shape()[0])))
+            .unwrap();
+
+        assert_eq!(
+            visited,
+            vec![([0], 1), ([1], 1), ([2], 1), ([3], 1), ([4], 1), ([5], 1)]
+        );
+    }
+
+    #[test]
+    fn flat() {
+        let chunked = chunked([10, 7], [3, 4]);
+        let array = Array::from_shape_fn([10, 7], |[i, j]| i * 7 + j);
+
+        let elems = chunked.flat().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(elems, array.flat().copied().collect::<Vec<_>>());
+
+        let view = chunked.slice(&s![1..;4, ..;3]);
+        let flat = view.flat();
+
+        assert_eq!(flat.len(), 9);
+        assert_eq!(
+            flat.collect::<Result<Vec<_>, _>>().unwrap(),
+            array
+                .slice(&s![1..;4, ..;3])
+                .flat()
+                .copied()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn flat_through_cache() {
+        let loads = Cell::new(0);
+        let loader = |[i, j]: [usize; 2]| {
+            loads.set(loads.get() + 1);
+
+            Ok::<_, ()>(Array::from_shape_fn([2, 2], |[r, c]| {
+                (i * 2 + r) * 4 + j * 2 + c
+            }))
+        };
+        let chunked = ChunkedArray::new(loader, [4, 4], [2, 2]).with_capacity(2);
+
+        // every row crosses both chunks of its band, which stay cached
+        let sum: usize = chunked.flat().map(Result::unwrap).sum();
+        assert_eq!(sum, 120);
+        assert_eq!(loads.get(), 4);
+
+        // the chunks of the bottom band are still cached
+        chunked.slice(&s![2.., ..]).flat().for_each(drop);
+        assert_eq!(loads.get(), 4);
+    }
+
+    #[test]
+    fn loader_errors() {
+        let loader = |[i]: [usize; 1]| match i {
+            0 => Ok(Array::zeros([4])),
+            1 => Ok(Array::zeros([3])),
+            _ => Err("missing"),
+        };
+        let chunked: ChunkedArray<i32, _, 1> = ChunkedArray::new(loader, [10], [4]);
+
+        assert_eq!(chunked.get([1]).unwrap(), 0);
+        assert!(matches!(
+            chunked.get([5]),
+            Err(ChunkError::Shape { chunk, .. }) if chunk == vec![1]
+        ));
+        assert!(matches!(chunked.get([9]), Err(ChunkError::Load("missing"))));
+
+        let mut flat = chunked.flat();
+        assert!(flat.by_ref().take(4).all(|elem| elem.is_ok()));
+        assert!(matches!(flat.next(), Some(Err(ChunkError::Shape { .. }))));
+        assert!(flat.next().is_none());
+    }
+
+    #[test]
+    fn npy_chunks() {
+        let dir = std::env::temp_dir().join(format!("nd_array_chunks_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let chunks = NpyChunks::new(&dir);
+        for i in 0..2 {
+            for j in 0..2 {
+                Array::from_shape_fn([2, 2], |[r, c]| ((i * 2 + r) * 4 + j * 2 + c) as f32)
+                    .write_npy(chunks.path([i, j]))
+                    .unwrap();
+            }
+        }
+
+        let chunked: ChunkedArray<f32, _, 2> = ChunkedArray::new(chunks, [4, 4], [2, 2]);
+
+        assert_eq!(
+            chunked.slice(&s![1..3, 1..]).to_array().unwrap(),
+            Array::init(vec![5.0, 6.0, 7.0, 9.0, 10.0, 11.0], [2, 3])
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
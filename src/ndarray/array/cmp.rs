@@ -0,0 +1,198 @@
+use std::fmt::Debug;
+
+use num_traits::Float;
+
+use super::iter::Indices;
+use crate::{ArrayBase, Storage};
+
+// Arrays are equal when their shapes and elements are, however they are laid out
+impl<T, S1, S2, const D: usize> PartialEq<ArrayBase<S2, D>> for ArrayBase<S1, D>
+where
+    T: PartialEq,
+    S1: Storage<Elem = T>,
+    S2: Storage<Elem = T>,
+{
+    fn eq(&self, other: &ArrayBase<S2, D>) -> bool {
+        self.shape == other.shape && self.flat().zip(other.flat()).all(|(lhs, rhs)| lhs == rhs)
+    }
+}
+
+impl<T: Eq, S: Storage<Elem = T>, const D: usize> Eq for ArrayBase<S, D> {}
+
+impl<T: Float, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    // Same tolerance as numpy: |a - b| <= atol + rtol * |b|, NaNs are never close and
+    // infinities only to infinities of the same sign
+    pub fn allclose<S2: Storage<Elem = T>>(
+        &self,
+        other: &ArrayBase<S2, D>,
+        rtol: T,
+        atol: T,
+    ) -> bool {
+        self.shape == other.shape && self.first_not_close(other, rtol, atol).is_none()
+    }
+
+    #[track_caller]
+    pub fn assert_allclose<S2: Storage<Elem = T>>(&self, other: &ArrayBase<S2, D>, rtol: T, atol: T)
+    where
+        T: Debug,
+    {
+        if self.shape != other.shape {
+            panic!(
+                "Shapes of the arrays do not match: {:?} != {:?}",
+                self.shape, other.shape
+            );
+        }
+
+        if let Some(idx) = self.first_not_close(other, rtol, atol) {
+            panic!(
+                "Arrays are not close at index {:?}: {:?} != {:?} (rtol = {:?}, atol = {:?})",
+                idx, self[idx], other[idx], rtol, atol
+            );
+        }
+    }
+
+    fn first_not_close<S2: Storage<Elem = T>>(
+        &self,
+        other: &ArrayBase<S2, D>,
+        rtol: T,
+        atol: T,
+    ) -> Option<[usize; D]> {
+        let mut indices = Indices::init(self.shape);
+
+        while let Some(idx) = indices.next_front() {
+            let (lhs, rhs) = (self[idx], other[idx]);
+            // An infinite `rhs` would make any finite `lhs` close to it
+            let close = lhs == rhs
+                || (lhs.is_finite()
+                    && rhs.is_finite()
+                    && (lhs - rhs).abs() <= atol + rtol * rhs.abs());
+
+            if !close {
+                return Some(idx);
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Array;
+
+    #[test]
+    fn eq() {
+        let array = Array::init(vec![1, 2, 3, 4], [2, 2]);
+
+        assert_eq!(array, Array::init(vec![1, 2, 3, 4], [2, 2]));
+        assert_ne!(array, Array::init(vec![1, 2, 3, 5], [2, 2]));
+    }
+
+    #[test]
+    fn eq_different_shapes() {
+        let array = Array::init(vec![1, 2, 3, 4], [2, 2]);
+
+        assert_ne!(array, Array::init(vec![1, 2, 3, 4], [1, 4]));
+        assert_ne!(Array::<i32, 2>::zeros([0, 3]), Array::zeros([3, 0]));
+        assert_eq!(Array::<i32, 2>::zeros([0, 3]), Array::zeros([0, 3]));
+    }
+
+    #[test]
+    fn eq_different_layouts() {
+        // 1 2
+        // 3 4
+        let array = Array::init(vec![1, 2, 3, 4], [2, 2]);
+        let transposed = Array::init(vec![1, 3, 2, 4], [2, 2]);
+
+        assert_eq!(array.t(), transposed);
+        assert_eq!(transposed, array.t());
+        assert_eq!(array.view(), array);
+        assert_eq!(
+            array.flip(0).slice(&[0..1, 0..2]),
+            Array::init(vec![3, 4], [1, 2])
+        );
+        assert_eq!(
+            array.slice(&[0..1, 0..2]).broadcast_to([2, 2]).unwrap(),
+            Array::init(vec![1, 2, 1, 2], [2, 2])
+        );
+    }
+
+    #[test]
+    fn eq_floats() {
+        let array = Array::init(vec![1.0, f64::NAN], [2]);
+
+        assert_ne!(array, array.clone());
+        assert_eq!(array.slice_axis(0, 0..1), Array::init(vec![1.0], [1]));
+    }
+
+    #[test]
+    fn allclose() {
+        let array = Array::init(vec![1.0, 2.0, 1e-9], [3]);
+
+        assert!(array.allclose(&Array::init(vec![1.000001, 2.0, 0.0], [3]), 1e-5, 1e-8));
+        assert!(!array.allclose(&Array::init(vec![1.1, 2.0, 0.0], [3]), 1e-5, 1e-8));
+        assert!(!array.allclose(&Array::init(vec![1.0, 2.0, 0.0], [3]), 1e-5, 0.0));
+        assert!(!array.allclose(&Array::init(vec![1.0, 2.0], [2]), 1e-5, 1e-8));
+        assert!(!Array::init(vec![f64::NAN], [1]).allclose(
+            &Array::init(vec![f64::NAN], [1]),
+            1.0,
+            1.0
+        ));
+    }
+
+    #[test]
+    fn allclose_infinities() {
+        let array = Array::init(vec![f64::INFINITY, f64::NEG_INFINITY, 1.0], [3]);
+
+        assert!(array.allclose(&array, 1e-5, 1e-8));
+        assert!(!array.allclose(
+            &Array::init(vec![f64::NEG_INFINITY, f64::NEG_INFINITY, 1.0], [3]),
+            1e-5,
+            1e-8
+        ));
+        assert!(!array.allclose(
+            &Array::init(vec![f64::MAX, f64::NEG_INFINITY, 1.0], [3]),
+            1e-5,
+            1e-8
+        ));
+        assert!(!Array::init(vec![f64::MAX], [1]).allclose(
+            &Array::init(vec![f64::INFINITY], [1]),
+            1e-5,
+            1e-8
+        ));
+    }
+
+    #[test]
+    fn assert_allclose() {
+        let array = Array::init(vec![1.0, 2.0, 3.0, 4.0], [2, 2]);
+
+        crate::assert_allclose!(
+            array.t(),
+            Array::init(vec![1.0, 3.0, 2.0, 4.0 + 1e-7], [2, 2])
+        );
+        crate::assert_allclose!(
+            array,
+            Array::init(vec![1.1, 2.2, 3.3, 4.4], [2, 2]),
+            0.1,
+            0.0
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Arrays are not close at index [1, 0]: 3.0 != 3.5 (rtol = 1e-5, atol = 1e-8)"
+    )]
+    fn assert_allclose_reports_index() {
+        let array = Array::init(vec![1.0, 2.0, 3.0, 4.0], [2, 2]);
+
+        crate::assert_allclose!(array, Array::init(vec![1.0, 2.0, 3.5, 4.5], [2, 2]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Shapes of the arrays do not match: [2, 2] != [4, 1]")]
+    fn assert_allclose_shape_mismatch() {
+        let array = Array::init(vec![1.0, 2.0, 3.0, 4.0], [2, 2]);
+
+        crate::assert_allclose!(array, Array::init(vec![1.0, 2.0, 3.0, 4.0], [4, 1]));
+    }
+}
@@ -0,0 +1,151 @@
+use std::ops::Neg;
+
+use num_complex::Complex;
+use num_traits::{Float, Num};
+
+use crate::{Array, ArrayBase, Storage, StorageMut, View, ViewMut};
+
+impl<T, S: Storage<Elem = Complex<T>>, const D: usize> ArrayBase<S, D> {
+    pub fn conj(&self) -> Array<Complex<T>, D>
+    where
+        T: Clone + Num + Neg<Output = T>,
+    {
+        self.map(Complex::conj)
+    }
+
+    pub fn abs(&self) -> Array<T, D>
+    where
+        T: Float,
+    {
+        self.map(|val| val.norm())
+    }
+
+    pub fn re(&self) -> View<'_, T, D> {
+        self.part(0)
+    }
+
+    pub fn im(&self) -> View<'_, T, D> {
+        self.part(1)
+    }
+
+    // `Complex` is `repr(C)`, so its buffer is also a buffer of interleaved real and
+    // imaginary parts, which only doubles the strides.
+    fn part(&self, part: usize) -> View<'_, T, D> {
+        let data = self.data.as_slice();
+
+        // Safety: every complex number is made of exactly two values of `T`
+        let parts =
+            unsafe { std::slice::from_raw_parts(data.as_ptr() as *const T, data.len() * 2) };
+
+        ArrayBase {
+            data: parts,
+            shape: self.shape,
+            strides: self.strides.map(|stride| stride * 2),
+            offset: self.offset * 2 + part,
+        }
+    }
+}
+
+impl<T, S: StorageMut<Elem = Complex<T>>, const D: usize> ArrayBase<S, D> {
+    pub fn re_mut(&mut self) -> ViewMut<'_, T, D> {
+        self.part_mut(0)
+    }
+
+    pub fn im_mut(&mut self) -> ViewMut<'_, T, D> {
+        self.part_mut(1)
+    }
+
+    fn part_mut(&mut self, part: usize) -> ViewMut<'_, T, D> {
+        let data = self.data.as_mut_slice();
+
+        // Safety: every complex number is made of exactly two values of `T`
+        let parts =
+            unsafe { std::slice::from_raw_parts_mut(data.as_mut_ptr() as *mut T, data.len() * 2) };
+
+        ArrayBase {
+            data: parts,
+            shape: self.shape,
+            strides: self.strides.map(|stride| stride * 2),
+            offset: self.offset * 2 + part,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_complex::Complex64;
+
+    use super::*;
+    use crate::Dot;
+
+    fn c(re: f64, im: f64) -> Complex64 {
+        Complex64::new(re, im)
+    }
+
+    #[test]
+    fn arithmetic_and_reductions() {
+        let array = Array::init(vec![c(1.0, 2.0), c(3.0, -1.0)], [2]);
+
+        assert_eq!(
+            &array + &array,
+            Array::init(vec![c(2.0, 4.0), c(6.0, -2.0)], [2])
+        );
+        assert_eq!(
+            &array * c(0.0, 1.0),
+            Array::init(vec![c(-2.0, 1.0), c(1.0, 3.0)], [2])
+        );
+        assert_eq!(
+            c(2.0, 0.0) * &array,
+            Array::init(vec![c(2.0, 4.0), c(6.0, -2.0)], [2])
+        );
+        assert_eq!(array.sum(), c(4.0, 1.0));
+        assert_eq!(array.prod(), c(5.0, 5.0));
+        assert_eq!(array.mean(), c(2.0, 0.5));
+        assert_eq!(array.dot(&array.conj()), c(15.0, 0.0));
+
+        let matrix = Array::init(
+            vec![c(0.0, 1.0), c(1.0, 0.0), c(1.0, 0.0), c(0.0, -1.0)],
+            [2, 2],
+        );
+
+        assert_eq!(
+            matrix.dot(&matrix),
+            Array::init(
+                vec![c(0.0, 0.0), c(0.0, 0.0), c(0.0, 0.0), c(0.0, 0.0)],
+                [2, 2]
+            )
+        );
+    }
+
+    #[test]
+    fn conj_and_abs() {
+        let array = Array::init(vec![c(3.0, 4.0), c(0.0, -2.0)], [2]);
+
+        assert_eq!(
+            array.conj(),
+            Array::init(vec![c(3.0, -4.0), c(0.0, 2.0)], [2])
+        );
+        assert_eq!(array.abs(), Array::init(vec![5.0, 2.0], [2]));
+    }
+
+    #[test]
+    fn parts() {
+        let mut array = Array::from_shape_fn([2, 2], |[i, j]| c(i as f64, j as f64));
+
+        assert_eq!(array.re(), Array::init(vec![0.0, 0.0, 1.0, 1.0], [2, 2]));
+        assert_eq!(
+            array.t().im(),
+            Array::init(vec![0.0, 0.0, 1.0, 1.0], [2, 2])
+        );
+        assert_eq!(
+            array.flip(1).im(),
+            Array::init(vec![1.0, 0.0, 1.0, 0.0], [2, 2])
+        );
+
+        array.im_mut().map_inplace(|im| im * 10.0);
+        array.slice_mut(&crate::s![1.., ..]).re_mut()[[0, 1]] = 5.0;
+
+        assert_eq!(array[[1, 1]], c(5.0, 10.0));
+        assert_eq!(array[[0, 1]], c(0.0, 10.0));
+    }
+}
@@ -0,0 +1,137 @@
+use std::borrow::Cow;
+
+use crate::{Array, ArrayBase, Storage, StorageMut, View};
+
+impl<T: Clone> Storage for Cow<'_, [T]> {
+    type Elem = T;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+// Writing to borrowed elements copies all of them first
+impl<T: Clone> StorageMut for Cow<'_, [T]> {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self.to_mut()
+    }
+}
+
+// An array that either borrows its elements or owns them, for functions that only
+// sometimes have to copy
+pub type CowArray<'a, T, const D: usize> = ArrayBase<Cow<'a, [T]>, D>;
+
+// Views whose elements alias each other are copied right away, since writing to the
+// copy of their data would write through several positions at once
+impl<'a, T: Clone, const D: usize> From<View<'a, T, D>> for CowArray<'a, T, D> {
+    fn from(view: View<'a, T, D>) -> Self {
+        if !view.is_unaliased() {
+            return view.to_owned().into();
+        }
+
+        ArrayBase {
+            data: Cow::Borrowed(view.data),
+            shape: view.shape,
+            strides: view.strides,
+            offset: view.offset,
+        }
+    }
+}
+
+impl<T: Clone, const D: usize> From<Array<T, D>> for CowArray<'_, T, D> {
+    fn from(array: Array<T, D>) -> Self {
+        ArrayBase {
+            data: Cow::Owned(array.data),
+            shape: array.shape,
+            strides: array.strides,
+            offset: array.offset,
+        }
+    }
+}
+
+impl<'a, T: Clone, const D: usize> CowArray<'a, T, D> {
+    pub fn is_view(&self) -> bool {
+        matches!(self.data, Cow::Borrowed(_))
+    }
+
+    pub fn is_owned(&self) -> bool {
+        !self.is_view()
+    }
+
+    // Takes the owned elements if they are in standard layout, or copies them into it
+    pub fn into_owned(self) -> Array<T, D> {
+        if self.is_view() || !self.is_standard_layout() {
+            return self.to_owned();
+        }
+
+        ArrayBase {
+            data: self.data.into_owned(),
+            shape: self.shape,
+            strides: self.strides,
+            offset: self.offset,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s;
+
+    // Only copies arrays that have negative elements
+    fn non_negative(array: View<'_, i32, 2>) -> CowArray<'_, i32, 2> {
+        if array.flat().all(|val| *val >= 0) {
+            return array.into();
+        }
+
+        array.map(|val| (*val).max(0)).into()
+    }
+
+    #[test]
+    fn borrowed_until_written() {
+        let array = Array::from_shape_fn([3, 4], |[i, j]| i as i32 * 4 + j as i32 - 2);
+
+        let positive = non_negative(array.slice(&s![1.., ..]));
+        let clamped = non_negative(array.view());
+
+        assert!(positive.is_view());
+        assert!(clamped.is_owned());
+        assert_eq!(clamped.sum(), 1 + 2 + 3 + 4 + 5 + 6 + 7 + 8 + 9);
+
+        let mut written = positive.clone();
+        written[[0, 0]] = -1;
+
+        assert!(written.is_owned());
+        assert_eq!(written.sum(), positive.sum() - 3);
+        assert_eq!(positive.t().to_owned(), array.slice(&s![1.., ..]).t());
+
+        assert_eq!(positive.into_owned(), array.slice(&s![1.., ..]));
+        assert_eq!(written.into_owned()[[1, 3]], 9);
+    }
+
+    #[test]
+    fn broadcast_views() {
+        let row = Array::init(vec![1, 2, 3], [1, 3]);
+
+        let mut broadcast: CowArray<'_, i32, 2> = row.broadcast_to([2, 3]).unwrap().into();
+
+        assert!(broadcast.is_owned());
+        assert_eq!(broadcast.strides(), &[3, 1]);
+
+        for val in broadcast.flat_mut() {
+            *val *= 10;
+        }
+        broadcast[[0, 0]] = 100;
+
+        let owned = broadcast.into_owned();
+
+        assert_eq!(owned.strides(), &[3, 1]);
+        assert_eq!(owned, Array::init(vec![100, 20, 30, 10, 20, 30], [2, 3]));
+
+        let transposed: CowArray<'_, i32, 2> = owned.reversed_axes().into();
+        let owned = transposed.into_owned();
+
+        assert_eq!(owned.strides(), &[2, 1]);
+        assert_eq!(owned, Array::init(vec![100, 10, 20, 20, 30, 30], [3, 2]));
+    }
+}
@@ -0,0 +1,277 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::str::FromStr;
+
+use crate::{Array, ArrayBase, Storage};
+
+#[derive(Debug)]
+pub enum CsvError {
+    Io(io::Error),
+    // `line` counts from 1 and includes the header
+    Parse {
+        line: usize,
+        column: usize,
+        value: String,
+    },
+    RaggedRow {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    HeaderMismatch {
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::Io(error) => write!(f, "I/O error: {}", error),
+            CsvError::Parse {
+                line,
+                column,
+                value,
+            } => write!(
+                f,
+                "Invalid number {:?} at line {}, column {}",
+                value, line, column
+            ),
+            CsvError::RaggedRow {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Number of fields at line {} does not match the first row: {} != {}",
+                line, found, expected
+            ),
+            CsvError::HeaderMismatch { expected, found } => write!(
+                f,
+                "Number of header names does not match the number of columns: {} != {}",
+                found, expected
+            ),
+        }
+    }
+}
+
+impl Error for CsvError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CsvError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CsvError {
+    fn from(error: io::Error) -> Self {
+        CsvError::Io(error)
+    }
+}
+
+impl<T: FromStr> Array<T, 2> {
+    // Every non-empty line is a row, the first one is skipped if `header` is set
+    pub fn from_csv<R: Read>(reader: R, delimiter: char, header: bool) -> Result<Self, CsvError> {
+        let mut vec = vec![];
+        let mut shape = [0, 0];
+
+        for (idx, line) in BufReader::new(reader).lines().enumerate() {
+            let line = line?;
+            if (header && idx == 0) || line.trim().is_empty() {
+                continue;
+            }
+
+            let len = vec.len();
+            for (column, field) in line.split(delimiter).enumerate() {
+                let field = field.trim();
+                let elem = field.parse().map_err(|_| CsvError::Parse {
+                    line: idx + 1,
+                    column: column + 1,
+                    value: field.to_string(),
+                })?;
+
+                vec.push(elem);
+            }
+
+            let found = vec.len() - len;
+            if shape[0] == 0 {
+                shape[1] = found;
+            } else if found != shape[1] {
+                return Err(CsvError::RaggedRow {
+                    line: idx + 1,
+                    expected: shape[1],
+                    found,
+                });
+            }
+
+            shape[0] += 1;
+        }
+
+        Ok(Array::init(vec, shape))
+    }
+}
+
+impl<T: fmt::Display, S: Storage<Elem = T>> ArrayBase<S, 2> {
+    pub fn to_csv<W: Write>(
+        &self,
+        writer: W,
+        delimiter: char,
+        header: Option<&[&str]>,
+    ) -> Result<(), CsvError> {
+        let [rows, cols] = self.shape;
+        let mut writer = BufWriter::new(writer);
+
+        if let Some(names) = header {
+            if names.len() != cols {
+                return Err(CsvError::HeaderMismatch {
+                    expected: cols,
+                    found: names.len(),
+                });
+            }
+
+            write_row(&mut writer, delimiter, names.iter())?;
+        }
+
+        for row in 0..rows {
+            write_row(
+                &mut writer,
+                delimiter,
+                (0..cols).map(|col| &self[[row, col]]),
+            )?;
+        }
+
+        Ok(writer.flush()?)
+    }
+}
+
+fn write_row<W: Write, T: fmt::Display, I: Iterator<Item = T>>(
+    writer: &mut W,
+    delimiter: char,
+    fields: I,
+) -> io::Result<()> {
+    for (idx, field) in fields.enumerate() {
+        if idx > 0 {
+            write!(writer, "{}", delimiter)?;
+        }
+
+        write!(writer, "{}", field)?;
+    }
+
+    writeln!(writer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read() {
+        let csv = "x,y,z\n1, 2.5, -3\n4,5,6e2\n\n";
+
+        let array = Array::<f64, 2>::from_csv(csv.as_bytes(), ',', true).unwrap();
+
+        assert_eq!(
+            array,
+            Array::init(vec![1.0, 2.5, -3.0, 4.0, 5.0, 600.0], [2, 3])
+        );
+    }
+
+    #[test]
+    fn read_delimiter() {
+        let csv = "1;2\r\n3;4\r\n";
+
+        let array = Array::<f64, 2>::from_csv(csv.as_bytes(), ';', false).unwrap();
+
+        assert_eq!(array, Array::init(vec![1.0, 2.0, 3.0, 4.0], [2, 2]));
+    }
+
+    #[test]
+    fn read_empty() {
+        let array = Array::<f64, 2>::from_csv("a,b\n".as_bytes(), ',', true).unwrap();
+
+        assert_eq!(array.shape(), &[0, 0]);
+    }
+
+    #[test]
+    fn read_errors() {
+        let result = Array::<f64, 2>::from_csv("a,b\n1,2\n3,x\n".as_bytes(), ',', true);
+
+        assert!(matches!(
+            result,
+            Err(CsvError::Parse { line: 3, column: 2, ref value }) if value == "x"
+        ));
+
+        // Without skipping it the header is parsed as numbers
+        let result = Array::<f64, 2>::from_csv("a,b\n1,2\n".as_bytes(), ',', false);
+
+        assert!(matches!(
+            result,
+            Err(CsvError::Parse {
+                line: 1,
+                column: 1,
+                ..
+            })
+        ));
+
+        let result = Array::<f64, 2>::from_csv("1,2\n3\n".as_bytes(), ',', false);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Number of fields at line 2 does not match the first row: 1 != 2"
+        );
+    }
+
+    #[test]
+    fn write() {
+        let array = Array::init(vec![1.0, 2.5, -3.0, 4.0, 5.0, 0.1], [2, 3]);
+
+        let mut csv = vec![];
+        array.to_csv(&mut csv, ',', Some(&["x", "y", "z"])).unwrap();
+
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "x,y,z\n1,2.5,-3\n4,5,0.1\n"
+        );
+
+        let mut csv = vec![];
+        array.t().to_csv(&mut csv, '\t', None).unwrap();
+
+        assert_eq!(String::from_utf8(csv).unwrap(), "1\t4\n2.5\t5\n-3\t0.1\n");
+    }
+
+    #[test]
+    fn round_trip() {
+        let array = Array::from_shape_fn([4, 3], |[i, j]| (i as f64 + 1.0) / (j as f64 + 3.0));
+
+        let mut csv = vec![];
+        array.to_csv(&mut csv, ',', None).unwrap();
+
+        assert_eq!(
+            Array::<f64, 2>::from_csv(csv.as_slice(), ',', false).unwrap(),
+            array
+        );
+    }
+
+    #[test]
+    fn write_header_mismatch() {
+        let array = Array::<f64, 2>::zeros([1, 2]);
+
+        let mut csv = vec![];
+        let result = array.to_csv(&mut csv, ',', Some(&["x"]));
+
+        assert!(matches!(
+            result,
+            Err(CsvError::HeaderMismatch {
+                expected: 2,
+                found: 1
+            })
+        ));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Number of header names does not match the number of columns: 1 != 2"
+        );
+        assert!(csv.is_empty());
+    }
+}
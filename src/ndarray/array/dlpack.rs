@@ -0,0 +1,410 @@
+use std::error::Error;
+use std::ffi::c_void;
+use std::fmt;
+use std::os::raw::c_char;
+
+use pyo3::types::PyCapsule;
+use pyo3::{ffi, Bound, PyResult, Python};
+
+use crate::{Array, View};
+
+// C ABI of DLPack, see https://dmlc.github.io/dlpack/latest/c_api.html
+
+pub const DL_CPU: i32 = 1;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DLDevice {
+    pub device_type: i32,
+    pub device_id: i32,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DLDataType {
+    pub code: u8,
+    pub bits: u8,
+    pub lanes: u16,
+}
+
+// Strides are counted in elements, null strides describe a row-major tensor
+#[repr(C)]
+#[derive(Debug)]
+pub struct DLTensor {
+    pub data: *mut c_void,
+    pub device: DLDevice,
+    pub ndim: i32,
+    pub dtype: DLDataType,
+    pub shape: *mut i64,
+    pub strides: *mut i64,
+    pub byte_offset: u64,
+}
+
+#[repr(C)]
+#[derive(Debug)]
+pub struct DLManagedTensor {
+    pub dl_tensor: DLTensor,
+    pub manager_ctx: *mut c_void,
+    pub deleter: Option<unsafe extern "C" fn(*mut DLManagedTensor)>,
+}
+
+pub trait DlpackElement: Copy {
+    const DTYPE: DLDataType;
+}
+
+macro_rules! impl_dlpack_element {
+    ($($ty:ty => $code:expr),*) => {
+        $(impl DlpackElement for $ty {
+            const DTYPE: DLDataType = DLDataType {
+                code: $code,
+                bits: (std::mem::size_of::<$ty>() * 8) as u8,
+                lanes: 1,
+            };
+        })*
+    };
+}
+
+// Type codes of signed integers, unsigned integers and floats
+impl_dlpack_element!(
+    i8 => 0, i16 => 0, i32 => 0, i64 => 0,
+    u8 => 1, u16 => 1, u32 => 1, u64 => 1,
+    f32 => 2, f64 => 2
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DlpackError {
+    DtypeMismatch {
+        expected: DLDataType,
+        found: DLDataType,
+    },
+    RankMismatch {
+        expected: usize,
+        found: usize,
+    },
+    Device(DLDevice),
+    InvalidCapsule,
+}
+
+impl fmt::Display for DlpackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DlpackError::DtypeMismatch { expected, found } => write!(
+                f,
+                "Data type of the tensor does not match the array: {:?} != {:?}",
+                found, expected
+            ),
+            DlpackError::RankMismatch { expected, found } => write!(
+                f,
+                "Number of axes of the tensor does not match the array: {} != {}",
+                found, expected
+            ),
+            DlpackError::Device(device) => write!(
+                f,
+                "Only tensors in CPU memory can be read, found device {:?}",
+                device
+            ),
+            DlpackError::InvalidCapsule => {
+                write!(f, "Capsule does not hold an unused DLPack tensor")
+            }
+        }
+    }
+}
+
+impl Error for DlpackError {}
+
+// Owns the array and the shape and strides that the exported tensor points at
+struct ManagerContext<T, const D: usize> {
+    array: Array<T, D>,
+    shape: [i64; D],
+    strides: [i64; D],
+}
+
+unsafe extern "C" fn delete_managed<T, const D: usize>(tensor: *mut DLManagedTensor) {
+    let tensor = Box::from_raw(tensor);
+
+    drop(Box::from_raw(
+        tensor.manager_ctx as *mut ManagerContext<T, D>,
+    ));
+}
+
+impl<T: DlpackElement, const D: usize> Array<T, D> {
+    // The tensor points into the vector of the array, which is freed once the
+    // consumer calls the deleter of the tensor.
+    pub fn into_dlpack(self) -> *mut DLManagedTensor {
+        let mut shape = [0; D];
+        let mut strides = [0; D];
+        for axis in 0..D {
+            shape[axis] = self.shape[axis] as i64;
+            strides[axis] = self.strides[axis] as i64;
+        }
+
+        let mut context = Box::new(ManagerContext {
+            array: self,
+            shape,
+            strides,
+        });
+
+        // Consumers expect the offset to be applied to the data pointer on CPUs
+        let data = context
+            .array
+            .data
+            .as_mut_ptr()
+            .wrapping_add(context.array.offset);
+
+        let tensor = DLTensor {
+            data: data as *mut c_void,
+            device: DLDevice {
+                device_type: DL_CPU,
+                device_id: 0,
+            },
+            ndim: D as i32,
+            dtype: T::DTYPE,
+            shape: context.shape.as_mut_ptr(),
+            strides: context.strides.as_mut_ptr(),
+            byte_offset: 0,
+        };
+
+        Box::into_raw(Box::new(DLManagedTensor {
+            dl_tensor: tensor,
+            manager_ctx: Box::into_raw(context) as *mut c_void,
+            deleter: Some(delete_managed::<T, D>),
+        }))
+    }
+
+    // Copies the elements of the tensor and hands it back to its producer
+    /// # Safety
+    ///
+    /// `tensor` must point at a valid managed tensor that is not used afterwards.
+    pub unsafe fn from_dlpack(tensor: *mut DLManagedTensor) -> Result<Self, DlpackError> {
+        let array = View::from_dlpack(&(*tensor).dl_tensor).map(|view| view.to_owned());
+
+        if let Some(deleter) = (*tensor).deleter {
+            deleter(tensor);
+        }
+
+        array
+    }
+}
+
+impl<'a, T: DlpackElement, const D: usize> View<'a, T, D> {
+    // Borrows the memory of the tensor without copying
+    /// # Safety
+    ///
+    /// The memory of the tensor must stay valid and unchanged for `'a`.
+    pub unsafe fn from_dlpack(tensor: &'a DLTensor) -> Result<Self, DlpackError> {
+        if tensor.device.device_type != DL_CPU {
+            return Err(DlpackError::Device(tensor.device));
+        }
+
+        if tensor.dtype != T::DTYPE {
+            return Err(DlpackError::DtypeMismatch {
+                expected: T::DTYPE,
+                found: tensor.dtype,
+            });
+        }
+
+        if tensor.ndim as usize != D {
+            return Err(DlpackError::RankMismatch {
+                expected: D,
+                found: tensor.ndim as usize,
+            });
+        }
+
+        let mut shape = [0; D];
+        for (axis, len) in shape.iter_mut().enumerate() {
+            *len = *tensor.shape.add(axis) as usize;
+        }
+
+        let mut strides = [0; D];
+        let mut stride = 1;
+        for axis in (0..D).rev() {
+            strides[axis] = if tensor.strides.is_null() {
+                stride
+            } else {
+                *tensor.strides.add(axis) as isize
+            };
+
+            stride *= shape[axis] as isize;
+        }
+
+        let first = (tensor.data as *const u8).add(tensor.byte_offset as usize) as *const T;
+
+        Ok(View::from_raw_parts(first, shape, strides))
+    }
+}
+
+const DLTENSOR: &[u8] = b"dltensor\0";
+const USED_DLTENSOR: &[u8] = b"used_dltensor\0";
+
+// Capsules that were never consumed still own their tensor
+unsafe extern "C" fn destroy_capsule(capsule: *mut ffi::PyObject) {
+    let name = DLTENSOR.as_ptr() as *const c_char;
+
+    if ffi::PyCapsule_IsValid(capsule, name) == 1 {
+        let tensor = ffi::PyCapsule_GetPointer(capsule, name) as *mut DLManagedTensor;
+
+        if let Some(deleter) = (*tensor).deleter {
+            deleter(tensor);
+        }
+    }
+}
+
+fn capsule_tensor(capsule: &Bound<'_, PyCapsule>) -> Result<*mut DLManagedTensor, DlpackError> {
+    let name = DLTENSOR.as_ptr() as *const c_char;
+
+    unsafe {
+        if ffi::PyCapsule_IsValid(capsule.as_ptr(), name) == 1 {
+            Ok(ffi::PyCapsule_GetPointer(capsule.as_ptr(), name) as *mut DLManagedTensor)
+        } else {
+            Err(DlpackError::InvalidCapsule)
+        }
+    }
+}
+
+impl<T: DlpackElement, const D: usize> Array<T, D> {
+    // Wraps the tensor in a `dltensor` capsule, as returned by `__dlpack__`
+    pub fn into_dlpack_capsule(self, py: Python<'_>) -> PyResult<Bound<'_, PyCapsule>> {
+        let tensor = self.into_dlpack();
+
+        unsafe {
+            let capsule = ffi::PyCapsule_New(
+                tensor as *mut c_void,
+                DLTENSOR.as_ptr() as *const c_char,
+                Some(destroy_capsule),
+            );
+
+            if capsule.is_null() {
+                delete_managed::<T, D>(tensor);
+            }
+
+            Ok(Bound::from_owned_ptr_or_err(py, capsule)?.cast_into_unchecked())
+        }
+    }
+
+    // Consumes the capsule the way the protocol asks for, by renaming it before taking
+    // over its tensor. A tensor that does not match the array is left to the capsule.
+    /// # Safety
+    ///
+    /// The capsule must hold a valid managed tensor, as the capsules of `__dlpack__` do.
+    pub unsafe fn from_dlpack_capsule(capsule: &Bound<'_, PyCapsule>) -> Result<Self, DlpackError> {
+        let tensor = capsule_tensor(capsule)?;
+        let array = View::<T, D>::from_dlpack(&(*tensor).dl_tensor)?.to_owned();
+
+        ffi::PyCapsule_SetName(capsule.as_ptr(), USED_DLTENSOR.as_ptr() as *const c_char);
+
+        if let Some(deleter) = (*tensor).deleter {
+            deleter(tensor);
+        }
+
+        Ok(array)
+    }
+}
+
+impl<'a, T: DlpackElement, const D: usize> View<'a, T, D> {
+    // The capsule keeps owning its tensor
+    /// # Safety
+    ///
+    /// The capsule may not be consumed while the view lives, neither by
+    /// `Array::from_dlpack_capsule` nor by another library, since its tensor is then
+    /// handed over and may be freed.
+    pub unsafe fn from_dlpack_capsule(
+        capsule: &'a Bound<'_, PyCapsule>,
+    ) -> Result<Self, DlpackError> {
+        let tensor = capsule_tensor(capsule)?;
+
+        View::from_dlpack(&(*tensor).dl_tensor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let array = Array::arange(0..6).reshape([2, 3]).unwrap().flip_move(1);
+        let ptr = &array[[0, 0]] as *const i32;
+
+        let tensor = array.into_dlpack();
+
+        unsafe {
+            assert_eq!((*tensor).dl_tensor.data as *const i32, ptr);
+            assert_eq!(*(*tensor).dl_tensor.strides.add(1), -1);
+
+            let view = View::<i32, 2>::from_dlpack(&(*tensor).dl_tensor).unwrap();
+
+            assert_eq!(view, Array::init(vec![2, 1, 0, 5, 4, 3], [2, 3]));
+            assert_eq!(&view[[0, 0]] as *const i32, ptr);
+            assert_eq!(
+                View::<f32, 2>::from_dlpack(&(*tensor).dl_tensor).err(),
+                Some(DlpackError::DtypeMismatch {
+                    expected: f32::DTYPE,
+                    found: i32::DTYPE
+                })
+            );
+
+            let array = Array::<i32, 2>::from_dlpack(tensor).unwrap();
+
+            assert_eq!(array, Array::init(vec![2, 1, 0, 5, 4, 3], [2, 3]));
+        }
+    }
+
+    #[test]
+    fn row_major_without_strides() {
+        let mut data = vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let mut shape = [3, 2];
+
+        let tensor = DLTensor {
+            data: data.as_mut_ptr() as *mut c_void,
+            device: DLDevice {
+                device_type: DL_CPU,
+                device_id: 0,
+            },
+            ndim: 2,
+            dtype: f64::DTYPE,
+            shape: shape.as_mut_ptr(),
+            strides: std::ptr::null_mut(),
+            byte_offset: 8,
+        };
+
+        let view = unsafe { View::<f64, 2>::from_dlpack(&tensor).unwrap() };
+
+        assert_eq!(view.strides(), &[2, 1]);
+        assert_eq!(view[[2, 0]], 5.0);
+    }
+
+    #[test]
+    fn capsules() {
+        Python::initialize();
+        Python::attach(|py| {
+            let capsule = Array::arange(0..4).into_dlpack_capsule(py).unwrap();
+
+            {
+                let view = unsafe { View::<i32, 1>::from_dlpack_capsule(&capsule).unwrap() };
+
+                assert_eq!(view, Array::arange(0..4));
+            }
+
+            // a mismatch leaves the capsule unconsumed
+            assert_eq!(
+                unsafe { Array::<f32, 1>::from_dlpack_capsule(&capsule) }.err(),
+                Some(DlpackError::DtypeMismatch {
+                    expected: f32::DTYPE,
+                    found: i32::DTYPE
+                })
+            );
+            assert!(matches!(
+                unsafe { Array::<i32, 2>::from_dlpack_capsule(&capsule) },
+                Err(DlpackError::RankMismatch { .. })
+            ));
+
+            let array = unsafe { Array::<i32, 1>::from_dlpack_capsule(&capsule) }.unwrap();
+
+            assert_eq!(array, Array::arange(0..4));
+            assert_eq!(
+                unsafe { Array::<i32, 1>::from_dlpack_capsule(&capsule) }.err(),
+                Some(DlpackError::InvalidCapsule)
+            );
+        });
+    }
+}
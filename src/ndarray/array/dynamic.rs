@@ -0,0 +1,656 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::ops::{Add, Index, IndexMut, Range};
+use std::path::Path;
+use std::slice;
+
+use num_traits::{One, Zero};
+use smallvec::SmallVec;
+
+use super::access::resolve_slice;
+use super::format::{write_debug, write_display};
+use super::iter::Indices;
+use super::npy::{read_npy_parts, write_npy_parts};
+use super::{checked_len, contiguous_range, len_of, offset_at};
+use crate::{
+    ArrayBase, IndexError, NpyElement, NpyError, ShapeError, Slice, SliceError, Storage, StorageMut,
+};
+
+// Shapes, strides and multi-indices of up to 4 axes are kept inline
+pub(crate) type DynIndices = SmallVec<[usize; 4]>;
+
+type DynStrides = SmallVec<[isize; 4]>;
+
+// Like `ArrayBase`, but the number of axes is only known at runtime
+#[derive(Clone)]
+pub struct DynArrayBase<S> {
+    data: S,
+    shape: DynIndices,
+    strides: DynStrides,
+    offset: usize,
+}
+
+pub type ArrayD<T> = DynArrayBase<Vec<T>>;
+
+pub type ViewD<'a, T> = DynArrayBase<&'a [T]>;
+
+pub type ViewMutD<'a, T> = DynArrayBase<&'a mut [T]>;
+
+fn dyn_strides(shape: &[usize]) -> DynStrides {
+    (0..shape.len())
+        .map(|axis| shape[axis + 1..].iter().product::<usize>() as isize)
+        .collect()
+}
+
+impl<T> ArrayD<T> {
+    pub fn init(vec: Vec<T>, shape: &[usize]) -> Self {
+        Self::try_init(vec, shape).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    pub fn try_init(vec: Vec<T>, shape: &[usize]) -> Result<Self, ShapeError> {
+        let expected = checked_len(shape)?;
+
+        if expected != vec.len() {
+            return Err(ShapeError::IncompatibleSize {
+                len: vec.len(),
+                expected,
+            });
+        }
+
+        Ok(DynArrayBase {
+            data: vec,
+            shape: shape.into(),
+            strides: dyn_strides(shape),
+            offset: 0,
+        })
+    }
+
+    pub fn from_shape_fn<F: FnMut(&[usize]) -> T>(shape: &[usize], mut f: F) -> Self {
+        let mut vec = Vec::with_capacity(len_of(shape));
+
+        let mut indices = Indices::init(DynIndices::from(shape));
+        while let Some(idx) = indices.next_front() {
+            vec.push(f(&idx));
+        }
+
+        ArrayD::init(vec, shape)
+    }
+}
+
+impl<T: Clone> ArrayD<T> {
+    pub fn full(val: T, shape: &[usize]) -> Self {
+        ArrayD::init(vec![val; len_of(shape)], shape)
+    }
+}
+
+impl<T: Clone + Zero> ArrayD<T> {
+    pub fn zeros(shape: &[usize]) -> Self {
+        ArrayD::full(T::zero(), shape)
+    }
+}
+
+impl<T: Clone + One> ArrayD<T> {
+    pub fn ones(shape: &[usize]) -> Self {
+        ArrayD::full(T::one(), shape)
+    }
+}
+
+impl<T: NpyElement> ArrayD<T> {
+    pub fn read_npy<P: AsRef<Path>>(path: P) -> Result<Self, NpyError> {
+        ArrayD::read_npy_from(BufReader::new(File::open(path)?))
+    }
+
+    pub fn read_npy_from<R: Read>(reader: R) -> Result<Self, NpyError> {
+        let (mut shape, fortran_order, vec) = read_npy_parts(reader)?;
+
+        // Fortran ordered data is the row-major data of the transposed array
+        if fortran_order {
+            shape.reverse();
+
+            Ok(ArrayD::try_init(vec, &shape)?.reversed_axes())
+        } else {
+            Ok(ArrayD::try_init(vec, &shape)?)
+        }
+    }
+}
+
+impl<S, const D: usize> ArrayBase<S, D> {
+    pub fn into_dyn(self) -> DynArrayBase<S> {
+        DynArrayBase {
+            data: self.data,
+            shape: self.shape[..].into(),
+            strides: self.strides[..].into(),
+            offset: self.offset,
+        }
+    }
+}
+
+impl<S, const D: usize> From<ArrayBase<S, D>> for DynArrayBase<S> {
+    fn from(array: ArrayBase<S, D>) -> Self {
+        array.into_dyn()
+    }
+}
+
+impl<S> DynArrayBase<S> {
+    // Fails unless the array has exactly `D` axes
+    pub fn into_dimensionality<const D: usize>(self) -> Result<ArrayBase<S, D>, ShapeError> {
+        let shape = <[usize; D]>::try_from(&self.shape[..]);
+        let strides = <[isize; D]>::try_from(&self.strides[..]);
+
+        match (shape, strides) {
+            (Ok(shape), Ok(strides)) => Ok(ArrayBase {
+                data: self.data,
+                shape,
+                strides,
+                offset: self.offset,
+            }),
+            _ => Err(ShapeError::IncompatibleRank {
+                ndim: self.ndim(),
+                expected: D,
+            }),
+        }
+    }
+
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    pub fn strides(&self) -> &[isize] {
+        &self.strides
+    }
+
+    pub fn ndim(&self) -> usize {
+        self.shape.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn reversed_axes(mut self) -> Self {
+        self.shape.reverse();
+        self.strides.reverse();
+
+        self
+    }
+
+    fn check_index(&self, indices: &[usize]) -> Result<(), IndexError> {
+        // Missing indices are reported like an index past the end of their axis
+        if indices.len() != self.ndim() {
+            let axis = indices.len().min(self.ndim());
+
+            return Err(IndexError {
+                axis,
+                index: indices.get(axis).copied().unwrap_or(0),
+                len: self.shape.get(axis).copied().unwrap_or(0),
+            });
+        }
+
+        match (0..self.ndim()).find(|&axis| indices[axis] >= self.shape[axis]) {
+            Some(axis) => Err(IndexError {
+                axis,
+                index: indices[axis],
+                len: self.shape[axis],
+            }),
+            None => Ok(()),
+        }
+    }
+
+    fn index_offset(&self, indices: &[usize]) -> usize {
+        if self.check_index(indices).is_err() {
+            panic!(
+                "Index {:?} is out of bounds for array of shape {:?}",
+                indices, self.shape
+            );
+        }
+
+        offset_at(indices, &self.strides, self.offset)
+    }
+
+    fn contiguous_range(&self) -> Option<Range<usize>> {
+        contiguous_range(&self.shape, &self.strides, self.offset)
+    }
+
+    pub fn slice_move<I: Into<Slice> + Clone>(self, slice: &[I]) -> Self {
+        self.try_slice_move(slice)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    // Like numpy, axes past the end of `slice` are kept whole
+    pub fn try_slice_move<I: Into<Slice> + Clone>(
+        mut self,
+        slice: &[I],
+    ) -> Result<Self, SliceError> {
+        if slice.len() > self.ndim() {
+            return Err(SliceError::AxisOutOfBounds {
+                axis: self.ndim(),
+                ndim: self.ndim(),
+            });
+        }
+
+        for (axis, axis_slice) in slice.iter().enumerate() {
+            let (start, len, step) =
+                resolve_slice(axis, axis_slice.clone().into(), self.shape[axis])?;
+
+            if len > 0 {
+                self.offset = (self.offset as isize + start as isize * self.strides[axis]) as usize;
+            }
+
+            self.shape[axis] = len;
+            self.strides[axis] *= step as isize;
+        }
+
+        Ok(self)
+    }
+}
+
+impl<T, S: Storage<Elem = T>> DynArrayBase<S> {
+    pub fn view(&self) -> ViewD<'_, T> {
+        DynArrayBase {
+            data: self.data.as_slice(),
+            shape: self.shape.clone(),
+            strides: self.strides.clone(),
+            offset: self.offset,
+        }
+    }
+
+    pub fn get(&self, indices: &[usize]) -> Option<&T> {
+        self.try_get(indices).ok()
+    }
+
+    pub fn try_get(&self, indices: &[usize]) -> Result<&T, IndexError> {
+        self.check_index(indices)?;
+
+        Ok(&self.data.as_slice()[offset_at(indices, &self.strides, self.offset)])
+    }
+
+    pub fn flat(&self) -> IterD<'_, T> {
+        IterD::init(self.view())
+    }
+
+    pub fn slice<I: Into<Slice> + Clone>(&self, slice: &[I]) -> ViewD<'_, T> {
+        self.view().slice_move(slice)
+    }
+
+    pub fn try_slice<I: Into<Slice> + Clone>(
+        &self,
+        slice: &[I],
+    ) -> Result<ViewD<'_, T>, SliceError> {
+        self.view().try_slice_move(slice)
+    }
+
+    pub fn t(&self) -> ViewD<'_, T> {
+        self.view().reversed_axes()
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> ArrayD<U> {
+        ArrayD::init(self.flat().map(f).collect(), &self.shape)
+    }
+
+    pub fn to_owned(&self) -> ArrayD<T>
+    where
+        T: Clone,
+    {
+        self.map(T::clone)
+    }
+
+    pub fn sum(&self) -> T
+    where
+        T: Clone + Add<Output = T> + Zero,
+    {
+        self.flat().cloned().fold(T::zero(), |acc, val| acc + val)
+    }
+
+    pub fn write_npy<P: AsRef<Path>>(&self, path: P) -> Result<(), NpyError>
+    where
+        T: NpyElement,
+    {
+        let mut writer = BufWriter::new(File::create(path)?);
+
+        self.write_npy_to(&mut writer)?;
+
+        Ok(writer.flush()?)
+    }
+
+    pub fn write_npy_to<W: Write>(&self, writer: W) -> Result<(), NpyError>
+    where
+        T: NpyElement,
+    {
+        // Column-major arrays are written as they are laid out instead of being reordered
+        let transposed = self.t();
+        let fortran_order =
+            self.contiguous_range().is_none() && transposed.contiguous_range().is_some();
+
+        let source = if fortran_order {
+            transposed
+        } else {
+            self.view()
+        };
+
+        write_npy_parts(writer, &self.shape, fortran_order, source.flat())
+    }
+}
+
+impl<T, S: StorageMut<Elem = T>> DynArrayBase<S> {
+    pub fn view_mut(&mut self) -> ViewMutD<'_, T> {
+        DynArrayBase {
+            data: self.data.as_mut_slice(),
+            shape: self.shape.clone(),
+            strides: self.strides.clone(),
+            offset: self.offset,
+        }
+    }
+
+    pub fn get_mut(&mut self, indices: &[usize]) -> Option<&mut T> {
+        self.check_index(indices).ok()?;
+
+        let offset = offset_at(indices, &self.strides, self.offset);
+
+        Some(&mut self.data.as_mut_slice()[offset])
+    }
+
+    pub fn flat_mut(&mut self) -> IterMutD<'_, T> {
+        IterMutD::init(self)
+    }
+
+    pub fn slice_mut<I: Into<Slice> + Clone>(&mut self, slice: &[I]) -> ViewMutD<'_, T> {
+        self.view_mut().slice_move(slice)
+    }
+
+    pub fn map_inplace<F: FnMut(&T) -> T>(&mut self, mut f: F) {
+        self.flat_mut().for_each(|val| *val = f(val))
+    }
+}
+
+impl<T, S: Storage<Elem = T>> Index<&[usize]> for DynArrayBase<S> {
+    type Output = T;
+
+    fn index(&self, indices: &[usize]) -> &T {
+        &self.data.as_slice()[self.index_offset(indices)]
+    }
+}
+
+impl<T, S: StorageMut<Elem = T>> IndexMut<&[usize]> for DynArrayBase<S> {
+    fn index_mut(&mut self, indices: &[usize]) -> &mut T {
+        let offset = self.index_offset(indices);
+
+        &mut self.data.as_mut_slice()[offset]
+    }
+}
+
+impl<T, S: Storage<Elem = T>, const N: usize> Index<[usize; N]> for DynArrayBase<S> {
+    type Output = T;
+
+    fn index(&self, indices: [usize; N]) -> &T {
+        &self[&indices[..]]
+    }
+}
+
+impl<T, S: StorageMut<Elem = T>, const N: usize> IndexMut<[usize; N]> for DynArrayBase<S> {
+    fn index_mut(&mut self, indices: [usize; N]) -> &mut T {
+        &mut self[&indices[..]]
+    }
+}
+
+impl<A, B, S1, S2> PartialEq<DynArrayBase<S2>> for DynArrayBase<S1>
+where
+    A: PartialEq<B>,
+    S1: Storage<Elem = A>,
+    S2: Storage<Elem = B>,
+{
+    fn eq(&self, other: &DynArrayBase<S2>) -> bool {
+        self.shape == other.shape && self.flat().zip(other.flat()).all(|(a, b)| a == b)
+    }
+}
+
+impl<T: fmt::Display, S: Storage<Elem = T>> fmt::Display for DynArrayBase<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_display(
+            f,
+            self.data.as_slice(),
+            &self.shape,
+            &self.strides,
+            self.offset,
+        )
+    }
+}
+
+impl<T: fmt::Debug, S: Storage<Elem = T>> fmt::Debug for DynArrayBase<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_debug(
+            f,
+            self.data.as_slice(),
+            &self.shape,
+            &self.strides,
+            self.offset,
+        )
+    }
+}
+
+pub struct IterD<'a, T> {
+    kind: IterKind<'a, T>,
+}
+
+enum IterKind<'a, T> {
+    Contiguous(slice::Iter<'a, T>),
+    Strided {
+        view: ViewD<'a, T>,
+        indices: Box<Indices<DynIndices>>,
+    },
+}
+
+impl<'a, T> IterD<'a, T> {
+    fn init(view: ViewD<'a, T>) -> Self {
+        let kind = match view.contiguous_range() {
+            Some(contiguous) => IterKind::Contiguous(view.data[contiguous].iter()),
+            None => IterKind::Strided {
+                indices: Box::new(Indices::init(view.shape.clone())),
+                view,
+            },
+        };
+
+        IterD { kind }
+    }
+}
+
+impl<'a, T> Iterator for IterD<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.kind {
+            IterKind::Contiguous(iter) => iter.next(),
+            IterKind::Strided { view, indices } => {
+                let indices = indices.next_front()?;
+
+                Some(&view.data[offset_at(&indices, &view.strides, view.offset)])
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.kind {
+            IterKind::Contiguous(iter) => iter.size_hint(),
+            IterKind::Strided { indices, .. } => (indices.remaining, Some(indices.remaining)),
+        }
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterD<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match &mut self.kind {
+            IterKind::Contiguous(iter) => iter.next_back(),
+            IterKind::Strided { view, indices } => {
+                let indices = indices.next_back()?;
+
+                Some(&view.data[offset_at(&indices, &view.strides, view.offset)])
+            }
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterD<'a, T> {}
+
+impl<'a, T> FusedIterator for IterD<'a, T> {}
+
+pub struct IterMutD<'a, T> {
+    data: *mut T,
+    strides: DynStrides,
+    offset: usize,
+    indices: Indices<DynIndices>,
+    marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> IterMutD<'a, T> {
+    fn init<S: StorageMut<Elem = T>>(array: &'a mut DynArrayBase<S>) -> Self {
+        IterMutD {
+            data: array.data.as_mut_slice().as_mut_ptr(),
+            strides: array.strides.clone(),
+            offset: array.offset,
+            indices: Indices::init(array.shape.clone()),
+            marker: PhantomData,
+        }
+    }
+
+    fn get(&self, indices: &[usize]) -> &'a mut T {
+        // SAFETY: as for `IterMut`, the array is mutably borrowed for `'a` and every
+        // multi-index maps to its own position of the buffer and is visited at most once.
+        unsafe {
+            &mut *self
+                .data
+                .add(offset_at(indices, &self.strides, self.offset))
+        }
+    }
+}
+
+impl<'a, T> Iterator for IterMutD<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let indices = self.indices.next_front()?;
+
+        Some(self.get(&indices))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.indices.remaining, Some(self.indices.remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMutD<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let indices = self.indices.next_back()?;
+
+        Some(self.get(&indices))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterMutD<'a, T> {}
+
+impl<'a, T> FusedIterator for IterMutD<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{s, Array};
+
+    #[test]
+    fn runtime_rank() {
+        let shape = vec![2, 3, 4];
+        let mut array = ArrayD::from_shape_fn(&shape, |idx| idx[0] * 12 + idx[1] * 4 + idx[2]);
+
+        assert_eq!(array.ndim(), 3);
+        assert_eq!(array[[1, 2, 3]], 23);
+        assert_eq!(array.get(&[1, 2]), None);
+        assert_eq!(
+            array.try_get(&[0, 3, 0]),
+            Err(IndexError {
+                axis: 1,
+                index: 3,
+                len: 3
+            })
+        );
+
+        let view = array.slice(&s![.., 1..;2]);
+
+        assert_eq!(view.shape(), &[2, 1, 4]);
+        assert_eq!(
+            view.flat().rev().copied().collect::<Vec<_>>(),
+            vec![19, 18, 17, 16, 7, 6, 5, 4]
+        );
+        assert_eq!(
+            view.t().to_owned(),
+            ArrayD::init(vec![4, 16, 5, 17, 6, 18, 7, 19], &[4, 1, 2])
+        );
+        assert!(array.try_slice(&s![.., .., .., ..]).is_err());
+
+        array.slice_mut(&s![1..]).map_inplace(|val| val * 10);
+        array[&[0, 0, 0][..]] = 100;
+
+        assert_eq!(
+            array.sum(),
+            100 + (1..12).sum::<usize>() + 10 * (12..24).sum::<usize>()
+        );
+
+        let scalar = ArrayD::init(vec![5.0], &[]);
+
+        assert_eq!(scalar[[]], 5.0);
+        assert_eq!(scalar.len(), 1);
+    }
+
+    #[test]
+    fn format_like_fixed_rank() {
+        let array = Array::arange(0..6).reshape([2, 3]).unwrap();
+        let dynamic = ArrayD::init((0..6).collect(), &[2, 3]);
+
+        assert_eq!(dynamic.to_string(), array.to_string());
+        assert_eq!(format!("{:?}", dynamic.t()), format!("{:?}", array.t()));
+    }
+
+    #[test]
+    fn npy_round_trip() {
+        let array = ArrayD::from_shape_fn(&[2, 1, 3, 2], |idx| idx.iter().sum::<usize>() as f32);
+
+        let mut bytes = vec![];
+        array.t().write_npy_to(&mut bytes).unwrap();
+
+        let fixed = Array::<f32, 4>::read_npy_from(&bytes[..]).unwrap();
+        let dynamic = ArrayD::<f32>::read_npy_from(&bytes[..]).unwrap();
+
+        assert_eq!(dynamic, array.t());
+        assert_eq!(fixed[[1, 2, 0, 1]], dynamic[[1, 2, 0, 1]]);
+    }
+
+    #[test]
+    fn between_ranks() {
+        let array = Array::from_shape_fn([2, 3], |[i, j]| i * 3 + j);
+
+        let dynamic = array.t().into_dyn();
+
+        assert_eq!(dynamic.shape(), &[3, 2]);
+        assert_eq!(dynamic[[2, 1]], 5);
+
+        let fixed = dynamic.into_dimensionality::<2>().unwrap();
+
+        assert_eq!(fixed, array.t());
+        assert_eq!(fixed.strides(), array.t().strides());
+
+        let dynamic: ArrayD<usize> = array.into();
+
+        assert_eq!(
+            dynamic.clone().into_dimensionality::<3>().unwrap_err(),
+            ShapeError::IncompatibleRank {
+                ndim: 2,
+                expected: 3
+            }
+        );
+        assert_eq!(dynamic.into_dimensionality::<2>().unwrap().sum(), 15);
+    }
+}
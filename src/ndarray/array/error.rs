@@ -0,0 +1,235 @@
+use std::error::Error as StdError;
+use std::fmt;
+
+use crate::Slice;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShapeError {
+    IncompatibleSize {
+        len: usize,
+        expected: usize,
+    },
+    IncompatibleLayout,
+    IncompatibleRank {
+        ndim: usize,
+        expected: usize,
+    },
+    Indivisible {
+        axis: usize,
+        len: usize,
+        chunk: usize,
+    },
+    Overflow,
+}
+
+impl fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShapeError::IncompatibleSize { len, expected } => write!(
+                f,
+                "Number of elements does not match the shape: {} != {}",
+                len, expected
+            ),
+            ShapeError::IncompatibleLayout => {
+                write!(
+                    f,
+                    "Layout of the array does not allow the shape without copying"
+                )
+            }
+            ShapeError::IncompatibleRank { ndim, expected } => write!(
+                f,
+                "Number of axes does not match the array: {} != {}",
+                ndim, expected
+            ),
+            ShapeError::Indivisible { axis, len, chunk } => write!(
+                f,
+                "Length {} of axis {} is not a multiple of the chunk length {}",
+                len, axis, chunk
+            ),
+            ShapeError::Overflow => {
+                write!(f, "Number of elements of the shape exceeds isize::MAX")
+            }
+        }
+    }
+}
+
+impl StdError for ShapeError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexError {
+    pub axis: usize,
+    pub index: usize,
+    pub len: usize,
+}
+
+impl fmt::Display for IndexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Index {} is out of bounds for axis {} of length {}",
+            self.index, self.axis, self.len
+        )
+    }
+}
+
+impl StdError for IndexError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliceError {
+    AxisOutOfBounds {
+        axis: usize,
+        ndim: usize,
+    },
+    OutOfBounds {
+        axis: usize,
+        slice: Slice,
+        len: usize,
+    },
+    ZeroStep {
+        axis: usize,
+    },
+}
+
+impl fmt::Display for SliceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SliceError::AxisOutOfBounds { axis, ndim } => {
+                write!(f, "Axis out of bound: {} >= {}", axis, ndim)
+            }
+            SliceError::OutOfBounds { axis, slice, len } => write!(
+                f,
+                "Slice: {:?} is out of bounds for axis {} of length {}",
+                slice, axis, len
+            ),
+            SliceError::ZeroStep { axis } => {
+                write!(f, "Step of the slice for axis {} can not be zero", axis)
+            }
+        }
+    }
+}
+
+impl StdError for SliceError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BroadcastError {
+    pub lhs: Vec<usize>,
+    pub rhs: Vec<usize>,
+}
+
+impl fmt::Display for BroadcastError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Shapes can not be broadcast together: {:?} and {:?}",
+            self.lhs, self.rhs
+        )
+    }
+}
+
+impl StdError for BroadcastError {}
+
+// Shape, index, slice and broadcast errors all convert into this, so callers can use `?`
+// across array operations without naming each error type. File formats, memory maps and
+// conversions from other libraries keep their own error types, since the I/O and parser
+// errors they carry can neither be cloned nor compared.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Shape(ShapeError),
+    Index(IndexError),
+    Slice(SliceError),
+    Broadcast(BroadcastError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Shape(error) => error.fmt(f),
+            Error::Index(error) => error.fmt(f),
+            Error::Slice(error) => error.fmt(f),
+            Error::Broadcast(error) => error.fmt(f),
+        }
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Error::Shape(error) => Some(error),
+            Error::Index(error) => Some(error),
+            Error::Slice(error) => Some(error),
+            Error::Broadcast(error) => Some(error),
+        }
+    }
+}
+
+impl From<ShapeError> for Error {
+    fn from(error: ShapeError) -> Self {
+        Error::Shape(error)
+    }
+}
+
+impl From<IndexError> for Error {
+    fn from(error: IndexError) -> Self {
+        Error::Index(error)
+    }
+}
+
+impl From<SliceError> for Error {
+    fn from(error: SliceError) -> Self {
+        Error::Slice(error)
+    }
+}
+
+impl From<BroadcastError> for Error {
+    fn from(error: BroadcastError) -> Self {
+        Error::Broadcast(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{s, Array};
+
+    fn corner(vec: Vec<i32>, shape: [usize; 2]) -> Result<i32, Error> {
+        let array = Array::try_init(vec, shape)?;
+        let view = array.try_slice(&s![1.., 1..])?;
+        let broadcast = view.broadcast_to([2, 2])?;
+
+        Ok(*broadcast.try_get([1, 1])?)
+    }
+
+    #[test]
+    fn question_mark_conversion() {
+        assert_eq!(corner(vec![1, 2, 3, 4], [2, 2]), Ok(4));
+        assert_eq!(
+            corner(vec![1, 2, 3], [2, 2]),
+            Err(Error::Shape(ShapeError::IncompatibleSize {
+                len: 3,
+                expected: 4
+            }))
+        );
+        assert_eq!(
+            corner(vec![1, 2, 3], [1, 3]),
+            Err(Error::Broadcast(BroadcastError {
+                lhs: vec![0, 2],
+                rhs: vec![2, 2]
+            }))
+        );
+    }
+
+    #[test]
+    fn display_and_source() {
+        let error = Error::from(IndexError {
+            axis: 1,
+            index: 3,
+            len: 2,
+        });
+
+        assert_eq!(
+            error.to_string(),
+            "Index 3 is out of bounds for axis 1 of length 2"
+        );
+        assert!(error.source().is_some());
+    }
+}
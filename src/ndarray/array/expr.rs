@@ -0,0 +1,443 @@
+use std::marker::PhantomData;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use super::{broadcast::broadcast_shape, iter::Indices, len_of};
+use crate::{Array, ArrayBase, Scalar, Storage, StorageMut, View};
+
+// A node of a lazy expression. Leaves are views and scalars, inner nodes combine the
+// elements of their children, and nothing is computed until the whole tree is evaluated
+// element by element, without a temporary array per operator.
+pub trait Expression<const D: usize> {
+    type Elem;
+
+    fn shape(&self) -> [usize; D];
+
+    fn broadcast(self, shape: [usize; D]) -> Self;
+
+    // Whether every leaf is stored contiguously in row-major order with the shape of the
+    // expression, in which case elements are found by their position in that order
+    fn is_flat(&self) -> bool;
+
+    fn item(&self, indices: &[usize; D]) -> Self::Elem;
+
+    fn flat_item(&self, index: usize) -> Self::Elem;
+}
+
+impl<'a, T: Clone, const D: usize> Expression<D> for View<'a, T, D> {
+    type Elem = T;
+
+    fn shape(&self) -> [usize; D] {
+        self.shape
+    }
+
+    fn broadcast(self, shape: [usize; D]) -> Self {
+        self.broadcast_move(shape)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    fn is_flat(&self) -> bool {
+        self.contiguous_range().is_some()
+    }
+
+    fn item(&self, indices: &[usize; D]) -> T {
+        self.data[self.offset_of(indices)].clone()
+    }
+
+    fn flat_item(&self, index: usize) -> T {
+        self.data[self.offset + index].clone()
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Splat<U, const D: usize> {
+    val: U,
+    shape: [usize; D],
+}
+
+impl<U: Clone, const D: usize> Expression<D> for Splat<U, D> {
+    type Elem = U;
+
+    fn shape(&self) -> [usize; D] {
+        self.shape
+    }
+
+    fn broadcast(self, shape: [usize; D]) -> Self {
+        Splat {
+            val: self.val,
+            shape,
+        }
+    }
+
+    fn is_flat(&self) -> bool {
+        true
+    }
+
+    fn item(&self, _: &[usize; D]) -> U {
+        self.val.clone()
+    }
+
+    fn flat_item(&self, _: usize) -> U {
+        self.val.clone()
+    }
+}
+
+pub trait Operator<L, R> {
+    type Output;
+
+    fn apply(lhs: L, rhs: R) -> Self::Output;
+}
+
+macro_rules! impl_operator {
+    ($name:ident, $op_trait:ident, $op:tt) => {
+        #[derive(Clone, Copy)]
+        pub struct $name;
+
+        impl<L: $op_trait<R>, R> Operator<L, R> for $name {
+            type Output = L::Output;
+
+            fn apply(lhs: L, rhs: R) -> L::Output {
+                lhs $op rhs
+            }
+        }
+    };
+}
+
+impl_operator!(AddOp, Add, +);
+impl_operator!(SubOp, Sub, -);
+impl_operator!(MulOp, Mul, *);
+impl_operator!(DivOp, Div, /);
+
+#[derive(Clone, Copy)]
+pub struct Binary<L, R, O> {
+    lhs: L,
+    rhs: R,
+    operator: PhantomData<O>,
+}
+
+impl<L, R, O, const D: usize> Expression<D> for Binary<L, R, O>
+where
+    L: Expression<D>,
+    R: Expression<D>,
+    O: Operator<L::Elem, R::Elem>,
+{
+    type Elem = O::Output;
+
+    fn shape(&self) -> [usize; D] {
+        self.lhs.shape()
+    }
+
+    fn broadcast(self, shape: [usize; D]) -> Self {
+        Binary {
+            lhs: self.lhs.broadcast(shape),
+            rhs: self.rhs.broadcast(shape),
+            operator: PhantomData,
+        }
+    }
+
+    fn is_flat(&self) -> bool {
+        self.lhs.is_flat() && self.rhs.is_flat()
+    }
+
+    fn item(&self, indices: &[usize; D]) -> O::Output {
+        O::apply(self.lhs.item(indices), self.rhs.item(indices))
+    }
+
+    fn flat_item(&self, index: usize) -> O::Output {
+        O::apply(self.lhs.flat_item(index), self.rhs.flat_item(index))
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Negated<E> {
+    expr: E,
+}
+
+impl<E, const D: usize> Expression<D> for Negated<E>
+where
+    E: Expression<D>,
+    E::Elem: Neg,
+{
+    type Elem = <E::Elem as Neg>::Output;
+
+    fn shape(&self) -> [usize; D] {
+        self.expr.shape()
+    }
+
+    fn broadcast(self, shape: [usize; D]) -> Self {
+        Negated {
+            expr: self.expr.broadcast(shape),
+        }
+    }
+
+    fn is_flat(&self) -> bool {
+        self.expr.is_flat()
+    }
+
+    fn item(&self, indices: &[usize; D]) -> Self::Elem {
+        -self.expr.item(indices)
+    }
+
+    fn flat_item(&self, index: usize) -> Self::Elem {
+        -self.expr.flat_item(index)
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Mapped<E, F> {
+    expr: E,
+    f: F,
+}
+
+impl<U, E, F, const D: usize> Expression<D> for Mapped<E, F>
+where
+    E: Expression<D>,
+    F: Fn(E::Elem) -> U,
+{
+    type Elem = U;
+
+    fn shape(&self) -> [usize; D] {
+        self.expr.shape()
+    }
+
+    fn broadcast(self, shape: [usize; D]) -> Self {
+        Mapped {
+            expr: self.expr.broadcast(shape),
+            f: self.f,
+        }
+    }
+
+    fn is_flat(&self) -> bool {
+        self.expr.is_flat()
+    }
+
+    fn item(&self, indices: &[usize; D]) -> U {
+        (self.f)(self.expr.item(indices))
+    }
+
+    fn flat_item(&self, index: usize) -> U {
+        (self.f)(self.expr.flat_item(index))
+    }
+}
+
+// The rank is carried by the wrapper, since a node type alone does not name it
+#[derive(Clone, Copy)]
+pub struct Expr<E, const D: usize>(E);
+
+impl<T: Clone, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn lazy(&self) -> Expr<View<'_, T, D>, D> {
+        Expr(self.view())
+    }
+}
+
+impl<E: Expression<D>, const D: usize> Expr<E, D> {
+    pub fn shape(&self) -> [usize; D] {
+        self.0.shape()
+    }
+
+    pub fn map<U, F: Fn(E::Elem) -> U>(self, f: F) -> Expr<Mapped<E, F>, D> {
+        Expr(Mapped { expr: self.0, f })
+    }
+
+    pub fn eval(self) -> Array<E::Elem, D> {
+        let shape = self.0.shape();
+        let mut vec = Vec::with_capacity(len_of(&shape));
+
+        self.for_each(|val| vec.push(val));
+
+        Array::init(vec, shape)
+    }
+
+    // Visits the elements in row-major order
+    fn for_each<F: FnMut(E::Elem)>(self, mut f: F) {
+        let expr = self.0;
+
+        if expr.is_flat() {
+            for index in 0..len_of(&expr.shape()) {
+                f(expr.flat_item(index));
+            }
+        } else {
+            let mut indices = Indices::init(expr.shape());
+
+            while let Some(idx) = indices.next_front() {
+                f(expr.item(&idx));
+            }
+        }
+    }
+
+    fn combine<R: Expression<D>, O>(self, rhs: R) -> Expr<Binary<E, R, O>, D> {
+        let shape = broadcast_shape(&self.0.shape(), &rhs.shape())
+            .unwrap_or_else(|error| panic!("{}", error));
+
+        Expr(Binary {
+            lhs: self.0.broadcast(shape),
+            rhs: rhs.broadcast(shape),
+            operator: PhantomData,
+        })
+    }
+}
+
+impl<T, S: StorageMut<Elem = T>, const D: usize> ArrayBase<S, D> {
+    // Evaluates `expr` straight into the elements of the array. The expression is
+    // broadcast to the shape of the array, but not the other way around.
+    pub fn assign<E: Expression<D, Elem = T>>(&mut self, expr: Expr<E, D>) {
+        if broadcast_shape(&expr.shape(), &self.shape).ok() != Some(self.shape) {
+            panic!(
+                "Expression of shape: {:?} can not be assigned to an array of shape: {:?}",
+                expr.shape(),
+                self.shape
+            )
+        }
+
+        let expr = Expr(expr.0.broadcast(self.shape));
+
+        match self.contiguous_range() {
+            Some(range) => {
+                let mut vals = self.data.as_mut_slice()[range].iter_mut();
+
+                expr.for_each(|val| *vals.next().unwrap() = val);
+            }
+            None => {
+                let mut vals = self.flat_mut();
+
+                expr.for_each(|val| *vals.next().unwrap() = val);
+            }
+        }
+    }
+}
+
+macro_rules! impl_expr_op {
+    ($op_trait:ident, $op_fn:ident, $operator:ident) => {
+        impl<L, R, const D: usize> $op_trait<Expr<R, D>> for Expr<L, D>
+        where
+            L: Expression<D>,
+            R: Expression<D>,
+            $operator: Operator<L::Elem, R::Elem>,
+        {
+            type Output = Expr<Binary<L, R, $operator>, D>;
+
+            fn $op_fn(self, rhs: Expr<R, D>) -> Self::Output {
+                self.combine(rhs.0)
+            }
+        }
+
+        impl<'a, L, T, S, const D: usize> $op_trait<&'a ArrayBase<S, D>> for Expr<L, D>
+        where
+            L: Expression<D>,
+            T: Clone + 'a,
+            S: Storage<Elem = T>,
+            $operator: Operator<L::Elem, T>,
+        {
+            type Output = Expr<Binary<L, View<'a, T, D>, $operator>, D>;
+
+            fn $op_fn(self, rhs: &'a ArrayBase<S, D>) -> Self::Output {
+                self.combine(rhs.view())
+            }
+        }
+
+        impl<L, U, const D: usize> $op_trait<U> for Expr<L, D>
+        where
+            L: Expression<D>,
+            U: Scalar,
+            $operator: Operator<L::Elem, U>,
+        {
+            type Output = Expr<Binary<L, Splat<U, D>, $operator>, D>;
+
+            fn $op_fn(self, rhs: U) -> Self::Output {
+                self.combine(Splat {
+                    val: rhs,
+                    shape: [1; D],
+                })
+            }
+        }
+
+        impl<'a, R, T, S, const D: usize> $op_trait<Expr<R, D>> for &'a ArrayBase<S, D>
+        where
+            R: Expression<D>,
+            T: Clone + 'a,
+            S: Storage<Elem = T>,
+            $operator: Operator<T, R::Elem>,
+        {
+            type Output = Expr<Binary<View<'a, T, D>, R, $operator>, D>;
+
+            fn $op_fn(self, rhs: Expr<R, D>) -> Self::Output {
+                self.lazy().combine(rhs.0)
+            }
+        }
+    };
+}
+
+impl_expr_op!(Add, add, AddOp);
+impl_expr_op!(Sub, sub, SubOp);
+impl_expr_op!(Mul, mul, MulOp);
+impl_expr_op!(Div, div, DivOp);
+
+impl<E, const D: usize> Neg for Expr<E, D>
+where
+    E: Expression<D>,
+    E::Elem: Neg,
+{
+    type Output = Expr<Negated<E>, D>;
+
+    fn neg(self) -> Self::Output {
+        Expr(Negated { expr: self.0 })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eval() {
+        let a = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+        let b = Array::init(vec![6, 5, 4, 3, 2, 1], [2, 3]);
+        let c = Array::init(vec![1, 0, 2], [1, 3]);
+
+        let expr = (a.lazy() + &b) * &c;
+
+        assert_eq!(expr.shape(), [2, 3]);
+        assert_eq!(expr.eval(), (&a + &b) * &c);
+        assert_eq!(
+            (-(a.lazy() - 1) / 2).map(|val| val * 10).eval(),
+            a.mapv(|val| -(val - 1) / 2 * 10)
+        );
+        assert_eq!((&a - b.lazy() * 2).eval(), &a - &(&b * 2));
+    }
+
+    #[test]
+    fn strided_leaves() {
+        let a = Array::from_shape_fn([4, 4], |[i, j]| (i * 4 + j) as f64);
+
+        let (transposed, flipped) = (a.t(), a.flip(1));
+        let expr = transposed.lazy() * 2.0 + &flipped;
+
+        assert_eq!(
+            expr.eval(),
+            Array::from_shape_fn([4, 4], |[i, j]| ((j * 4 + i) * 2 + i * 4 + 3 - j) as f64)
+        );
+    }
+
+    #[test]
+    fn assign() {
+        let a = Array::init(vec![1.0, 2.0, 3.0, 4.0], [2, 2]);
+        let row = Array::init(vec![10.0, 20.0], [1, 2]);
+        let mut out = Array::zeros([2, 2]);
+
+        out.assign(a.lazy() * &row - 1.0);
+
+        assert_eq!(out, Array::init(vec![9.0, 39.0, 29.0, 79.0], [2, 2]));
+
+        out.view_mut().reversed_axes().assign(row.lazy());
+
+        assert_eq!(out, Array::init(vec![10.0, 10.0, 20.0, 20.0], [2, 2]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn assign_broadcasts_only_the_expression() {
+        let a = Array::init(vec![1.0, 2.0, 3.0, 4.0], [2, 2]);
+        let mut row = Array::zeros([1, 2]);
+
+        row.assign(a.lazy() + 1.0);
+    }
+}
@@ -0,0 +1,303 @@
+use std::fmt;
+use std::iter;
+
+use super::{contiguous_range, offset_at};
+use crate::{ArrayBase, Storage};
+
+// Like numpy, arrays with more elements than `THRESHOLD` only show the first and
+// last `EDGE_ITEMS` along every longer axis.
+const THRESHOLD: usize = 1000;
+const EDGE_ITEMS: usize = 3;
+
+impl<T: fmt::Display, S: Storage<Elem = T>, const D: usize> fmt::Display for ArrayBase<S, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_display(
+            f,
+            self.data.as_slice(),
+            &self.shape,
+            &self.strides,
+            self.offset,
+        )
+    }
+}
+
+impl<T: fmt::Debug, S: Storage<Elem = T>, const D: usize> fmt::Debug for ArrayBase<S, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_debug(
+            f,
+            self.data.as_slice(),
+            &self.shape,
+            &self.strides,
+            self.offset,
+        )
+    }
+}
+
+pub(crate) fn write_display<T: fmt::Display>(
+    f: &mut fmt::Formatter<'_>,
+    data: &[T],
+    shape: &[usize],
+    strides: &[isize],
+    offset: usize,
+) -> fmt::Result {
+    let precision = f.precision();
+
+    write_preview(f, shape, "", |indices| {
+        let elem = &data[offset_at(indices, strides, offset)];
+
+        match precision {
+            Some(precision) => format!("{:.*}", precision, elem),
+            None => elem.to_string(),
+        }
+    })
+}
+
+pub(crate) fn write_debug<T: fmt::Debug>(
+    f: &mut fmt::Formatter<'_>,
+    data: &[T],
+    shape: &[usize],
+    strides: &[isize],
+    offset: usize,
+) -> fmt::Result {
+    let precision = f.precision();
+
+    write_preview(f, shape, ",", |indices| {
+        let elem = &data[offset_at(indices, strides, offset)];
+
+        match precision {
+            Some(precision) => format!("{:.*?}", precision, elem),
+            None => format!("{:?}", elem),
+        }
+    })?;
+
+    // Arrays with a single row or column are both C and F contiguous
+    let c_contiguous = contiguous_range(shape, strides, offset).is_some();
+    let reversed_shape: Vec<usize> = shape.iter().rev().copied().collect();
+    let reversed_strides: Vec<isize> = strides.iter().rev().copied().collect();
+    let f_contiguous = contiguous_range(&reversed_shape, &reversed_strides, offset).is_some();
+
+    let layout = match (c_contiguous, f_contiguous) {
+        (true, true) => "CF",
+        (true, false) => "C",
+        (false, true) => "F",
+        (false, false) => "strided",
+    };
+
+    write!(
+        f,
+        ", shape={:?}, strides={:?}, layout={}, len={}",
+        shape,
+        strides,
+        layout,
+        shape.iter().product::<usize>()
+    )
+}
+
+// Writes the elements in nested brackets, every sub-array is followed by `delimiter` and
+// elements are right aligned to the widest one.
+fn write_preview<F: Fn(&[usize]) -> String>(
+    f: &mut fmt::Formatter<'_>,
+    shape: &[usize],
+    delimiter: &str,
+    format: F,
+) -> fmt::Result {
+    let len: usize = shape.iter().product();
+    if len == 0 {
+        return write!(f, "[]");
+    }
+
+    // `None` marks the position of the ellipsis
+    let shown: Vec<Vec<Option<usize>>> = shape
+        .iter()
+        .map(|&axis_len| {
+            if len > THRESHOLD && axis_len > 2 * EDGE_ITEMS {
+                (0..EDGE_ITEMS)
+                    .map(Some)
+                    .chain(iter::once(None))
+                    .chain((axis_len - EDGE_ITEMS..axis_len).map(Some))
+                    .collect()
+            } else {
+                (0..axis_len).map(Some).collect()
+            }
+        })
+        .collect();
+
+    let mut elems = vec![];
+    format_elems(&format, &shown, 0, &mut vec![0; shape.len()], &mut elems);
+
+    let width = elems
+        .iter()
+        .map(|elem| elem.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    write_axis(f, &shown, 0, &mut elems.into_iter(), width, delimiter)
+}
+
+// Formats the shown elements in the order they are written
+fn format_elems<F: Fn(&[usize]) -> String>(
+    format: &F,
+    shown: &[Vec<Option<usize>>],
+    axis: usize,
+    indices: &mut [usize],
+    elems: &mut Vec<String>,
+) {
+    if axis == shown.len() {
+        elems.push(format(indices));
+
+        return;
+    }
+
+    for idx in shown[axis].iter().flatten() {
+        indices[axis] = *idx;
+        format_elems(format, shown, axis + 1, indices, elems);
+    }
+}
+
+fn write_axis<I: Iterator<Item = String>>(
+    f: &mut fmt::Formatter<'_>,
+    shown: &[Vec<Option<usize>>],
+    axis: usize,
+    elems: &mut I,
+    width: usize,
+    delimiter: &str,
+) -> fmt::Result {
+    let ndim = shown.len();
+
+    if axis == ndim {
+        let elem = elems.next().unwrap_or_default();
+
+        return write!(f, "{:>width$}", elem, width = width);
+    }
+
+    // Sub-arrays are separated by one blank line less per level of nesting
+    // and indented past the brackets that are still open.
+    let separator = if axis + 1 == ndim {
+        format!("{} ", delimiter)
+    } else {
+        format!(
+            "{}{}{}",
+            delimiter,
+            "\n".repeat(ndim - axis - 1),
+            " ".repeat(axis + 1)
+        )
+    };
+
+    write!(f, "[")?;
+    for (pos, idx) in shown[axis].iter().enumerate() {
+        if pos > 0 {
+            write!(f, "{}", separator)?;
+        }
+
+        match idx {
+            Some(_) => write_axis(f, shown, axis + 1, elems, width, delimiter)?,
+            None => write!(f, "...")?,
+        }
+    }
+    write!(f, "]")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Array;
+
+    #[test]
+    fn display_1d() {
+        let array = Array::init(vec![1, 2, 3], [3]);
+
+        assert_eq!(array.to_string(), "[1 2 3]");
+    }
+
+    #[test]
+    fn display_2d_aligned() {
+        let array = Array::init(vec![1, -20, 3, 400, 5, 6], [2, 3]);
+
+        assert_eq!(array.to_string(), "[[  1 -20   3]\n [400   5   6]]");
+    }
+
+    #[test]
+    fn display_3d() {
+        let array = Array::arange(0..8).reshape([2, 2, 2]).unwrap();
+
+        assert_eq!(array.to_string(), "[[[0 1]\n  [2 3]]\n\n [[4 5]\n  [6 7]]]");
+    }
+
+    #[test]
+    fn display_view() {
+        let array = Array::arange(0..6).reshape([2, 3]).unwrap();
+
+        assert_eq!(array.t().to_string(), "[[0 3]\n [1 4]\n [2 5]]");
+        assert_eq!(array.slice(&[1..2, 0..3]).flip(1).to_string(), "[[5 4 3]]");
+    }
+
+    #[test]
+    fn display_precision() {
+        let array = Array::init(vec![1.0, 2.5, -0.125], [3]);
+
+        assert_eq!(array.to_string(), "[     1    2.5 -0.125]");
+        assert_eq!(format!("{:.2}", array), "[ 1.00  2.50 -0.12]");
+    }
+
+    #[test]
+    fn display_truncated() {
+        let array = Array::arange(0..2000);
+
+        assert_eq!(array.to_string(), "[   0    1    2 ... 1997 1998 1999]");
+
+        let array = Array::arange(0..1010).reshape([101, 10]).unwrap();
+
+        assert_eq!(
+            array.to_string(),
+            "[[   0    1    2 ...    7    8    9]\n \
+             [  10   11   12 ...   17   18   19]\n \
+             [  20   21   22 ...   27   28   29]\n \
+             ...\n \
+             [ 980  981  982 ...  987  988  989]\n \
+             [ 990  991  992 ...  997  998  999]\n \
+             [1000 1001 1002 ... 1007 1008 1009]]"
+        );
+    }
+
+    #[test]
+    fn display_empty_and_scalar() {
+        assert_eq!(Array::<i32, 2>::zeros([2, 0]).to_string(), "[]");
+        assert_eq!(Array::init(vec![7], []).to_string(), "7");
+    }
+
+    #[test]
+    fn debug_layout() {
+        let array = Array::arange(0..6).reshape([2, 3]).unwrap();
+
+        assert_eq!(
+            format!("{:?}", array),
+            "[[0, 1, 2],\n [3, 4, 5]], shape=[2, 3], strides=[3, 1], layout=C, len=6"
+        );
+        assert_eq!(
+            format!("{:?}", array.t()),
+            "[[0, 3],\n [1, 4],\n [2, 5]], shape=[3, 2], strides=[1, 3], layout=F, len=6"
+        );
+        assert_eq!(
+            format!("{:?}", array.slice(&crate::s![.., ..;2])),
+            "[[0, 2],\n [3, 5]], shape=[2, 2], strides=[3, 2], layout=strided, len=4"
+        );
+        assert_eq!(
+            format!("{:?}", array.slice(&[1..2, 0..3])),
+            "[[3, 4, 5]], shape=[1, 3], strides=[3, 1], layout=CF, len=3"
+        );
+    }
+
+    #[test]
+    fn debug_preview() {
+        let array = Array::arange(0..2000).mapv(|x| x as f64 / 2.0);
+
+        assert_eq!(
+            format!("{:.1?}", array),
+            "[  0.0,   0.5,   1.0, ..., 998.5, 999.0, 999.5], \
+             shape=[2000], strides=[1], layout=CF, len=2000"
+        );
+        assert_eq!(
+            format!("{:?}", Array::<i32, 2>::zeros([0, 3])),
+            "[], shape=[0, 3], strides=[3, 1], layout=CF, len=0"
+        );
+    }
+}
@@ -0,0 +1,226 @@
+use std::ops::{Add, Mul};
+
+use num_traits::Zero;
+
+use crate::{Array, ArrayBase, Storage};
+
+// Blocking in the style of BLIS: a `KC x NC` panel of `rhs` and an `MC x KC` block of
+// `lhs` are packed so that the micro-kernel reads both of them sequentially, while it
+// keeps an `MR x NR` tile of the product in registers.
+const MR: usize = 4;
+const NR: usize = 8;
+const KC: usize = 256;
+const MC: usize = 128;
+const NC: usize = 2048;
+
+pub(crate) fn gemm<T, S1, S2>(lhs: &ArrayBase<S1, 2>, rhs: &ArrayBase<S2, 2>) -> Array<T, 2>
+where
+    T: Clone + Zero + Add<Output = T> + Mul<Output = T>,
+    S1: Storage<Elem = T>,
+    S2: Storage<Elem = T>,
+{
+    let [m, k] = lhs.shape;
+    let n = rhs.shape[1];
+
+    // Copying the operands in row-major order is linear in their size, while the product
+    // is cubic, and leaves the packing with a single layout to handle
+    let lhs: Vec<T> = lhs.flat().cloned().collect();
+    let rhs: Vec<T> = rhs.flat().cloned().collect();
+    let mut product = vec![T::zero(); m * n];
+
+    #[cfg(feature = "rayon")]
+    if parallel::try_gemm::<f32, T>(&lhs, &rhs, &mut product, [m, n, k])
+        || parallel::try_gemm::<f64, T>(&lhs, &rhs, &mut product, [m, n, k])
+    {
+        return Array::init(product, [m, n]);
+    }
+
+    for (jc, pc, packed_rhs) in rhs_panels(&rhs, [m, n, k]) {
+        for (ic, rows) in product.chunks_mut(MC * n).enumerate() {
+            multiply_block(&lhs, &packed_rhs, rows, [ic * MC, jc, pc], [m, n, k]);
+        }
+    }
+
+    Array::init(product, [m, n])
+}
+
+// Packs every `KC x NC` panel of `rhs` into strips of `NR` columns, each stored row by row
+// and padded with zeros up to `NR`
+fn rhs_panels<T: Clone + Zero>(
+    rhs: &[T],
+    [_, n, k]: [usize; 3],
+) -> impl Iterator<Item = (usize, usize, Vec<T>)> + '_ {
+    (0..n).step_by(NC).flat_map(move |jc| {
+        (0..k).step_by(KC).map(move |pc| {
+            let nc = NC.min(n - jc);
+            let kc = KC.min(k - pc);
+
+            let mut packed = Vec::with_capacity(nc.div_ceil(NR) * NR * kc);
+            for jr in (0..nc).step_by(NR) {
+                for p in pc..pc + kc {
+                    let row = &rhs[p * n + jc + jr..p * n + jc + nc.min(jr + NR)];
+
+                    packed.extend(row.iter().cloned());
+                    packed.extend((row.len()..NR).map(|_| T::zero()));
+                }
+            }
+
+            (jc, pc, packed)
+        })
+    })
+}
+
+// Adds the product of rows `ic..` of `lhs` with a packed panel of `rhs` to `rows`, the
+// matching rows of the product
+fn multiply_block<T>(
+    lhs: &[T],
+    packed_rhs: &[T],
+    rows: &mut [T],
+    [ic, jc, pc]: [usize; 3],
+    [_, n, k]: [usize; 3],
+) where
+    T: Clone + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    let mc = rows.len() / n;
+    let nc = NC.min(n - jc);
+    let kc = KC.min(k - pc);
+
+    // Strips of `MR` rows, each stored column by column and padded with zeros up to `MR`
+    let mut packed_lhs = Vec::with_capacity(mc.div_ceil(MR) * MR * kc);
+    for ir in (0..mc).step_by(MR) {
+        for p in pc..pc + kc {
+            for i in ir..ir + MR {
+                packed_lhs.push(if i < mc {
+                    lhs[(ic + i) * k + p].clone()
+                } else {
+                    T::zero()
+                });
+            }
+        }
+    }
+
+    for (jr, rhs_strip) in (0..nc).step_by(NR).zip(packed_rhs.chunks(NR * kc)) {
+        for (ir, lhs_strip) in (0..mc).step_by(MR).zip(packed_lhs.chunks(MR * kc)) {
+            let tile = micro_kernel(lhs_strip, rhs_strip);
+
+            for (i, tile_row) in tile.iter().enumerate().take(mc - ir) {
+                let row = &mut rows[(ir + i) * n + jc + jr..][..NR.min(nc - jr)];
+
+                for (val, tile_val) in row.iter_mut().zip(tile_row) {
+                    *val = val.clone() + tile_val.clone();
+                }
+            }
+        }
+    }
+}
+
+fn micro_kernel<T>(lhs_strip: &[T], rhs_strip: &[T]) -> [[T; NR]; MR]
+where
+    T: Clone + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    let mut tile: [[T; NR]; MR] = std::array::from_fn(|_| std::array::from_fn(|_| T::zero()));
+
+    for (lhs_col, rhs_row) in lhs_strip.chunks_exact(MR).zip(rhs_strip.chunks_exact(NR)) {
+        for (tile_row, lhs_val) in tile.iter_mut().zip(lhs_col) {
+            for (tile_val, rhs_val) in tile_row.iter_mut().zip(rhs_row) {
+                *tile_val = tile_val.clone() + lhs_val.clone() * rhs_val.clone();
+            }
+        }
+    }
+
+    tile
+}
+
+// Threads need `Send` and `Sync` elements, which the bounds of `Dot` do not ask for, so
+// only the float types are recognized by their type id and multiplied in parallel
+#[cfg(feature = "rayon")]
+mod parallel {
+    use std::ops::{Add, Mul};
+
+    use num_traits::Zero;
+    use rayon::prelude::*;
+
+    use super::{multiply_block, rhs_panels, MC};
+    use crate::ndarray::array::type_id;
+
+    pub(super) fn try_gemm<U, T>(
+        lhs: &[T],
+        rhs: &[T],
+        product: &mut [T],
+        [m, n, k]: [usize; 3],
+    ) -> bool
+    where
+        U: Clone + Zero + Add<Output = U> + Mul<Output = U> + Send + Sync + 'static,
+    {
+        if !type_id::is::<T, U>() {
+            return false;
+        }
+
+        let lhs = type_id::cast_slice::<T, U>(lhs);
+        let rhs = type_id::cast_slice::<T, U>(rhs);
+        let product = type_id::cast_slice_mut::<T, U>(product);
+
+        // Every block of `MC` rows of the product is written by a single thread
+        for (jc, pc, packed_rhs) in rhs_panels(rhs, [m, n, k]) {
+            product
+                .par_chunks_mut(MC * n)
+                .enumerate()
+                .for_each(|(ic, rows)| {
+                    multiply_block(lhs, &packed_rhs, rows, [ic * MC, jc, pc], [m, n, k])
+                });
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive<T>(lhs: &Array<T, 2>, rhs: &Array<T, 2>) -> Array<T, 2>
+    where
+        T: Clone + Zero + Add<Output = T> + Mul<Output = T>,
+    {
+        let [m, k] = lhs.shape;
+        let n = rhs.shape[1];
+
+        Array::from_shape_fn([m, n], |[i, j]| {
+            (0..k).fold(T::zero(), |acc, p| {
+                acc + lhs[[i, p]].clone() * rhs[[p, j]].clone()
+            })
+        })
+    }
+
+    #[test]
+    fn blocked_matches_naive() {
+        // Sizes that are not multiples of any block, with more than one `KC` and `MC` block
+        for [m, k, n] in [[1, 1, 1], [3, 5, 7], [131, 300, 9], [5, 257, 70]] {
+            let lhs = Array::from_shape_fn([m, k], |[i, p]| (i * 7 + p * 3) as i64 % 11 - 5);
+            let rhs = Array::from_shape_fn([k, n], |[p, j]| (p * 5 + j) as i64 % 13 - 6);
+
+            assert_eq!(gemm(&lhs, &rhs), naive(&lhs, &rhs));
+            assert_eq!(
+                gemm(&rhs.t(), &lhs.t()),
+                naive(&rhs.t().to_owned(), &lhs.t().to_owned())
+            );
+        }
+    }
+
+    #[test]
+    fn blocked_floats() {
+        let lhs = Array::from_shape_fn([130, 260], |[i, p]| ((i + 2 * p) % 17) as f32 - 8.0);
+        let rhs = Array::from_shape_fn([260, 40], |[p, j]| ((3 * p + j) % 19) as f32 - 9.0);
+
+        // Small integers in floats are exact, whatever order they are summed in
+        assert_eq!(gemm(&lhs, &rhs), naive(&lhs, &rhs));
+        assert_eq!(
+            gemm(&lhs.mapv(f64::from), &rhs.mapv(f64::from)),
+            naive(&lhs, &rhs).mapv(f64::from)
+        );
+        assert_eq!(
+            gemm(&lhs.slice(&crate::s![..0, ..]), &rhs).shape(),
+            &[0, 40]
+        );
+    }
+}
@@ -0,0 +1,723 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::size_of;
+use std::ops::{Add, Div, Mul, Sub};
+use std::sync::{mpsc, Arc, Mutex};
+
+use bytemuck::Pod;
+use num_traits::Zero;
+use wgpu::util::DeviceExt;
+
+use super::{broadcast::broadcast_shape, default_strides, len_of};
+use crate::{Array, ArrayBase, Dot, Storage};
+
+// Every kernel is written once with `{T}` standing in for the element type and `{OP}` for
+// the operation, and compiled on first use for each combination of the two.
+
+// `shape_info` holds the length of the output, the rank, the output shape and the strides of
+// both operands, which are zero along broadcast axes
+const BINARY: &str = r#"
+@group(0) @binding(0) var<storage, read> lhs: array<{T}>;
+@group(0) @binding(1) var<storage, read> rhs: array<{T}>;
+@group(0) @binding(2) var<storage, read_write> out: array<{T}>;
+@group(0) @binding(3) var<storage, read> shape_info: array<u32>;
+
+@compute @workgroup_size(256)
+fn main(@builtin(global_invocation_id) id: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let index = id.x + id.y * groups.x * 256u;
+    if (index >= shape_info[0]) {
+        return;
+    }
+
+    let rank = shape_info[1];
+    var rest = index;
+    var lhs_index = 0u;
+    var rhs_index = 0u;
+    for (var axis = rank; axis > 0u; axis -= 1u) {
+        let i = rest % shape_info[1u + axis];
+        rest = rest / shape_info[1u + axis];
+
+        lhs_index += i * shape_info[1u + rank + axis];
+        rhs_index += i * shape_info[1u + 2u * rank + axis];
+    }
+
+    out[index] = lhs[lhs_index] {OP} rhs[rhs_index];
+}
+"#;
+
+// Every workgroup reduces up to 256 values to a single one, without an identity element
+const REDUCE: &str = r#"
+@group(0) @binding(0) var<storage, read> vals: array<{T}>;
+@group(0) @binding(1) var<storage, read_write> partials: array<{T}>;
+@group(0) @binding(2) var<storage, read> params: array<u32>;
+
+var<workgroup> scratch: array<{T}, 256>;
+
+fn combine(lhs: {T}, rhs: {T}) -> {T} {
+    return {OP};
+}
+
+@compute @workgroup_size(256)
+fn main(
+    @builtin(local_invocation_id) local: vec3<u32>,
+    @builtin(workgroup_id) group: vec3<u32>,
+    @builtin(num_workgroups) groups: vec3<u32>,
+) {
+    let len = params[0];
+    let group_index = group.x + group.y * groups.x;
+    let start = group_index * 256u;
+
+    var count = 0u;
+    if (start < len) {
+        count = min(256u, len - start);
+    }
+
+    if (local.x < count) {
+        scratch[local.x] = vals[start + local.x];
+    }
+    workgroupBarrier();
+
+    for (var stride = 128u; stride > 0u; stride = stride / 2u) {
+        if (local.x < stride && local.x + stride < count) {
+            scratch[local.x] = combine(scratch[local.x], scratch[local.x + stride]);
+        }
+        workgroupBarrier();
+    }
+
+    if (local.x == 0u && count > 0u) {
+        partials[group_index] = scratch[0];
+    }
+}
+"#;
+
+// Tiles of both operands are staged in workgroup memory, `dims` holds `m`, `k` and `n`
+// followed by the row and column of the output block this dispatch computes
+const MATMUL: &str = r#"
+@group(0) @binding(0) var<storage, read> lhs: array<{T}>;
+@group(0) @binding(1) var<storage, read> rhs: array<{T}>;
+@group(0) @binding(2) var<storage, read_write> out: array<{T}>;
+@group(0) @binding(3) var<storage, read> dims: array<u32>;
+
+var<workgroup> lhs_tile: array<array<{T}, 16>, 16>;
+var<workgroup> rhs_tile: array<array<{T}, 16>, 16>;
+
+@compute @workgroup_size(16, 16)
+fn main(@builtin(global_invocation_id) id: vec3<u32>, @builtin(local_invocation_id) local: vec3<u32>) {
+    let m = dims[0];
+    let k = dims[1];
+    let n = dims[2];
+    let row = dims[3] + id.y;
+    let col = dims[4] + id.x;
+
+    var acc = {T}(0);
+    for (var tile = 0u; tile < (k + 15u) / 16u; tile += 1u) {
+        let p = tile * 16u;
+
+        lhs_tile[local.y][local.x] = {T}(0);
+        if (row < m && p + local.x < k) {
+            lhs_tile[local.y][local.x] = lhs[row * k + p + local.x];
+        }
+        rhs_tile[local.y][local.x] = {T}(0);
+        if (p + local.y < k && col < n) {
+            rhs_tile[local.y][local.x] = rhs[(p + local.y) * n + col];
+        }
+        workgroupBarrier();
+
+        for (var i = 0u; i < 16u; i += 1u) {
+            acc += lhs_tile[local.y][i] * rhs_tile[i][local.x];
+        }
+        workgroupBarrier();
+    }
+
+    if (row < m && col < n) {
+        out[row * n + col] = acc;
+    }
+}
+"#;
+
+const WORKGROUP_LEN: usize = 256;
+
+// Dispatches are limited to 65535 workgroups along each dimension
+const MAX_WORKGROUPS: usize = 65535;
+
+fn kernel<T: GpuElement>(template: &str, op: &str) -> String {
+    template.replace("{T}", T::WGSL).replace("{OP}", op)
+}
+
+// Kernels index with 32 bit integers
+fn index(val: usize) -> u32 {
+    u32::try_from(val).expect("Arrays on the GPU can not have more than u32::MAX elements")
+}
+
+fn grid(workgroups: usize) -> [u32; 2] {
+    let x = workgroups.clamp(1, MAX_WORKGROUPS);
+
+    [index(x), index(workgroups.div_ceil(x))]
+}
+
+// Splits an `m` by `n` product into blocks that fit into a single dispatch of 16x16
+// workgroups, returning the first row and column of every block and its grid
+fn matmul_blocks(m: usize, n: usize) -> Vec<([usize; 2], [u32; 2])> {
+    let block_len = MAX_WORKGROUPS * 16;
+    let mut blocks = Vec::new();
+
+    for row in (0..m).step_by(block_len) {
+        for col in (0..n).step_by(block_len) {
+            let rows = (m - row).min(block_len);
+            let cols = (n - col).min(block_len);
+
+            blocks.push((
+                [row, col],
+                [index(cols.div_ceil(16)), index(rows.div_ceil(16))],
+            ));
+        }
+    }
+
+    blocks
+}
+
+// Element types that WGSL can compute with
+pub trait GpuElement: Pod + Zero {
+    const WGSL: &'static str;
+}
+
+impl GpuElement for f32 {
+    const WGSL: &'static str = "f32";
+}
+
+impl GpuElement for i32 {
+    const WGSL: &'static str = "i32";
+}
+
+impl GpuElement for u32 {
+    const WGSL: &'static str = "u32";
+}
+
+#[derive(Debug)]
+pub enum GpuError {
+    Adapter(wgpu::RequestAdapterError),
+    Device(wgpu::RequestDeviceError),
+}
+
+impl fmt::Display for GpuError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GpuError::Adapter(error) => write!(f, "No GPU adapter is available: {}", error),
+            GpuError::Device(error) => write!(f, "GPU device could not be opened: {}", error),
+        }
+    }
+}
+
+impl Error for GpuError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GpuError::Adapter(error) => Some(error),
+            GpuError::Device(error) => Some(error),
+        }
+    }
+}
+
+impl From<wgpu::RequestAdapterError> for GpuError {
+    fn from(error: wgpu::RequestAdapterError) -> Self {
+        GpuError::Adapter(error)
+    }
+}
+
+impl From<wgpu::RequestDeviceError> for GpuError {
+    fn from(error: wgpu::RequestDeviceError) -> Self {
+        GpuError::Device(error)
+    }
+}
+
+struct Context {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    pipelines: Mutex<HashMap<String, wgpu::ComputePipeline>>,
+}
+
+// A cheaply cloned handle to a device and its queue, shared by the arrays stored on it
+#[derive(Clone)]
+pub struct GpuDevice {
+    context: Arc<Context>,
+}
+
+impl GpuDevice {
+    pub fn new() -> Result<Self, GpuError> {
+        let instance = wgpu::Instance::default();
+
+        let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::HighPerformance,
+            ..Default::default()
+        }))?;
+
+        let (device, queue) =
+            pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor::default()))?;
+
+        Ok(GpuDevice {
+            context: Arc::new(Context {
+                device,
+                queue,
+                pipelines: Mutex::new(HashMap::new()),
+            }),
+        })
+    }
+
+    fn pipeline(&self, source: String) -> wgpu::ComputePipeline {
+        let mut pipelines = self.context.pipelines.lock().unwrap();
+        let device = &self.context.device;
+
+        let pipeline = pipelines.entry(source).or_insert_with_key(|source| {
+            let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: None,
+                source: wgpu::ShaderSource::Wgsl(source.as_str().into()),
+            });
+
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: None,
+                layout: None,
+                module: &module,
+                entry_point: Some("main"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        });
+
+        pipeline.clone()
+    }
+
+    // Bindings can not be empty, so empty buffers hold a single unused value
+    fn buffer<T: Pod>(&self, vals: &[T]) -> wgpu::Buffer {
+        let contents = match vals.is_empty() {
+            true => &[0; 4],
+            false => bytemuck::cast_slice(vals),
+        };
+
+        self.context
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: None,
+                contents,
+                usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            })
+    }
+
+    fn output<T>(&self, len: usize) -> wgpu::Buffer {
+        self.context.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: (len.max(1) * size_of::<T>()).max(4) as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        })
+    }
+
+    fn run(&self, source: String, buffers: &[&wgpu::Buffer], [x, y]: [u32; 2]) {
+        let pipeline = self.pipeline(source);
+        let device = &self.context.device;
+
+        let entries: Vec<_> = buffers
+            .iter()
+            .enumerate()
+            .map(|(binding, buffer)| wgpu::BindGroupEntry {
+                binding: binding as u32,
+                resource: buffer.as_entire_binding(),
+            })
+            .collect();
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &pipeline.get_bind_group_layout(0),
+            entries: &entries,
+        });
+
+        let mut encoder = device.create_command_encoder(&Default::default());
+        {
+            let mut pass = encoder.begin_compute_pass(&Default::default());
+            pass.set_pipeline(&pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups(x, y, 1);
+        }
+
+        self.context.queue.submit(Some(encoder.finish()));
+    }
+
+    // Copies the first `len` values of `buffer` back and waits for the copy to finish
+    fn read<T: Pod>(&self, buffer: &wgpu::Buffer, len: usize) -> Vec<T> {
+        if len == 0 {
+            return Vec::new();
+        }
+
+        let device = &self.context.device;
+        let size = (len * size_of::<T>()) as u64;
+
+        let staging = device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = device.create_command_encoder(&Default::default());
+        encoder.copy_buffer_to_buffer(buffer, 0, &staging, 0, size);
+        self.context.queue.submit(Some(encoder.finish()));
+
+        let (sender, receiver) = mpsc::channel();
+        staging.map_async(wgpu::MapMode::Read, .., move |result| {
+            let _ = sender.send(result);
+        });
+
+        device
+            .poll(wgpu::PollType::wait_indefinitely())
+            .expect("GPU device was lost");
+        receiver
+            .recv()
+            .expect("GPU device was lost")
+            .expect("GPU buffer could not be mapped");
+
+        let vec = bytemuck::pod_collect_to_vec(
+            &staging
+                .get_mapped_range(..)
+                .expect("GPU buffer could not be mapped"),
+        );
+        staging.unmap();
+
+        vec
+    }
+
+    fn same_as(&self, other: &GpuDevice) -> bool {
+        Arc::ptr_eq(&self.context, &other.context)
+    }
+}
+
+// An array in the memory of a GPU, in row-major order. Operations on it run on the device
+// and only `to_host` waits for them and copies the elements back.
+pub struct GpuArray<T, const D: usize> {
+    device: GpuDevice,
+    buffer: wgpu::Buffer,
+    shape: [usize; D],
+    marker: PhantomData<T>,
+}
+
+impl<T: GpuElement, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn to_gpu(&self, device: &GpuDevice) -> GpuArray<T, D> {
+        index(len_of(&self.shape));
+
+        let buffer = match self.contiguous_range() {
+            Some(range) => device.buffer(&self.data.as_slice()[range]),
+            None => device.buffer(&self.flat().copied().collect::<Vec<T>>()),
+        };
+
+        GpuArray {
+            device: device.clone(),
+            buffer,
+            shape: self.shape,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: GpuElement, const D: usize> GpuArray<T, D> {
+    pub fn shape(&self) -> &[usize; D] {
+        &self.shape
+    }
+
+    pub fn device(&self) -> &GpuDevice {
+        &self.device
+    }
+
+    pub fn to_host(&self) -> Array<T, D> {
+        Array::init(
+            self.device.read(&self.buffer, len_of(&self.shape)),
+            self.shape,
+        )
+    }
+
+    pub fn sum(&self) -> T {
+        self.reduce("lhs + rhs").unwrap_or_else(T::zero)
+    }
+
+    // WGSL leaves the result unspecified when a float is NaN
+    pub fn max(&self) -> Option<T> {
+        self.reduce("max(lhs, rhs)")
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.reduce("min(lhs, rhs)")
+    }
+
+    // Every pass leaves one value per workgroup, until a single value is left
+    fn reduce(&self, op: &str) -> Option<T> {
+        let device = &self.device;
+        let mut len = len_of(&self.shape);
+
+        if len == 0 {
+            return None;
+        }
+
+        let mut vals = None;
+        while len > 1 {
+            let workgroups = len.div_ceil(WORKGROUP_LEN);
+            let partials = device.output::<T>(workgroups);
+            let params = device.buffer(&[index(len)]);
+
+            device.run(
+                kernel::<T>(REDUCE, op),
+                &[vals.as_ref().unwrap_or(&self.buffer), &partials, &params],
+                grid(workgroups),
+            );
+
+            vals = Some(partials);
+            len = workgroups;
+        }
+
+        device.read(vals.as_ref().unwrap_or(&self.buffer), 1).pop()
+    }
+
+    fn binary(&self, rhs: &GpuArray<T, D>, op: &str) -> GpuArray<T, D> {
+        if !self.device.same_as(&rhs.device) {
+            panic!("Arrays on different GPU devices can not be combined");
+        }
+
+        let shape =
+            broadcast_shape(&self.shape, &rhs.shape).unwrap_or_else(|error| panic!("{}", error));
+        let len = len_of(&shape);
+
+        let mut shape_info = vec![index(len), index(D)];
+        shape_info.extend(shape.iter().map(|len| index(*len)));
+        shape_info.extend(broadcast_strides(&self.shape, &shape));
+        shape_info.extend(broadcast_strides(&rhs.shape, &shape));
+
+        let device = &self.device;
+        let out = device.output::<T>(len);
+        let shape_info = device.buffer(&shape_info);
+
+        if len > 0 {
+            device.run(
+                kernel::<T>(BINARY, op),
+                &[&self.buffer, &rhs.buffer, &out, &shape_info],
+                grid(len.div_ceil(WORKGROUP_LEN)),
+            );
+        }
+
+        GpuArray {
+            device: device.clone(),
+            buffer: out,
+            shape,
+            marker: PhantomData,
+        }
+    }
+
+    fn splat(&self, val: T) -> GpuArray<T, D> {
+        GpuArray {
+            device: self.device.clone(),
+            buffer: self.device.buffer(&[val]),
+            shape: [1; D],
+            marker: PhantomData,
+        }
+    }
+}
+
+// Row-major strides of `shape`, with zeros along the axes broadcast to `broadcast`
+fn broadcast_strides<const D: usize>(
+    shape: &[usize; D],
+    broadcast: &[usize; D],
+) -> impl Iterator<Item = u32> {
+    let strides = default_strides(shape);
+    let broadcast = *broadcast;
+    let shape = *shape;
+
+    (0..D).map(move |axis| match shape[axis] == broadcast[axis] {
+        true => index(strides[axis] as usize),
+        false => 0,
+    })
+}
+
+macro_rules! impl_gpu_op {
+    ($op_trait:ident, $op_fn:ident, $op:literal) => {
+        impl<T: GpuElement, const D: usize> $op_trait<&GpuArray<T, D>> for &GpuArray<T, D> {
+            type Output = GpuArray<T, D>;
+
+            fn $op_fn(self, rhs: &GpuArray<T, D>) -> Self::Output {
+                self.binary(rhs, $op)
+            }
+        }
+
+        impl<T: GpuElement, const D: usize> $op_trait<T> for &GpuArray<T, D> {
+            type Output = GpuArray<T, D>;
+
+            fn $op_fn(self, rhs: T) -> Self::Output {
+                self.binary(&self.splat(rhs), $op)
+            }
+        }
+    };
+}
+
+impl_gpu_op!(Add, add, "+");
+impl_gpu_op!(Sub, sub, "-");
+impl_gpu_op!(Mul, mul, "*");
+impl_gpu_op!(Div, div, "/");
+
+impl<T: GpuElement> Dot<GpuArray<T, 2>> for GpuArray<T, 2> {
+    type Output = GpuArray<T, 2>;
+
+    fn dot(&self, rhs: &GpuArray<T, 2>) -> Self::Output {
+        if !self.device.same_as(&rhs.device) {
+            panic!("Arrays on different GPU devices can not be combined");
+        }
+
+        let [m, k] = self.shape;
+        let [rhs_k, n] = rhs.shape;
+
+        if k != rhs_k {
+            panic!(
+                "Matrices of shape: {:?} and {:?} can not be multiplied",
+                self.shape, rhs.shape
+            )
+        }
+
+        let device = &self.device;
+        let out = device.output::<T>(len_of(&[m, n]));
+
+        for ([row, col], grid) in matmul_blocks(m, n) {
+            let dims = device.buffer(&[index(m), index(k), index(n), index(row), index(col)]);
+
+            device.run(
+                kernel::<T>(MATMUL, ""),
+                &[&self.buffer, &rhs.buffer, &out, &dims],
+                grid,
+            );
+        }
+
+        GpuArray {
+            device: device.clone(),
+            buffer: out,
+            shape: [m, n],
+            marker: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wgpu::naga;
+
+    use super::*;
+
+    // Most machines running the tests have no GPU, in which case only the kernels are checked
+    fn device() -> Option<GpuDevice> {
+        GpuDevice::new().ok()
+    }
+
+    fn validate(source: &str) {
+        let module = naga::front::wgsl::parse_str(source)
+            .unwrap_or_else(|error| panic!("{}", error.emit_to_string(source)));
+
+        naga::valid::Validator::new(
+            naga::valid::ValidationFlags::all(),
+            naga::valid::Capabilities::empty(),
+        )
+        .validate(&module)
+        .unwrap_or_else(|error| panic!("{}", error.emit_to_string(source)));
+    }
+
+    fn validate_kernels<T: GpuElement>() {
+        for op in ["+", "-", "*", "/"] {
+            validate(&kernel::<T>(BINARY, op));
+        }
+
+        for op in ["lhs + rhs", "max(lhs, rhs)", "min(lhs, rhs)"] {
+            validate(&kernel::<T>(REDUCE, op));
+        }
+
+        validate(&kernel::<T>(MATMUL, ""));
+    }
+
+    #[test]
+    fn kernels() {
+        validate_kernels::<f32>();
+        validate_kernels::<i32>();
+        validate_kernels::<u32>();
+    }
+
+    #[test]
+    fn grids() {
+        assert_eq!(grid(0), [1, 0]);
+        assert_eq!(grid(3), [3, 1]);
+        assert_eq!(grid(MAX_WORKGROUPS + 1), [MAX_WORKGROUPS as u32, 2]);
+
+        let max = MAX_WORKGROUPS as u32;
+        let block_len = MAX_WORKGROUPS * 16;
+
+        assert_eq!(matmul_blocks(0, 5), vec![]);
+        assert_eq!(matmul_blocks(33, 17), vec![([0, 0], [2, 3])]);
+        assert_eq!(
+            matmul_blocks(2, block_len + 20),
+            vec![([0, 0], [max, 1]), ([0, block_len], [2, 1])]
+        );
+        assert_eq!(
+            matmul_blocks(block_len + 1, 16),
+            vec![([0, 0], [1, max]), ([block_len, 0], [1, 1])]
+        );
+        assert_eq!(
+            broadcast_strides(&[1, 3, 1], &[2, 3, 4]).collect::<Vec<_>>(),
+            vec![0, 1, 0]
+        );
+    }
+
+    #[test]
+    fn elementwise() {
+        let Some(device) = device() else {
+            return;
+        };
+
+        let lhs = Array::from_shape_fn([3, 70], |[i, j]| (i * 70 + j) as f32);
+        let rhs = Array::from_shape_fn([1, 70], |[_, j]| j as f32 + 1.0);
+
+        let (lhs_gpu, rhs_gpu) = (lhs.to_gpu(&device), rhs.to_gpu(&device));
+
+        assert_eq!(lhs_gpu.to_host(), lhs);
+        assert_eq!((&lhs_gpu + &rhs_gpu).to_host(), &lhs + &rhs);
+        assert_eq!((&lhs_gpu - &rhs_gpu).to_host(), &lhs - &rhs);
+        assert_eq!((&lhs_gpu * &rhs_gpu).to_host(), &lhs * &rhs);
+        assert_eq!((&lhs_gpu / 2.0).to_host(), &lhs / 2.0);
+        assert_eq!(lhs.t().to_gpu(&device).to_host(), lhs.t());
+    }
+
+    #[test]
+    fn reductions() {
+        let Some(device) = device() else {
+            return;
+        };
+
+        // More than one pass of workgroups
+        let array = Array::from_shape_fn([300, 301], |[i, j]| (i * 301 + j) as i32 % 1000 - 500);
+        let array_gpu = array.to_gpu(&device);
+
+        assert_eq!(array_gpu.sum(), array.sum());
+        assert_eq!(array_gpu.max(), array.max());
+        assert_eq!(array_gpu.min(), array.min());
+
+        let empty = Array::<u32, 2>::zeros([0, 3]).to_gpu(&device);
+
+        assert_eq!(empty.sum(), 0);
+        assert_eq!(empty.max(), None);
+        assert_eq!((&empty + 1).to_host().shape(), &[0, 3]);
+    }
+
+    #[test]
+    fn matmul() {
+        let Some(device) = device() else {
+            return;
+        };
+
+        let lhs = Array::from_shape_fn([33, 20], |[i, p]| ((i + 2 * p) % 7) as f32 - 3.0);
+        let rhs = Array::from_shape_fn([20, 17], |[p, j]| ((3 * p + j) % 5) as f32 - 2.0);
+
+        assert_eq!(
+            lhs.to_gpu(&device).dot(&rhs.to_gpu(&device)).to_host(),
+            lhs.dot(&rhs)
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use std::slice;
+
+use half::{bf16, f16};
+
+use super::len_of;
+use super::type_id;
+use crate::{Array, ArrayBase, Storage};
+
+// Half precision floats only hold about three decimal digits, so sums and matrix
+// products of them are accumulated in f32 and rounded once at the end.
+trait Widen: Copy + 'static {
+    fn widen(self) -> f32;
+
+    fn narrow(value: f32) -> Self;
+}
+
+impl Widen for f16 {
+    fn widen(self) -> f32 {
+        self.to_f32()
+    }
+
+    fn narrow(value: f32) -> Self {
+        f16::from_f32(value)
+    }
+}
+
+impl Widen for bf16 {
+    fn widen(self) -> f32 {
+        self.to_f32()
+    }
+
+    fn narrow(value: f32) -> Self {
+        bf16::from_f32(value)
+    }
+}
+
+// Only called once the type ids of `T` and `H` are known to be equal
+fn widen<T, H: Widen>(val: &T) -> f32 {
+    type_id::cast_slice::<T, H>(slice::from_ref(val))[0].widen()
+}
+
+fn narrow_array<H: Widen, T, const D: usize>(values: Vec<f32>, shape: [usize; D]) -> Array<T, D> {
+    let vec: Vec<H> = values.into_iter().map(H::narrow).collect();
+
+    Array::init(type_id::cast_vec(vec), shape)
+}
+
+macro_rules! dispatch {
+    ($ty:ty, $f:ident::<$($generic:tt),*>($($arg:expr),*)) => {
+        if type_id::is::<$ty, f16>() {
+            Some($f::<f16, $($generic),*>($($arg),*))
+        } else if type_id::is::<$ty, bf16>() {
+            Some($f::<bf16, $($generic),*>($($arg),*))
+        } else {
+            None
+        }
+    };
+}
+
+pub(crate) fn try_widened_sum<T, S, const D: usize>(array: &ArrayBase<S, D>) -> Option<T>
+where
+    S: Storage<Elem = T>,
+{
+    dispatch!(T, widened_sum::<T, S, D>(array))
+}
+
+fn widened_sum<H: Widen, T, S: Storage<Elem = T>, const D: usize>(array: &ArrayBase<S, D>) -> T {
+    type_id::cast(H::narrow(array.flat().map(widen::<T, H>).sum()))
+}
+
+pub(crate) fn try_widened_sum_axis<T, S, const D: usize, const R: usize>(
+    array: &ArrayBase<S, D>,
+    axis: usize,
+) -> Option<Array<T, R>>
+where
+    S: Storage<Elem = T>,
+{
+    dispatch!(T, widened_sum_axis::<T, S, D, R>(array, axis))
+}
+
+fn widened_sum_axis<H: Widen, T, S: Storage<Elem = T>, const D: usize, const R: usize>(
+    array: &ArrayBase<S, D>,
+    axis: usize,
+) -> Array<T, R> {
+    let sums = array.fold_axis::<R, f32, _>(axis, 0.0, |acc, val| acc + widen::<T, H>(val));
+
+    narrow_array::<H, T, R>(sums.flat().copied().collect(), sums.shape)
+}
+
+pub(crate) fn try_widened_gemm<T, S1, S2>(
+    lhs: &ArrayBase<S1, 2>,
+    rhs: &ArrayBase<S2, 2>,
+) -> Option<Array<T, 2>>
+where
+    S1: Storage<Elem = T>,
+    S2: Storage<Elem = T>,
+{
+    dispatch!(T, widened_gemm::<T, S1, S2>(lhs, rhs))
+}
+
+fn widened_gemm<H: Widen, T, S1: Storage<Elem = T>, S2: Storage<Elem = T>>(
+    lhs: &ArrayBase<S1, 2>,
+    rhs: &ArrayBase<S2, 2>,
+) -> Array<T, 2> {
+    let [m, k] = lhs.shape;
+    let n = rhs.shape[1];
+
+    // Widening both operands up front keeps the conversions out of the inner loop
+    let lhs: Vec<f32> = lhs.flat().map(widen::<T, H>).collect();
+    let rhs: Vec<f32> = rhs.flat().map(widen::<T, H>).collect();
+
+    let mut vec = vec![0.0; len_of(&[m, n])];
+    for i in 0..m {
+        for p in 0..k {
+            let lhs_val = lhs[i * k + p];
+
+            for j in 0..n {
+                vec[i * n + j] += lhs_val * rhs[p * n + j];
+            }
+        }
+    }
+
+    narrow_array::<H, T, 2>(vec, [m, n])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Dot;
+
+    #[test]
+    fn widened_reductions() {
+        // Above 2048 the spacing of f16 is 2, so adding ones one by one gets stuck
+        let mut vec = vec![f16::ONE; 128];
+        vec[0] = f16::from_f32(2048.0);
+        let array = Array::init(vec, [128, 1]);
+
+        assert_eq!(array.sum(), f16::from_f32(2176.0));
+        assert_eq!(array.mean(), f16::from_f32(17.0));
+        assert_eq!(
+            array.sum_axis::<1>(0),
+            Array::init(vec![f16::from_f32(2176.0)], [1])
+        );
+        assert_eq!(array.sum_axis::<1>(1), array.reshape([128]).unwrap());
+
+        let array = Array::init(vec![bf16::ONE; 512], [512]);
+
+        assert_eq!(array.sum(), bf16::from_f32(512.0));
+    }
+
+    #[test]
+    fn widened_matmul() {
+        let lhs = Array::init(vec![f16::ONE; 2 * 4096], [2, 4096]);
+        let rhs = Array::init(vec![f16::ONE; 4096 * 3], [4096, 3]).flip_move(0);
+
+        assert_eq!(
+            lhs.dot(&rhs),
+            Array::init(vec![f16::from_f32(4096.0); 6], [2, 3])
+        );
+    }
+
+    #[test]
+    fn casts_and_scalars() {
+        let array = Array::init(vec![0.5f32, 1.5, 65504.0], [3]);
+
+        let halves = array.astype_lossy::<f16>();
+
+        assert_eq!(halves.astype::<f32>(), array);
+        assert_eq!(
+            (halves * f16::from_f32(2.0)).astype::<f32>(),
+            Array::init(vec![1.0, 3.0, f32::INFINITY], [3])
+        );
+    }
+}
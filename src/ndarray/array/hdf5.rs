@@ -0,0 +1,349 @@
+use std::error::Error;
+use std::fmt;
+use std::path::Path;
+
+use hdf5_pure::{Dataset, DatasetBuilder, File, FileBuilder};
+
+use super::access::resolve_slice;
+use crate::{Array, ArrayBase, ShapeError, Slice, SliceError, Storage};
+
+// The scalar types HDF5 datasets are read into and written from
+pub trait Hdf5Element: Copy {
+    fn read_rows(dataset: &Dataset, start: u64, count: u64) -> Result<Vec<Self>, hdf5_pure::Error>;
+
+    fn write_data(builder: &mut DatasetBuilder, data: &[Self]);
+}
+
+macro_rules! impl_hdf5_element {
+    ($($ty:ty => $read_rows:ident, $with_data:ident),*) => {
+        $(impl Hdf5Element for $ty {
+            fn read_rows(
+                dataset: &Dataset,
+                start: u64,
+                count: u64,
+            ) -> Result<Vec<Self>, hdf5_pure::Error> {
+                dataset.$read_rows(start, count)
+            }
+
+            fn write_data(builder: &mut DatasetBuilder, data: &[Self]) {
+                builder.$with_data(data);
+            }
+        })*
+    };
+}
+
+impl_hdf5_element!(
+    i8 => read_i8_rows, with_i8_data,
+    i16 => read_i16_rows, with_i16_data,
+    i32 => read_i32_rows, with_i32_data,
+    i64 => read_i64_rows, with_i64_data,
+    u8 => read_u8_rows, with_u8_data,
+    u16 => read_u16_rows, with_u16_data,
+    u32 => read_u32_rows, with_u32_data,
+    u64 => read_u64_rows, with_u64_data,
+    f32 => read_f32_rows, with_f32_data,
+    f64 => read_f64_rows, with_f64_data
+);
+
+#[derive(Debug)]
+pub enum Hdf5Error {
+    Hdf5(hdf5_pure::Error),
+    Shape(ShapeError),
+    Slice(SliceError),
+}
+
+impl fmt::Display for Hdf5Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Hdf5Error::Hdf5(error) => write!(f, "HDF5 error: {}", error),
+            Hdf5Error::Shape(error) => error.fmt(f),
+            Hdf5Error::Slice(error) => error.fmt(f),
+        }
+    }
+}
+
+impl Error for Hdf5Error {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Hdf5Error::Hdf5(error) => Some(error),
+            Hdf5Error::Shape(error) => Some(error),
+            Hdf5Error::Slice(error) => Some(error),
+        }
+    }
+}
+
+impl From<hdf5_pure::Error> for Hdf5Error {
+    fn from(error: hdf5_pure::Error) -> Self {
+        Hdf5Error::Hdf5(error)
+    }
+}
+
+impl From<ShapeError> for Hdf5Error {
+    fn from(error: ShapeError) -> Self {
+        Hdf5Error::Shape(error)
+    }
+}
+
+impl From<SliceError> for Hdf5Error {
+    fn from(error: SliceError) -> Self {
+        Hdf5Error::Slice(error)
+    }
+}
+
+// Only the metadata of a dataset is parsed until its elements are asked for
+pub struct Hdf5Reader {
+    file: File,
+}
+
+impl Hdf5Reader {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Hdf5Error> {
+        Ok(Hdf5Reader {
+            file: File::open(path)?,
+        })
+    }
+
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, Hdf5Error> {
+        Ok(Hdf5Reader {
+            file: File::from_bytes(bytes)?,
+        })
+    }
+
+    // Paths of the datasets in the root group
+    pub fn names(&self) -> Result<Vec<String>, Hdf5Error> {
+        Ok(self.file.root().datasets()?)
+    }
+
+    pub fn shape(&self, name: &str) -> Result<Vec<usize>, Hdf5Error> {
+        let shape = self.file.dataset(name)?.shape()?;
+
+        Ok(shape.into_iter().map(|len| len as usize).collect())
+    }
+
+    fn dataset_shape<const D: usize>(&self, name: &str) -> Result<[usize; D], Hdf5Error> {
+        let shape = self.shape(name)?;
+
+        if shape.len() != D {
+            return Err(ShapeError::IncompatibleRank {
+                ndim: shape.len(),
+                expected: D,
+            }
+            .into());
+        }
+
+        let mut array_shape = [0; D];
+        array_shape.copy_from_slice(&shape);
+
+        Ok(array_shape)
+    }
+
+    pub fn read<T: Hdf5Element, const D: usize>(
+        &self,
+        name: &str,
+    ) -> Result<Array<T, D>, Hdf5Error> {
+        let shape = self.dataset_shape::<D>(name)?;
+        let rows = shape.first().copied().unwrap_or(1);
+
+        let data = T::read_rows(&self.file.dataset(name)?, 0, rows as u64)?;
+
+        Ok(Array::try_init(data, shape)?)
+    }
+
+    // Reads a hyperslab of the dataset. Only the rows between the first and last row the
+    // slice selects are read from the file, the slices of the other axes and the step of
+    // the first one are applied to those rows in memory.
+    pub fn read_slice<T: Hdf5Element, I: Into<Slice> + Clone, const D: usize>(
+        &self,
+        name: &str,
+        slice: &[I; D],
+    ) -> Result<Array<T, D>, Hdf5Error> {
+        let mut shape = self.dataset_shape::<D>(name)?;
+        let first = match slice.first() {
+            Some(first) => first.clone().into(),
+            None => return self.read(name),
+        };
+
+        let (start, len, step) = resolve_slice(0, first, shape[0])?;
+        let rows = if len == 0 { 0 } else { (len - 1) * step + 1 };
+
+        // The remaining axes are checked before anything is read
+        for (axis, axis_slice) in slice.iter().enumerate().skip(1) {
+            resolve_slice(axis, axis_slice.clone().into(), shape[axis])?;
+        }
+
+        let data = T::read_rows(&self.file.dataset(name)?, start as u64, rows as u64)?;
+        shape[0] = rows;
+
+        let block = Array::try_init(data, shape)?;
+
+        let mut block_slice: [Slice; D] = slice.clone().map(Into::into);
+        block_slice[0] = Slice::from(..).step_by(step);
+
+        Ok(block.try_slice(&block_slice)?.to_owned())
+    }
+}
+
+// Datasets are collected in memory and written when the file is finished
+pub struct Hdf5Writer {
+    builder: FileBuilder,
+}
+
+impl Default for Hdf5Writer {
+    fn default() -> Self {
+        Hdf5Writer::new()
+    }
+}
+
+impl Hdf5Writer {
+    pub fn new() -> Self {
+        Hdf5Writer {
+            builder: FileBuilder::new(),
+        }
+    }
+
+    pub fn add_array<T: Hdf5Element, S: Storage<Elem = T>, const D: usize>(
+        &mut self,
+        name: &str,
+        array: &ArrayBase<S, D>,
+    ) -> &mut Self {
+        self.add(name, array, None)
+    }
+
+    // Stores the dataset in chunks of shape `chunk`, so reading a few rows of it later
+    // only decodes the chunks holding them
+    pub fn add_array_chunked<T: Hdf5Element, S: Storage<Elem = T>, const D: usize>(
+        &mut self,
+        name: &str,
+        array: &ArrayBase<S, D>,
+        chunk: [usize; D],
+    ) -> &mut Self {
+        self.add(name, array, Some(chunk))
+    }
+
+    fn add<T: Hdf5Element, S: Storage<Elem = T>, const D: usize>(
+        &mut self,
+        name: &str,
+        array: &ArrayBase<S, D>,
+        chunk: Option<[usize; D]>,
+    ) -> &mut Self {
+        let data: Vec<T> = array.flat().copied().collect();
+        let shape: Vec<u64> = array.shape().iter().map(|&len| len as u64).collect();
+
+        let builder = self.builder.create_dataset(name);
+        T::write_data(builder, &data);
+        builder.with_shape(&shape);
+
+        if let Some(chunk) = chunk {
+            let chunk: Vec<u64> = chunk.iter().map(|&len| len as u64).collect();
+            builder.with_chunks(&chunk);
+        }
+
+        self
+    }
+
+    pub fn finish(self) -> Result<Vec<u8>, Hdf5Error> {
+        Ok(self.builder.finish()?)
+    }
+
+    pub fn write<P: AsRef<Path>>(self, path: P) -> Result<(), Hdf5Error> {
+        Ok(self.builder.write(path)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s;
+
+    fn file() -> Hdf5Reader {
+        let grid = Array::from_shape_fn([6, 5], |[i, j]| (i * 5 + j) as f64);
+        let labels = Array::init(vec![3u8, 1, 4], [3]);
+
+        let mut writer = Hdf5Writer::new();
+        writer
+            .add_array_chunked("grid", &grid, [2, 5])
+            .add_array("transposed", &grid.t())
+            .add_array("labels", &labels);
+
+        Hdf5Reader::from_bytes(writer.finish().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let reader = file();
+        let grid = Array::from_shape_fn([6, 5], |[i, j]| (i * 5 + j) as f64);
+
+        let mut names = reader.names().unwrap();
+        names.sort();
+
+        assert_eq!(names, vec!["grid", "labels", "transposed"]);
+        assert_eq!(reader.shape("transposed").unwrap(), vec![5, 6]);
+        assert_eq!(reader.read::<f64, 2>("grid").unwrap(), grid);
+        assert_eq!(reader.read::<f64, 2>("transposed").unwrap(), grid.t());
+        assert_eq!(
+            reader.read::<u8, 1>("labels").unwrap(),
+            Array::init(vec![3, 1, 4], [3])
+        );
+        assert!(matches!(
+            reader.read::<f64, 1>("grid"),
+            Err(Hdf5Error::Shape(ShapeError::IncompatibleRank {
+                ndim: 2,
+                expected: 1
+            }))
+        ));
+        assert!(matches!(
+            reader.read::<f64, 2>("missing"),
+            Err(Hdf5Error::Hdf5(_))
+        ));
+    }
+
+    #[test]
+    fn files() {
+        let path = std::env::temp_dir().join(format!("nd_array_{}.h5", std::process::id()));
+        let array = Array::from_shape_fn([2, 3, 4], |[i, j, k]| (i * 12 + j * 4 + k) as i32);
+
+        let mut writer = Hdf5Writer::new();
+        writer.add_array("cube", &array);
+        writer.write(&path).unwrap();
+
+        let reader = Hdf5Reader::open(&path).unwrap();
+
+        assert_eq!(reader.read::<i32, 3>("cube").unwrap(), array);
+        assert_eq!(
+            reader
+                .read_slice::<i32, _, 3>("cube", &s![1.., ..;2, 3..])
+                .unwrap(),
+            array.slice(&s![1.., ..;2, 3..])
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn hyperslabs() {
+        let reader = file();
+        let grid = Array::from_shape_fn([6, 5], |[i, j]| (i * 5 + j) as f64);
+
+        for slice in [
+            s![1..4, ..],
+            s![1..;2, 1..4],
+            s![Slice::last(2), ..;3],
+            s![3..3, ..],
+        ] {
+            assert_eq!(
+                reader.read_slice::<f64, _, 2>("grid", &slice).unwrap(),
+                grid.slice(&slice)
+            );
+        }
+
+        assert_eq!(
+            reader
+                .read_slice::<f64, _, 2>("transposed", &s![4.., ..;5])
+                .unwrap(),
+            Array::init(vec![4.0, 29.0], [1, 2])
+        );
+        assert!(matches!(
+            reader.read_slice::<f64, _, 2>("grid", &s![.., 2..6]),
+            Err(Hdf5Error::Slice(SliceError::OutOfBounds { axis: 1, .. }))
+        ));
+    }
+}
@@ -0,0 +1,243 @@
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
+use image::{DynamicImage, ImageBuffer, Luma, Pixel, Primitive};
+
+use crate::Array;
+
+// Order of the color channels along the last axis of an image array
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChannelOrder {
+    Rgb,
+    Bgr,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageConversionError {
+    ChannelMismatch { expected: usize, found: usize },
+    UnsupportedChannels(usize),
+    TooLarge { height: usize, width: usize },
+}
+
+impl fmt::Display for ImageConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImageConversionError::ChannelMismatch { expected, found } => write!(
+                f,
+                "Number of channels of the array does not match the pixel: {} != {}",
+                found, expected
+            ),
+            ImageConversionError::UnsupportedChannels(channels) => write!(
+                f,
+                "Images can not have {} channels, only 1 to 4 are supported",
+                channels
+            ),
+            ImageConversionError::TooLarge { height, width } => write!(
+                f,
+                "Image of {}x{} pixels exceeds u32::MAX along an axis",
+                height, width
+            ),
+        }
+    }
+}
+
+impl Error for ImageConversionError {}
+
+impl<T, const D: usize> Array<T, D> {
+    // Takes the vector of arrays laid out in row-major order, copies the others
+    fn into_row_major_vec(self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        if self.contiguous_range() == Some(0..self.data.len()) {
+            self.data
+        } else {
+            self.flat().cloned().collect()
+        }
+    }
+}
+
+fn image_size(height: usize, width: usize) -> Result<(u32, u32), ImageConversionError> {
+    match (u32::try_from(width), u32::try_from(height)) {
+        (Ok(width), Ok(height)) => Ok((width, height)),
+        _ => Err(ImageConversionError::TooLarge { height, width }),
+    }
+}
+
+// Swaps the first and third channel of every pixel, which turns RGB(A) into BGR(A) and back
+fn swap_red_blue<T>(vec: &mut [T], channels: usize) {
+    if channels >= 3 {
+        for pixel in vec.chunks_exact_mut(channels) {
+            pixel.swap(0, 2);
+        }
+    }
+}
+
+// Images are moved into arrays of shape (height, width, channels) without copying
+impl<P: Pixel> From<ImageBuffer<P, Vec<P::Subpixel>>> for Array<P::Subpixel, 3> {
+    fn from(image: ImageBuffer<P, Vec<P::Subpixel>>) -> Self {
+        let (width, height) = image.dimensions();
+        let shape = [height as usize, width as usize, P::CHANNEL_COUNT as usize];
+
+        Array::init(image.into_raw(), shape)
+    }
+}
+
+impl<P: Pixel> TryFrom<Array<P::Subpixel, 3>> for ImageBuffer<P, Vec<P::Subpixel>> {
+    type Error = ImageConversionError;
+
+    fn try_from(array: Array<P::Subpixel, 3>) -> Result<Self, Self::Error> {
+        let [height, width, channels] = array.shape;
+
+        if channels != P::CHANNEL_COUNT as usize {
+            return Err(ImageConversionError::ChannelMismatch {
+                expected: P::CHANNEL_COUNT as usize,
+                found: channels,
+            });
+        }
+
+        let (width, height) = image_size(height, width)?;
+
+        Ok(
+            ImageBuffer::from_raw(width, height, array.into_row_major_vec())
+                .expect("Length of the array must match the image"),
+        )
+    }
+}
+
+impl<T: Primitive> From<ImageBuffer<Luma<T>, Vec<T>>> for Array<T, 2> {
+    fn from(image: ImageBuffer<Luma<T>, Vec<T>>) -> Self {
+        let (width, height) = image.dimensions();
+
+        Array::init(image.into_raw(), [height as usize, width as usize])
+    }
+}
+
+impl<T: Primitive> TryFrom<Array<T, 2>> for ImageBuffer<Luma<T>, Vec<T>> {
+    type Error = ImageConversionError;
+
+    fn try_from(array: Array<T, 2>) -> Result<Self, Self::Error> {
+        let (width, height) = image_size(array.shape[0], array.shape[1])?;
+
+        Ok(
+            ImageBuffer::from_raw(width, height, array.into_row_major_vec())
+                .expect("Length of the array must match the image"),
+        )
+    }
+}
+
+impl Array<u8, 3> {
+    // 8-bit images keep their channels and are moved without copying when the order
+    // is RGB, the others are converted to 8-bit first.
+    pub fn from_dynamic_image(image: DynamicImage, order: ChannelOrder) -> Self {
+        let mut array = match image.color().channel_count() {
+            1 => Array::from(image.into_luma8()),
+            2 => Array::from(image.into_luma_alpha8()),
+            3 => Array::from(image.into_rgb8()),
+            _ => Array::from(image.into_rgba8()),
+        };
+
+        if order == ChannelOrder::Bgr {
+            swap_red_blue(&mut array.data, array.shape[2]);
+        }
+
+        array
+    }
+
+    pub fn into_dynamic_image(
+        self,
+        order: ChannelOrder,
+    ) -> Result<DynamicImage, ImageConversionError> {
+        let [height, width, channels] = self.shape;
+
+        if !(1..=4).contains(&channels) {
+            return Err(ImageConversionError::UnsupportedChannels(channels));
+        }
+
+        let (width, height) = image_size(height, width)?;
+        let mut vec = self.into_row_major_vec();
+
+        if order == ChannelOrder::Bgr {
+            swap_red_blue(&mut vec, channels);
+        }
+
+        let image = match channels {
+            1 => ImageBuffer::from_raw(width, height, vec).map(DynamicImage::ImageLuma8),
+            2 => ImageBuffer::from_raw(width, height, vec).map(DynamicImage::ImageLumaA8),
+            3 => ImageBuffer::from_raw(width, height, vec).map(DynamicImage::ImageRgb8),
+            _ => ImageBuffer::from_raw(width, height, vec).map(DynamicImage::ImageRgba8),
+        };
+
+        Ok(image.expect("Length of the array must match the image"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use image::{GrayImage, Rgb, RgbImage, Rgba};
+
+    use super::*;
+
+    #[test]
+    fn image_buffers() {
+        let pixel = |x: u32, y: u32| Rgb([x as u8, y as u8, 7]);
+        let image = RgbImage::from_fn(3, 2, pixel);
+        let ptr = image.as_ptr();
+
+        let array = Array::from(image);
+
+        assert_eq!(array.shape(), &[2, 3, 3]);
+        assert_eq!(
+            (0..3).map(|c| array[[1, 2, c]]).collect::<Vec<_>>(),
+            vec![2, 1, 7]
+        );
+        assert_eq!(array.data.as_ptr(), ptr);
+        assert_eq!(
+            RgbImage::try_from(array.clone()),
+            Ok(RgbImage::from_fn(3, 2, pixel))
+        );
+        assert_eq!(
+            ImageBuffer::<Rgba<u8>, Vec<u8>>::try_from(array).err(),
+            Some(ImageConversionError::ChannelMismatch {
+                expected: 4,
+                found: 3
+            })
+        );
+    }
+
+    #[test]
+    fn gray_images() {
+        let array = Array::init((0..6).collect(), [2, 3]);
+
+        let image = GrayImage::try_from(array.t().to_owned()).unwrap();
+
+        assert_eq!(image.dimensions(), (2, 3));
+        assert_eq!(image.get_pixel(1, 0), &Luma([3]));
+        assert_eq!(Array::from(image), array.t());
+    }
+
+    #[test]
+    fn dynamic_images() {
+        let image = RgbImage::from_fn(2, 1, |x, _| Rgb([1, 2, 3 + x as u8]));
+        let dynamic = DynamicImage::ImageRgb8(image);
+
+        let rgb = Array::from_dynamic_image(dynamic.clone(), ChannelOrder::Rgb);
+        let bgr = Array::from_dynamic_image(dynamic.clone(), ChannelOrder::Bgr);
+
+        assert_eq!(rgb, Array::init(vec![1, 2, 3, 1, 2, 4], [1, 2, 3]));
+        assert_eq!(bgr, Array::init(vec![3, 2, 1, 4, 2, 1], [1, 2, 3]));
+        assert_eq!(bgr.into_dynamic_image(ChannelOrder::Bgr), Ok(dynamic));
+
+        let wide = DynamicImage::ImageLuma16(ImageBuffer::from_raw(1, 1, vec![u16::MAX]).unwrap());
+
+        assert_eq!(
+            Array::from_dynamic_image(wide, ChannelOrder::Rgb),
+            Array::init(vec![255], [1, 1, 1])
+        );
+        assert_eq!(
+            Array::<u8, 3>::zeros([1, 1, 5]).into_dynamic_image(ChannelOrder::Rgb),
+            Err(ImageConversionError::UnsupportedChannels(5))
+        );
+    }
+}
@@ -1,12 +1,18 @@
-use std::ops::Range;
+use std::{iter::FusedIterator, marker::PhantomData, ops::Range, slice};
 
 use arrayvec::ArrayVec;
 
-use crate::Array;
+use super::dynamic::DynIndices;
+use super::len_of;
+use crate::{ArrayBase, Storage, StorageMut, View};
 
-impl<'a, T: Clone, const D: usize> Array<'a, T, D> {
+impl<T, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
     pub fn flat(&self) -> Iter<'_, T, D> {
-        Iter::init(self)
+        Iter::init(self.view())
+    }
+
+    pub fn indexed_iter(&self) -> IndexedIter<'_, T, D> {
+        IndexedIter::init(self.view())
     }
 
     pub fn axes(&self) -> Axes<'_, D> {
@@ -14,60 +20,321 @@ impl<'a, T: Clone, const D: usize> Array<'a, T, D> {
     }
 
     pub fn axis_view(&self, axis: usize) -> AxisView<'_, T, D> {
-        AxisView::init(self, axis)
+        AxisView::init(self.view(), axis)
+    }
+
+    // Consecutive views of `size` subviews along `axis`, the last one holding the rest if
+    // `size` does not divide the length of the axis
+    pub fn axis_chunks_iter(&self, axis: usize, size: usize) -> AxisView<'_, T, D> {
+        AxisView::init_chunks(self.view(), axis, size)
     }
 }
 
-pub struct Iter<'a, T: Clone, const D: usize> {
-    array: &'a Array<'a, T, D>,
-    indices: [usize; D],
+impl<T, S: StorageMut<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn flat_mut(&mut self) -> IterMut<'_, T, D> {
+        IterMut::init(self)
+    }
+}
+
+// A multi-index of a fixed rank or of a rank only known at runtime
+pub(crate) trait IndexBuf: Clone + AsRef<[usize]> + AsMut<[usize]> {}
+
+impl<const D: usize> IndexBuf for [usize; D] {}
+
+impl IndexBuf for DynIndices {}
+
+#[derive(Clone)]
+pub(crate) struct Indices<I> {
+    shape: I,
+    front: I,
+    back: I,
+    pub(crate) remaining: usize,
 }
 
-impl<'a, T: Clone, const D: usize> Iter<'a, T, D> {
-    fn init(array: &'a Array<'a, T, D>) -> Self {
-        Iter {
-            array,
-            indices: [0; D],
+impl<I: IndexBuf> Indices<I> {
+    pub(crate) fn init(shape: I) -> Self {
+        let len = shape.as_ref().iter().product();
+
+        Indices::init_range(shape, 0..len)
+    }
+
+    // Only visits the multi-indices whose position in row-major order falls in `range`
+    pub(crate) fn init_range(shape: I, range: Range<usize>) -> Self {
+        let unravel = |mut pos: usize| {
+            let mut indices = shape.clone();
+            let ndim = indices.as_ref().len();
+
+            for axis in (0..ndim).rev() {
+                let len = shape.as_ref()[axis];
+
+                indices.as_mut()[axis] = pos % len;
+                pos /= len;
+            }
+
+            indices
+        };
+
+        let remaining = range.len();
+        let (front, back) = if remaining != 0 {
+            (unravel(range.start), unravel(range.end - 1))
+        } else {
+            let mut zeros = shape.clone();
+            zeros.as_mut().fill(0);
+
+            (zeros.clone(), zeros)
+        };
+
+        Indices {
+            shape,
+            front,
+            back,
+            remaining,
+        }
+    }
+
+    pub(crate) fn next_front(&mut self) -> Option<I> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let indices = self.front.clone();
+
+        self.remaining -= 1;
+        if self.remaining != 0 {
+            self.increment_front();
+        }
+
+        Some(indices)
+    }
+
+    pub(crate) fn next_back(&mut self) -> Option<I> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let indices = self.back.clone();
+
+        self.remaining -= 1;
+        if self.remaining != 0 {
+            self.decrement_back();
         }
+
+        Some(indices)
     }
 
-    fn increment_indices(&mut self) {
-        self.increment_idx_at_axis(D - 1)
+    fn increment_front(&mut self) {
+        let (shape, front) = (self.shape.as_ref(), self.front.as_mut());
+
+        for axis in (0..shape.len()).rev() {
+            front[axis] += 1;
+
+            if front[axis] < shape[axis] {
+                break;
+            }
+
+            front[axis] = 0;
+        }
     }
 
-    fn increment_idx_at_axis(&mut self, axis: usize) {
-        self.indices[axis] += 1;
+    fn decrement_back(&mut self) {
+        let (shape, back) = (self.shape.as_ref(), self.back.as_mut());
 
-        if axis != 0 && self.indices[axis] >= self.array.shape[axis] {
-            self.indices[axis] = 0;
+        for axis in (0..shape.len()).rev() {
+            if back[axis] > 0 {
+                back[axis] -= 1;
+                break;
+            }
 
-            self.increment_idx_at_axis(axis - 1);
+            back[axis] = shape[axis] - 1;
         }
     }
 }
 
-impl<'a, T: Clone, const D: usize> Iterator for Iter<'a, T, D> {
+pub struct Iter<'a, T, const D: usize> {
+    kind: IterKind<'a, T, D>,
+}
+
+enum IterKind<'a, T, const D: usize> {
+    Contiguous(slice::Iter<'a, T>),
+    Strided {
+        view: View<'a, T, D>,
+        indices: Indices<[usize; D]>,
+    },
+}
+
+impl<'a, T, const D: usize> Iter<'a, T, D> {
+    fn init(view: View<'a, T, D>) -> Self {
+        Iter::init_range(view, 0..len_of(&view.shape))
+    }
+
+    // Iterates over the elements at positions `range` of the row-major order
+    pub(crate) fn init_range(view: View<'a, T, D>, range: Range<usize>) -> Self {
+        let kind = match view.contiguous_range() {
+            Some(contiguous) => IterKind::Contiguous(view.data[contiguous][range].iter()),
+            None => IterKind::Strided {
+                view,
+                indices: Indices::init_range(view.shape, range),
+            },
+        };
+
+        Iter { kind }
+    }
+}
+
+impl<'a, T, const D: usize> Iterator for Iter<'a, T, D> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let item = self.array.get(self.indices);
+        match &mut self.kind {
+            IterKind::Contiguous(iter) => iter.next(),
+            IterKind::Strided { view, indices } => {
+                let indices = indices.next_front()?;
 
-        self.increment_indices();
+                Some(&view.data[view.offset_of(&indices)])
+            }
+        }
+    }
 
-        item
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.kind {
+            IterKind::Contiguous(iter) => iter.size_hint(),
+            IterKind::Strided { indices, .. } => (indices.remaining, Some(indices.remaining)),
+        }
     }
 }
 
+impl<'a, T, const D: usize> DoubleEndedIterator for Iter<'a, T, D> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match &mut self.kind {
+            IterKind::Contiguous(iter) => iter.next_back(),
+            IterKind::Strided { view, indices } => {
+                let indices = indices.next_back()?;
+
+                Some(&view.data[view.offset_of(&indices)])
+            }
+        }
+    }
+}
+
+impl<'a, T, const D: usize> ExactSizeIterator for Iter<'a, T, D> {}
+
+impl<'a, T, const D: usize> FusedIterator for Iter<'a, T, D> {}
+
+pub struct IndexedIter<'a, T, const D: usize> {
+    view: View<'a, T, D>,
+    indices: Indices<[usize; D]>,
+}
+
+impl<'a, T, const D: usize> IndexedIter<'a, T, D> {
+    fn init(view: View<'a, T, D>) -> Self {
+        IndexedIter {
+            view,
+            indices: Indices::init(view.shape),
+        }
+    }
+}
+
+impl<'a, T, const D: usize> Iterator for IndexedIter<'a, T, D> {
+    type Item = ([usize; D], &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let indices = self.indices.next_front()?;
+
+        Some((indices, &self.view.data[self.view.offset_of(&indices)]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.indices.remaining, Some(self.indices.remaining))
+    }
+}
+
+impl<'a, T, const D: usize> DoubleEndedIterator for IndexedIter<'a, T, D> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let indices = self.indices.next_back()?;
+
+        Some((indices, &self.view.data[self.view.offset_of(&indices)]))
+    }
+}
+
+impl<'a, T, const D: usize> ExactSizeIterator for IndexedIter<'a, T, D> {}
+
+impl<'a, T, const D: usize> FusedIterator for IndexedIter<'a, T, D> {}
+
+pub struct IterMut<'a, T, const D: usize> {
+    view: ArrayBase<*mut T, D>,
+    indices: Indices<[usize; D]>,
+    marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T, const D: usize> IterMut<'a, T, D> {
+    fn init<S: StorageMut<Elem = T>>(array: &'a mut ArrayBase<S, D>) -> Self {
+        let view = ArrayBase {
+            data: array.data.as_mut_slice().as_mut_ptr(),
+            shape: array.shape,
+            strides: array.strides,
+            offset: array.offset,
+        };
+
+        // Safety: the mutable borrow of the array is held for as long as the iterator lives
+        unsafe { IterMut::init_range(view, 0..len_of(&array.shape)) }
+    }
+
+    // Safety: `view` must point into a buffer that is mutably borrowed for `'a`, and no
+    // other iterator may visit a position of `range`.
+    pub(crate) unsafe fn init_range(view: ArrayBase<*mut T, D>, range: Range<usize>) -> Self {
+        IterMut {
+            view,
+            indices: Indices::init_range(view.shape, range),
+            marker: PhantomData,
+        }
+    }
+
+    fn get(&self, indices: [usize; D]) -> &'a mut T {
+        // SAFETY: every multi-index is visited at most once and the layout of the
+        // array maps distinct multi-indices to distinct positions of the buffer,
+        // so no two references handed out by this iterator alias.
+        unsafe { &mut *self.view.data.add(self.view.offset_of(&indices)) }
+    }
+}
+
+impl<'a, T, const D: usize> Iterator for IterMut<'a, T, D> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let indices = self.indices.next_front()?;
+
+        Some(self.get(indices))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.indices.remaining, Some(self.indices.remaining))
+    }
+}
+
+impl<'a, T, const D: usize> DoubleEndedIterator for IterMut<'a, T, D> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let indices = self.indices.next_back()?;
+
+        Some(self.get(indices))
+    }
+}
+
+impl<'a, T, const D: usize> ExactSizeIterator for IterMut<'a, T, D> {}
+
+impl<'a, T, const D: usize> FusedIterator for IterMut<'a, T, D> {}
+
 pub struct Axes<'a, const D: usize> {
     axis: usize,
+    end: usize,
     shape: &'a [usize; D],
-    strides: &'a [usize; D],
+    strides: &'a [isize; D],
 }
 
 impl<'a, const D: usize> Axes<'a, D> {
-    pub fn init(shape: &'a [usize; D], strides: &'a [usize; D]) -> Self {
+    pub fn init(shape: &'a [usize; D], strides: &'a [isize; D]) -> Self {
         Axes {
             axis: 0,
+            end: D,
             shape,
             strides,
         }
@@ -75,55 +342,117 @@ impl<'a, const D: usize> Axes<'a, D> {
 }
 
 impl<'a, const D: usize> Iterator for Axes<'a, D> {
-    type Item = (usize, usize);
+    type Item = (usize, isize);
 
     fn next(&mut self) -> Option<Self::Item> {
-        let shape_stride = if self.axis < D {
-            Some((self.shape[self.axis], self.strides[self.axis]))
+        if self.axis < self.end {
+            let shape_stride = (self.shape[self.axis], self.strides[self.axis]);
+
+            self.axis += 1;
+
+            Some(shape_stride)
         } else {
             None
-        };
+        }
+    }
 
-        self.axis += 1;
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.axis;
 
-        shape_stride
+        (len, Some(len))
     }
 }
 
-pub struct AxisView<'a, T: Clone, const D: usize> {
-    array: &'a Array<'a, T, D>,
+impl<'a, const D: usize> DoubleEndedIterator for Axes<'a, D> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.axis < self.end {
+            self.end -= 1;
+
+            Some((self.shape[self.end], self.strides[self.end]))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, const D: usize> ExactSizeIterator for Axes<'a, D> {}
+
+impl<'a, const D: usize> FusedIterator for Axes<'a, D> {}
+
+pub struct AxisView<'a, T, const D: usize> {
+    view: View<'a, T, D>,
     slice: [Range<usize>; D],
     axis: usize,
+    size: usize,
     idx: usize,
+    end: usize,
 }
 
-impl<'a, T: Clone, const D: usize> AxisView<'a, T, D> {
-    pub fn init(array: &'a Array<'a, T, D>, axis: usize) -> AxisView<'a, T, D> {
+impl<'a, T, const D: usize> AxisView<'a, T, D> {
+    pub fn init(view: View<'a, T, D>, axis: usize) -> AxisView<'a, T, D> {
+        AxisView::init_chunks(view, axis, 1)
+    }
+
+    pub fn init_chunks(view: View<'a, T, D>, axis: usize, size: usize) -> AxisView<'a, T, D> {
+        if size == 0 {
+            panic!("Chunk size must be greater than zero");
+        }
         if axis >= D {
             panic!("Axis out of bound: {} > {}", axis, D);
         }
 
         let mut slice = ArrayVec::new_const();
-        for (shape, _) in array.axes() {
+        for (shape, _) in view.axes() {
             slice.push(0..shape);
         }
 
         AxisView {
-            array,
+            view,
             slice: slice.into_inner().unwrap(),
             axis,
+            size,
             idx: 0,
+            end: view.shape[axis].div_ceil(size),
         }
     }
+
+    fn view_at(&self, idx: usize) -> View<'a, T, D> {
+        let start = idx * self.size;
+        let end = start
+            .saturating_add(self.size)
+            .min(self.view.shape[self.axis]);
+
+        let mut slice = self.slice.clone();
+        slice[self.axis] = start..end;
+
+        self.view.slice_move(&slice)
+    }
+
+    // Hands the first `index` remaining subviews to the left half and the rest to the right
+    #[cfg(feature = "rayon")]
+    pub(crate) fn split_at(self, index: usize) -> (Self, Self) {
+        let mid = self.idx + index;
+
+        (
+            AxisView {
+                view: self.view,
+                slice: self.slice.clone(),
+                axis: self.axis,
+                size: self.size,
+                idx: self.idx,
+                end: mid,
+            },
+            AxisView { idx: mid, ..self },
+        )
+    }
 }
 
-impl<'a, T: Clone, const D: usize> Iterator for AxisView<'a, T, D> {
-    type Item = Array<'a, T, D>;
+impl<'a, T, const D: usize> Iterator for AxisView<'a, T, D> {
+    type Item = View<'a, T, D>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.idx < self.array.shape[self.axis] {
-            self.slice[self.axis] = self.idx..self.idx + 1;
-            let view = self.array.slice(&self.slice);
+        if self.idx < self.end {
+            let view = self.view_at(self.idx);
 
             self.idx += 1;
 
@@ -132,11 +461,34 @@ impl<'a, T: Clone, const D: usize> Iterator for AxisView<'a, T, D> {
             None
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.idx;
+
+        (len, Some(len))
+    }
 }
 
+impl<'a, T, const D: usize> DoubleEndedIterator for AxisView<'a, T, D> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.idx < self.end {
+            self.end -= 1;
+
+            Some(self.view_at(self.end))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T, const D: usize> ExactSizeIterator for AxisView<'a, T, D> {}
+
+impl<'a, T, const D: usize> FusedIterator for AxisView<'a, T, D> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Array;
 
     #[test]
     fn iter() {
@@ -150,4 +502,215 @@ mod tests {
             vec![1, 2, 3, 4, 5, 6]
         )
     }
+
+    #[test]
+    fn indexed_iter() {
+        // 2-D array:
+        // 1 2 3
+        // 4 5 6
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        assert_eq!(
+            array.indexed_iter().collect::<Vec<([usize; 2], &usize)>>(),
+            vec![
+                ([0, 0], &1),
+                ([0, 1], &2),
+                ([0, 2], &3),
+                ([1, 0], &4),
+                ([1, 1], &5),
+                ([1, 2], &6)
+            ]
+        );
+    }
+
+    #[test]
+    fn indexed_iter_strided() {
+        // 2-D array:
+        // 1 2 3
+        // 4 5 6
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        // 6 5 4
+        // 3 2 1
+        let flipped = array.flip(0);
+        let flipped = flipped.flip(1);
+
+        for (indices, val) in flipped.indexed_iter() {
+            assert_eq!(flipped[indices], *val);
+        }
+        assert_eq!(flipped.indexed_iter().next_back(), Some(([1, 2], &1)));
+    }
+
+    #[test]
+    fn iter_mut() {
+        // 2-D array:
+        // 1 2 3
+        // 4 5 6
+        let mut array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        for val in array.flat_mut() {
+            *val *= 2;
+        }
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![2, 4, 6, 8, 10, 12]
+        )
+    }
+
+    #[test]
+    fn iter_mut_strided() {
+        // 2-D array:
+        // 1 2 3
+        // 4 5 6
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        // 1 4
+        // 2 5
+        // 3 6
+        let mut transposed = array.transpose();
+
+        for (idx, val) in transposed.flat_mut().enumerate() {
+            *val += idx * 10;
+        }
+
+        assert_eq!(
+            transposed.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 14, 22, 35, 43, 56]
+        );
+    }
+
+    #[test]
+    fn iter_contiguous_slice() {
+        // 2-D array:
+        // 1   2  3  4
+        // 5   6  7  8
+        // 9  10 11 12
+        // 13 14 15 16
+        let array = Array::init((1..17).collect(), [4, 4]);
+
+        let rows = array.slice(&[1..3, 0..4]);
+        let mut iter = rows.flat();
+
+        assert!(matches!(iter.kind, IterKind::Contiguous(_)));
+        assert_eq!(iter.len(), 8);
+        assert_eq!(iter.next_back(), Some(&12));
+        assert_eq!(
+            iter.copied().collect::<Vec<usize>>(),
+            vec![5, 6, 7, 8, 9, 10, 11]
+        );
+    }
+
+    #[test]
+    fn iter_len() {
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        let mut iter = array.flat();
+        assert_eq!(iter.len(), 6);
+
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.len(), 4);
+    }
+
+    #[test]
+    fn iter_rev() {
+        // 2-D array:
+        // 1 2 3
+        // 4 5 6
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        assert_eq!(
+            array.flat().rev().copied().collect::<Vec<usize>>(),
+            vec![6, 5, 4, 3, 2, 1]
+        );
+        assert_eq!(
+            array.t().flat().rev().copied().collect::<Vec<usize>>(),
+            vec![6, 3, 5, 2, 4, 1]
+        );
+        assert_eq!(array.flat().rfind(|val| **val % 2 == 1), Some(&5));
+    }
+
+    #[test]
+    fn iter_meet_in_the_middle() {
+        let array = Array::arange(0..5);
+
+        let mut iter = array.flat();
+
+        assert_eq!(iter.next(), Some(&0));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&3));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn iter_empty() {
+        let array: Array<usize, 2> = Array::init(vec![], [3, 0]);
+
+        assert_eq!(array.flat().len(), 0);
+        assert_eq!(array.flat().next(), None);
+    }
+
+    #[test]
+    fn iter_mut_rev() {
+        let mut array = Array::arange(0..4);
+
+        for (idx, val) in array.flat_mut().rev().enumerate() {
+            *val += idx * 10;
+        }
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![30, 21, 12, 3]
+        );
+    }
+
+    #[test]
+    fn axes() {
+        let array: Array<usize, 3> = Array::zeros([2, 3, 4]);
+
+        assert_eq!(array.axes().len(), 3);
+        assert_eq!(
+            array.axes().rev().collect::<Vec<(usize, isize)>>(),
+            vec![(4, 1), (3, 4), (2, 12)]
+        );
+    }
+
+    #[test]
+    fn axis_view_rev() {
+        // 2-D array:
+        // 1 2 3
+        // 4 5 6
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        let axis_view = array.axis_view(1);
+        assert_eq!(axis_view.len(), 3);
+
+        let columns = axis_view
+            .rev()
+            .map(|view| view.flat().copied().collect())
+            .collect::<Vec<Vec<usize>>>();
+
+        assert_eq!(columns, vec![vec![3, 6], vec![2, 5], vec![1, 4]]);
+    }
+
+    #[test]
+    fn axis_chunks_iter() {
+        let array = Array::from_shape_fn([7, 2], |[i, j]| i * 2 + j);
+
+        let batches = array.axis_chunks_iter(0, 3);
+        assert_eq!(batches.len(), 3);
+
+        let shapes = batches.map(|batch| *batch.shape()).collect::<Vec<_>>();
+        assert_eq!(shapes, vec![[3, 2], [3, 2], [1, 2]]);
+
+        let last = array.axis_chunks_iter(0, 3).next_back().unwrap();
+        assert_eq!(last.flat().copied().collect::<Vec<usize>>(), vec![12, 13]);
+
+        let columns = array.axis_chunks_iter(1, 5).collect::<Vec<_>>();
+        assert_eq!(columns, vec![array.view()]);
+    }
 }
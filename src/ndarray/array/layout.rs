@@ -0,0 +1,243 @@
+use std::ops::Range;
+
+use super::checked_len;
+use super::raw::is_unaliased;
+use crate::{Array, ArrayBase, CowArray, ShapeError, Storage, StorageMut};
+
+// The order in which the elements of an array are laid out or visited, the last axis
+// changing fastest in row-major (C) order and the first one in column-major (Fortran) order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+    RowMajor,
+    ColumnMajor,
+}
+
+impl<T, const D: usize> Array<T, D> {
+    // Takes `vec` in column-major order, as LAPACK, Fortran and MATLAB store arrays
+    pub fn init_f(vec: Vec<T>, shape: [usize; D]) -> Self {
+        Self::try_init_f(vec, shape).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    pub fn try_init_f(vec: Vec<T>, shape: [usize; D]) -> Result<Self, ShapeError> {
+        checked_len(&shape)?;
+
+        // The column-major data of an array is the row-major data of its transpose
+        let mut reversed = shape;
+        reversed.reverse();
+
+        Ok(Array::try_init(vec, reversed)?.reversed_axes())
+    }
+
+    pub fn init_with_order(vec: Vec<T>, shape: [usize; D], order: Order) -> Self {
+        match order {
+            Order::RowMajor => Array::init(vec, shape),
+            Order::ColumnMajor => Array::init_f(vec, shape),
+        }
+    }
+}
+
+impl<S, const D: usize> ArrayBase<S, D> {
+    // Whether the elements are laid out in row-major order without gaps
+    pub fn is_standard_layout(&self) -> bool {
+        self.contiguous_range().is_some()
+    }
+
+    // Whether the elements fill a block of memory without gaps, in any order of the axes
+    // and in either direction along each of them
+    pub fn is_contiguous(&self) -> bool {
+        self.memory_range().is_some()
+    }
+
+    // Whether distinct indices are at distinct positions of the data, which broadcast
+    // views and overlapping windows are not
+    pub(crate) fn is_unaliased(&self) -> bool {
+        is_unaliased(&self.shape, &self.strides)
+    }
+
+    // The block of the data holding the elements when they are contiguous. Elements filling
+    // a block without gaps never share a position.
+    pub(crate) fn memory_range(&self) -> Option<Range<usize>> {
+        if self.shape.contains(&0) {
+            return Some(0..0);
+        }
+
+        let mut axes: Vec<(usize, usize)> = (0..D)
+            .filter(|&axis| self.shape[axis] != 1)
+            .map(|axis| (self.shape[axis], self.strides[axis].unsigned_abs()))
+            .collect();
+        axes.sort_by_key(|&(_, stride)| stride);
+
+        let mut expected_stride = 1;
+        for (len, stride) in axes {
+            if stride != expected_stride {
+                return None;
+            }
+
+            expected_stride *= len;
+        }
+
+        // Axes with negative strides start at the end of the block
+        let start = (0..D)
+            .filter(|&axis| self.strides[axis] < 0)
+            .fold(self.offset as isize, |start, axis| {
+                start + (self.shape[axis] - 1) as isize * self.strides[axis]
+            }) as usize;
+
+        Some(start..start + expected_stride)
+    }
+}
+
+impl<T, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    // The elements in row-major order, if they are stored that way
+    pub fn as_slice(&self) -> Option<&[T]> {
+        let range = self.contiguous_range()?;
+
+        Some(&self.data.as_slice()[range])
+    }
+
+    // The elements in the order they are stored in, if they fill a block of memory
+    pub fn as_slice_memory_order(&self) -> Option<&[T]> {
+        let range = self.memory_range()?;
+
+        Some(&self.data.as_slice()[range])
+    }
+}
+
+impl<T, S: StorageMut<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn as_slice_mut(&mut self) -> Option<&mut [T]> {
+        let range = self.contiguous_range()?;
+
+        Some(&mut self.data.as_mut_slice()[range])
+    }
+
+    pub fn as_slice_memory_order_mut(&mut self) -> Option<&mut [T]> {
+        let range = self.memory_range()?;
+
+        Some(&mut self.data.as_mut_slice()[range])
+    }
+}
+
+impl<T: Clone, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    // Borrows the elements when they are already in standard layout and copies them into
+    // it otherwise
+    pub fn as_standard_layout(&self) -> CowArray<'_, T, D> {
+        if self.is_standard_layout() {
+            self.view().into()
+        } else {
+            self.to_owned().into()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s;
+
+    #[test]
+    fn column_major() {
+        // 1 3 5
+        // 2 4 6
+        let array = Array::init_f(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        assert_eq!(array, Array::init(vec![1, 3, 5, 2, 4, 6], [2, 3]));
+        assert_eq!(array.strides(), &[1, 2]);
+        assert_eq!(
+            Array::init_with_order(vec![1, 2, 3, 4, 5, 6], [2, 3], Order::ColumnMajor),
+            array
+        );
+        assert_eq!(
+            Array::try_init_f(vec![1, 2], [2, 3]),
+            Err(ShapeError::IncompatibleSize {
+                len: 2,
+                expected: 6
+            })
+        );
+
+        assert_eq!(
+            array.flatten_with_order(Order::ColumnMajor),
+            Array::arange(1..7)
+        );
+        assert_eq!(
+            array.ravel_with_order(Order::RowMajor),
+            Array::init(vec![1, 3, 5, 2, 4, 6], [6])
+        );
+
+        // Reading and writing in column-major order keeps the columns together
+        let reshaped = array
+            .reshape_with_order([3, 2], Order::ColumnMajor)
+            .unwrap();
+
+        assert_eq!(reshaped, Array::init_f(vec![1, 2, 3, 4, 5, 6], [3, 2]));
+        assert_eq!(reshaped.strides(), &[1, 3]);
+
+        let view = array
+            .view()
+            .into_shape_with_order([6], Order::ColumnMajor)
+            .unwrap();
+
+        assert_eq!(view, Array::arange(1..7));
+        assert_eq!(
+            array.view().into_shape_with_order([6], Order::RowMajor),
+            Err(ShapeError::IncompatibleLayout)
+        );
+    }
+
+    #[test]
+    fn layout_queries() {
+        let array = Array::from_shape_fn([3, 4, 2], |[i, j, k]| i * 8 + j * 2 + k);
+
+        assert!(array.is_standard_layout() && array.is_contiguous());
+        assert!(!array.t().is_standard_layout() && array.t().is_contiguous());
+        assert!(array.flip(1).is_contiguous());
+        assert!(array.slice(&s![1..2, .., ..]).is_standard_layout());
+        assert!(!array.slice(&s![.., 1..3, ..]).is_contiguous());
+        assert!(!array.slice(&s![.., .., ..;2]).is_contiguous());
+        assert!(array.slice(&s![.., 4.., ..]).is_standard_layout());
+
+        let rows = array.slice(&s![1.., .., ..]);
+        let standard = rows.as_standard_layout();
+
+        assert!(standard.is_view());
+
+        let transposed = array.t();
+        let standard = transposed.as_standard_layout();
+
+        assert!(standard.is_owned() && standard.is_standard_layout());
+        assert_eq!(standard, transposed);
+    }
+
+    #[test]
+    fn slices() {
+        let mut array = Array::from_shape_fn([2, 3], |[i, j]| i * 3 + j);
+
+        assert_eq!(array.as_slice(), Some(&[0, 1, 2, 3, 4, 5][..]));
+        assert_eq!(array.slice(&s![1.., ..]).as_slice(), Some(&[3, 4, 5][..]));
+        assert_eq!(array.slice(&s![.., 1..]).as_slice(), None);
+        assert_eq!(array.t().as_slice(), None);
+        assert_eq!(
+            array.t().as_slice_memory_order(),
+            Some(&[0, 1, 2, 3, 4, 5][..])
+        );
+        assert_eq!(
+            array.flip(1).as_slice_memory_order(),
+            Some(&[0, 1, 2, 3, 4, 5][..])
+        );
+        assert_eq!(
+            array.slice(&s![1.., ..]).flip(1).as_slice_memory_order(),
+            Some(&[3, 4, 5][..])
+        );
+        assert_eq!(array.slice(&s![.., 3..]).as_slice(), Some(&[][..]));
+
+        array.as_slice_mut().unwrap().sort_by(|a, b| b.cmp(a));
+
+        assert_eq!(array[[0, 0]], 5);
+
+        let mut transposed = array.view_mut().reversed_axes();
+
+        assert!(transposed.as_slice_mut().is_none());
+        transposed.as_slice_memory_order_mut().unwrap().fill(1);
+
+        assert_eq!(array.sum(), 6);
+    }
+}
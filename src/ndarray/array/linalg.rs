@@ -0,0 +1,464 @@
+use std::ops::{Add, Mul};
+
+use num_traits::Zero;
+
+use crate::{Array, ArrayBase, Storage, View};
+
+pub trait Dot<Rhs> {
+    type Output;
+
+    fn dot(&self, rhs: &Rhs) -> Self::Output;
+}
+
+impl<T, S1, S2> Dot<ArrayBase<S2, 2>> for ArrayBase<S1, 2>
+where
+    T: Clone + Zero + Add<Output = T> + Mul<Output = T>,
+    S1: Storage<Elem = T>,
+    S2: Storage<Elem = T>,
+{
+    type Output = Array<T, 2>;
+
+    fn dot(&self, rhs: &ArrayBase<S2, 2>) -> Self::Output {
+        if self.shape[1] != rhs.shape[0] {
+            panic!(
+                "Inner dimensions of matrices do not match: {:?} x {:?}",
+                self.shape, rhs.shape
+            );
+        }
+
+        #[cfg(feature = "blas")]
+        if let Some(product) = super::blas::try_gemm(self, rhs) {
+            return product;
+        }
+
+        #[cfg(feature = "half")]
+        if let Some(product) = super::half_precision::try_widened_gemm(self, rhs) {
+            return product;
+        }
+
+        super::gemm::gemm(self, rhs)
+    }
+}
+
+impl<T, S1, S2> Dot<ArrayBase<S2, 1>> for ArrayBase<S1, 1>
+where
+    T: Clone + Zero + Add<Output = T> + Mul<Output = T>,
+    S1: Storage<Elem = T>,
+    S2: Storage<Elem = T>,
+{
+    type Output = T;
+
+    fn dot(&self, rhs: &ArrayBase<S2, 1>) -> Self::Output {
+        if self.shape != rhs.shape {
+            panic!(
+                "Length of vectors do not match: {} != {}",
+                self.shape[0], rhs.shape[0]
+            );
+        }
+
+        self.flat()
+            .zip(rhs.flat())
+            .fold(T::zero(), |acc, (v1, v2)| acc + v1.clone() * v2.clone())
+    }
+}
+
+impl<T, S1, S2> Dot<ArrayBase<S2, 1>> for ArrayBase<S1, 2>
+where
+    T: Clone + Zero + Add<Output = T> + Mul<Output = T>,
+    S1: Storage<Elem = T>,
+    S2: Storage<Elem = T>,
+{
+    type Output = Array<T, 1>;
+
+    fn dot(&self, rhs: &ArrayBase<S2, 1>) -> Self::Output {
+        if self.shape[1] != rhs.shape[0] {
+            panic!(
+                "Inner dimensions of matrix and vector do not match: {:?} x {:?}",
+                self.shape, rhs.shape
+            );
+        }
+
+        let [m, k] = self.shape;
+
+        let lhs_data = self.data.as_slice();
+        let rhs_data = rhs.data.as_slice();
+
+        let vec = (0..m)
+            .map(|i| {
+                (0..k).fold(T::zero(), |acc, p| {
+                    acc + lhs_data[self.offset_of(&[i, p])].clone()
+                        * rhs_data[rhs.offset_of(&[p])].clone()
+                })
+            })
+            .collect();
+
+        Array::init(vec, [m])
+    }
+}
+
+impl<T, S1, S2> Dot<ArrayBase<S2, 2>> for ArrayBase<S1, 1>
+where
+    T: Clone + Zero + Add<Output = T> + Mul<Output = T>,
+    S1: Storage<Elem = T>,
+    S2: Storage<Elem = T>,
+{
+    type Output = Array<T, 1>;
+
+    fn dot(&self, rhs: &ArrayBase<S2, 2>) -> Self::Output {
+        if self.shape[0] != rhs.shape[0] {
+            panic!(
+                "Inner dimensions of vector and matrix do not match: {:?} x {:?}",
+                self.shape, rhs.shape
+            );
+        }
+
+        rhs.t().dot(self)
+    }
+}
+
+impl<T, S: Storage<Elem = T>> ArrayBase<S, 2>
+where
+    T: Clone + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    pub fn matmul<S2: Storage<Elem = T>>(&self, rhs: &ArrayBase<S2, 2>) -> Array<T, 2> {
+        self.dot(rhs)
+    }
+}
+
+impl<T, S: Storage<Elem = T>> ArrayBase<S, 2> {
+    pub fn diag(&self) -> View<'_, T, 1> {
+        self.diag_offset(0)
+    }
+
+    pub fn diag_offset(&self, k: isize) -> View<'_, T, 1> {
+        let [rows, cols] = self.shape;
+
+        // Diagonals above the main one start in the first row, the ones below it in the
+        // first column.
+        let (row, col) = if k >= 0 {
+            (0, k as usize)
+        } else {
+            (k.unsigned_abs(), 0)
+        };
+
+        let len = rows.saturating_sub(row).min(cols.saturating_sub(col));
+        let offset = if len > 0 {
+            self.offset_of(&[row, col])
+        } else {
+            self.offset
+        };
+
+        ArrayBase {
+            data: self.data.as_slice(),
+            shape: [len],
+            strides: [self.strides[0] + self.strides[1]],
+            offset,
+        }
+    }
+}
+
+impl<T: Clone + Zero, S: Storage<Elem = T>> ArrayBase<S, 2> {
+    pub fn triu(&self, k: isize) -> Array<T, 2> {
+        self.mask_triangle(|row, col| col - row >= k)
+    }
+
+    pub fn tril(&self, k: isize) -> Array<T, 2> {
+        self.mask_triangle(|row, col| col - row <= k)
+    }
+
+    fn mask_triangle<F: Fn(isize, isize) -> bool>(&self, keep: F) -> Array<T, 2> {
+        let vec = self
+            .indexed_iter()
+            .map(|([row, col], val)| {
+                if keep(row as isize, col as isize) {
+                    val.clone()
+                } else {
+                    T::zero()
+                }
+            })
+            .collect();
+
+        Array::init(vec, self.shape)
+    }
+}
+
+impl<T: Clone + Zero> Array<T, 2> {
+    pub fn from_diag<S: Storage<Elem = T>>(diag: &ArrayBase<S, 1>) -> Self {
+        let len = diag.shape[0];
+
+        let mut array = Array::zeros([len, len]);
+        for (idx, val) in diag.flat().enumerate() {
+            array.data[idx * len + idx] = val.clone();
+        }
+
+        array
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matmul() {
+        // 1 2 3     7  8      58  64
+        // 4 5 6  x  9  10  =  139 154
+        //           11 12
+        let lhs = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+        let rhs = Array::init(vec![7, 8, 9, 10, 11, 12], [3, 2]);
+
+        let product = lhs.matmul(&rhs);
+
+        assert_eq!(product.shape(), &[2, 2]);
+        assert_eq!(
+            product.flat().copied().collect::<Vec<i32>>(),
+            vec![58, 64, 139, 154]
+        );
+    }
+
+    #[test]
+    fn matmul_transposed() {
+        // 1 2 3     1 4      14 32
+        // 4 5 6  x  2 5   =  32 77
+        //           3 6
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        let product = array.dot(&array.t());
+
+        assert_eq!(
+            product.flat().copied().collect::<Vec<i32>>(),
+            vec![14, 32, 32, 77]
+        );
+    }
+
+    #[test]
+    fn matmul_strided() {
+        // 1 2 3
+        // 4 5 6
+        // 7 8 9
+        let array = Array::init((1..10).collect(), [3, 3]);
+
+        // 6 5     1 4     16 49
+        // 9 8  x  2 5  =  25 76
+        let rows = array.slice(&[1..3, 0..3]);
+        let flipped = rows.flip(1);
+        let lhs = flipped.slice(&[0..2, 0..2]);
+
+        let transposed = array.t();
+        let rhs = transposed.slice(&[0..2, 0..2]);
+
+        let product = lhs.matmul(&rhs);
+
+        assert_eq!(
+            product.flat().copied().collect::<Vec<i32>>(),
+            vec![16, 49, 25, 76]
+        );
+    }
+
+    #[test]
+    fn dot_vectors() {
+        let lhs = Array::init(vec![1, 2, 3], [3]);
+        let rhs = Array::init(vec![4, 5, 6], [3]);
+
+        assert_eq!(lhs.dot(&rhs), 32);
+        assert_eq!(lhs.flip(0).dot(&rhs), 28);
+    }
+
+    #[test]
+    fn dot_matrix_vector() {
+        // 1 2 3     1     14
+        // 4 5 6  x  2  =  32
+        //           3
+        let matrix = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+        let vector = Array::init(vec![1, 2, 3], [3]);
+
+        let product = matrix.dot(&vector);
+
+        assert_eq!(product.shape(), &[2]);
+        assert_eq!(product.flat().copied().collect::<Vec<i32>>(), vec![14, 32]);
+    }
+
+    #[test]
+    fn dot_vector_matrix() {
+        //          1 2 3
+        // 1 2  x   4 5 6  =  9 12 15
+        let vector = Array::init(vec![1, 2], [2]);
+        let matrix = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        assert_eq!(
+            vector.dot(&matrix).flat().copied().collect::<Vec<i32>>(),
+            vec![9, 12, 15]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn dot_length_mismatch() {
+        let lhs = Array::init(vec![1, 2, 3], [3]);
+        let rhs = Array::init(vec![1, 2], [2]);
+
+        lhs.dot(&rhs);
+    }
+
+    #[test]
+    #[should_panic]
+    fn matmul_shape_mismatch() {
+        let lhs = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+        let rhs = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        lhs.matmul(&rhs);
+    }
+
+    #[test]
+    fn diag() {
+        // 1 2 3
+        // 4 5 6
+        // 7 8 9
+        let array = Array::init((1..10).collect(), [3, 3]);
+
+        let diag = array.diag();
+
+        assert_eq!(diag.shape(), &[3]);
+        assert_eq!(diag.strides(), &[4]);
+        assert_eq!(diag.flat().copied().collect::<Vec<i32>>(), vec![1, 5, 9]);
+
+        let flipped = array.flip(1);
+        assert_eq!(
+            flipped.diag().flat().copied().collect::<Vec<i32>>(),
+            vec![3, 5, 7]
+        );
+
+        let transposed = array.t();
+        let rows = transposed.slice(&[0..3, 1..3]);
+        assert_eq!(
+            rows.diag().flat().copied().collect::<Vec<i32>>(),
+            vec![4, 8]
+        );
+    }
+
+    #[test]
+    fn diag_offset() {
+        // 1  2  3  4
+        // 5  6  7  8
+        // 9 10 11 12
+        let array = Array::init((1..13).collect(), [3, 4]);
+
+        let collect = |k| array.diag_offset(k).flat().copied().collect::<Vec<i32>>();
+
+        assert_eq!(collect(0), vec![1, 6, 11]);
+        assert_eq!(collect(1), vec![2, 7, 12]);
+        assert_eq!(collect(3), vec![4]);
+        assert_eq!(collect(-1), vec![5, 10]);
+        assert_eq!(collect(-2), vec![9]);
+        assert_eq!(collect(4), Vec::<i32>::new());
+        assert_eq!(collect(-5), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn from_diag() {
+        let diag = Array::init(vec![1, 2, 3], [3]);
+
+        let array = Array::from_diag(&diag);
+
+        assert_eq!(array.shape(), &[3, 3]);
+        assert_eq!(
+            array.flat().copied().collect::<Vec<i32>>(),
+            vec![1, 0, 0, 0, 2, 0, 0, 0, 3]
+        );
+        assert_eq!(
+            Array::from_diag(&diag.flip(0))
+                .diag()
+                .flat()
+                .copied()
+                .collect::<Vec<i32>>(),
+            vec![3, 2, 1]
+        );
+    }
+
+    #[test]
+    fn triu() {
+        // 1 2 3
+        // 4 5 6
+        // 7 8 9
+        let array = Array::init((1..10).collect(), [3, 3]);
+
+        assert_eq!(
+            array.triu(0).flat().copied().collect::<Vec<i32>>(),
+            vec![1, 2, 3, 0, 5, 6, 0, 0, 9]
+        );
+        assert_eq!(
+            array.triu(1).flat().copied().collect::<Vec<i32>>(),
+            vec![0, 2, 3, 0, 0, 6, 0, 0, 0]
+        );
+        assert_eq!(
+            array.triu(-1).flat().copied().collect::<Vec<i32>>(),
+            vec![1, 2, 3, 4, 5, 6, 0, 8, 9]
+        );
+    }
+
+    #[test]
+    fn tril() {
+        // 1 2 3 4
+        // 5 6 7 8
+        let array = Array::init((1..9).collect(), [2, 4]);
+
+        assert_eq!(
+            array.tril(0).flat().copied().collect::<Vec<i32>>(),
+            vec![1, 0, 0, 0, 5, 6, 0, 0]
+        );
+        assert_eq!(
+            array.tril(2).flat().copied().collect::<Vec<i32>>(),
+            vec![1, 2, 3, 0, 5, 6, 7, 8]
+        );
+
+        let transposed = array.t();
+        assert_eq!(
+            transposed.tril(-1).flat().copied().collect::<Vec<i32>>(),
+            vec![0, 0, 2, 0, 3, 7, 4, 8]
+        );
+    }
+
+    #[test]
+    fn matmul_borrowed_elements() {
+        // Numbers that borrow their label, which is kept from the left hand side
+        #[derive(Clone, Debug, PartialEq)]
+        struct Labeled<'a>(i32, &'a str);
+
+        impl<'a> Add for Labeled<'a> {
+            type Output = Labeled<'a>;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                Labeled(
+                    self.0 + rhs.0,
+                    if self.1.is_empty() { rhs.1 } else { self.1 },
+                )
+            }
+        }
+
+        impl<'a> Mul for Labeled<'a> {
+            type Output = Labeled<'a>;
+
+            fn mul(self, rhs: Self) -> Self::Output {
+                Labeled(self.0 * rhs.0, self.1)
+            }
+        }
+
+        impl Zero for Labeled<'_> {
+            fn zero() -> Self {
+                Labeled(0, "")
+            }
+
+            fn is_zero(&self) -> bool {
+                self.0 == 0
+            }
+        }
+
+        let label = String::from("x");
+        let lhs = Array::from_shape_fn([2, 2], |[i, j]| Labeled((i * 2 + j) as i32, &label));
+
+        assert_eq!(
+            lhs.matmul(&lhs).map(|val| val.0),
+            Array::init(vec![2, 3, 6, 11], [2, 2])
+        );
+    }
+}
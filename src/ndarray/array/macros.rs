@@ -0,0 +1,151 @@
+use crate::Array;
+
+#[macro_export]
+macro_rules! array {
+    ($([$([$($x:expr),* $(,)?]),+ $(,)?]),+ $(,)?) => {
+        $crate::Array::<_, 3>::from([$([$([$($x),*]),+]),+])
+    };
+    ($([$($x:expr),* $(,)?]),+ $(,)?) => {
+        $crate::Array::<_, 2>::from([$([$($x),*]),+])
+    };
+    ($($x:expr),* $(,)?) => {
+        $crate::Array::<_, 1>::from([$($x),*])
+    };
+}
+
+#[macro_export]
+macro_rules! s {
+    (@slice $slice:expr) => {
+        $crate::Slice::from($slice)
+    };
+    (@slice $slice:expr ; $step:expr) => {
+        $crate::Slice::from($slice).step_by($step)
+    };
+    ($($slice:expr $(; $step:expr)?),+ $(,)?) => {
+        [$($crate::s!(@slice $slice $(; $step)?)),+]
+    };
+}
+
+impl<T, const N: usize> From<[T; N]> for Array<T, 1> {
+    fn from(array: [T; N]) -> Self {
+        Array::init(IntoIterator::into_iter(array).collect(), [N])
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<[[T; C]; R]> for Array<T, 2> {
+    fn from(array: [[T; C]; R]) -> Self {
+        let vec = IntoIterator::into_iter(array)
+            .flat_map(IntoIterator::into_iter)
+            .collect();
+
+        Array::init(vec, [R, C])
+    }
+}
+
+impl<T, const P: usize, const R: usize, const C: usize> From<[[[T; C]; R]; P]> for Array<T, 3> {
+    fn from(array: [[[T; C]; R]; P]) -> Self {
+        let vec = IntoIterator::into_iter(array)
+            .flat_map(IntoIterator::into_iter)
+            .flat_map(IntoIterator::into_iter)
+            .collect();
+
+        Array::init(vec, [P, R, C])
+    }
+}
+
+#[macro_export]
+macro_rules! assert_allclose {
+    ($lhs:expr, $rhs:expr $(,)?) => {
+        $crate::assert_allclose!($lhs, $rhs, 1e-5, 1e-8)
+    };
+    ($lhs:expr, $rhs:expr, $rtol:expr, $atol:expr $(,)?) => {
+        $lhs.assert_allclose(&$rhs, $rtol, $atol)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Array;
+
+    #[test]
+    fn array_1d() {
+        let array = crate::array![1, 2, 3];
+
+        assert_eq!(array.shape(), &[3]);
+        assert_eq!(array.flat().copied().collect::<Vec<i32>>(), vec![1, 2, 3]);
+
+        let empty: Array<f64, 1> = crate::array![];
+        assert_eq!(empty.shape(), &[0]);
+    }
+
+    #[test]
+    fn array_2d() {
+        let array = crate::array![[1, 2, 3], [4, 5, 6],];
+
+        assert_eq!(array.shape(), &[2, 3]);
+        assert_eq!(array[[1, 0]], 4);
+        assert_eq!(
+            array.flat().copied().collect::<Vec<i32>>(),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+    }
+
+    #[test]
+    fn array_3d() {
+        let array = crate::array![[[1, 2], [3, 4]], [[5, 6], [7, 8]], [[9, 10], [11, 12]]];
+
+        assert_eq!(array.shape(), &[3, 2, 2]);
+        assert_eq!(array[[2, 0, 1]], 10);
+    }
+
+    #[test]
+    fn array_of_expressions() {
+        let x = 2.0;
+
+        let array = crate::array![[x, x * x], [-x, 1.0 / x]];
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<f64>>(),
+            vec![2.0, 4.0, -2.0, 0.5]
+        );
+    }
+
+    #[test]
+    fn from_nested_array() {
+        let array = Array::from([[String::from("a")], [String::from("b")]]);
+
+        assert_eq!(array.shape(), &[2, 1]);
+        assert_eq!(array[[1, 0]], "b");
+    }
+
+    #[test]
+    fn s() {
+        // 0  1  2  3  4
+        // 5  6  7  8  9
+        // 10 11 12 13 14
+        // 15 16 17 18 19
+        let array = Array::init((0..20).collect(), [4, 5]);
+
+        let view = array.slice(&crate::s![1..3, ..]);
+        assert_eq!(view.shape(), &[2, 5]);
+        assert_eq!(view[[0, 0]], 5);
+
+        let view = array.slice(&crate::s![..;2, 1..=3]);
+        assert_eq!(
+            view.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 2, 3, 11, 12, 13]
+        );
+
+        let view = array.slice(&crate::s![2.., ..;3]);
+        assert_eq!(
+            view.flat().copied().collect::<Vec<usize>>(),
+            vec![10, 13, 15, 18]
+        );
+
+        let view = array.slice(&crate::s![crate::Slice::last(1), ..;2]);
+        assert_eq!(
+            view.flat().copied().collect::<Vec<usize>>(),
+            vec![15, 17, 19]
+        );
+    }
+}
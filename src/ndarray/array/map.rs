@@ -0,0 +1,206 @@
+use std::convert::TryFrom;
+
+use num_traits::AsPrimitive;
+
+use crate::{Array, ArrayBase, Storage, StorageMut};
+
+impl<T, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Array<U, D> {
+        let data = self.data.as_slice();
+
+        // Mapping the backing buffer keeps the layout of the array intact, which is only
+        // possible when the elements fill the whole buffer. Views of a part of it would map
+        // elements outside the array, and broadcast or overlapping views would give the
+        // result elements that alias each other.
+        if self.memory_range() == Some(0..data.len()) {
+            ArrayBase {
+                data: data.iter().map(f).collect(),
+                shape: self.shape,
+                strides: self.strides,
+                offset: self.offset,
+            }
+        } else {
+            Array::init(self.flat().map(f).collect(), self.shape)
+        }
+    }
+}
+
+impl<T: Clone, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn mapv<U, F: FnMut(T) -> U>(&self, mut f: F) -> Array<U, D> {
+        self.map(|val| f(val.clone()))
+    }
+
+    pub fn astype<U: From<T>>(&self) -> Array<U, D> {
+        self.mapv(U::from)
+    }
+
+    // Stops at the first element that does not fit in `U`
+    pub fn try_astype<U: TryFrom<T>>(&self) -> Result<Array<U, D>, U::Error> {
+        let vec = self
+            .flat()
+            .cloned()
+            .map(U::try_from)
+            .collect::<Result<Vec<U>, U::Error>>()?;
+
+        Ok(Array::init(vec, self.shape))
+    }
+
+    // Converts like `as`: floats saturate at the bounds of integers, integers wrap around
+    pub fn astype_lossy<U: Copy + 'static>(&self) -> Array<U, D>
+    where
+        T: AsPrimitive<U>,
+    {
+        self.mapv(T::as_)
+    }
+}
+
+impl<T, S: StorageMut<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn for_each_mut<F: FnMut(&mut T)>(&mut self, f: F) {
+        match self.contiguous_range() {
+            Some(range) => self.data.as_mut_slice()[range].iter_mut().for_each(f),
+            None => self.flat_mut().for_each(f),
+        }
+    }
+
+    pub fn map_inplace<F: FnMut(&T) -> T>(&mut self, mut f: F) {
+        self.for_each_mut(|val| *val = f(val))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Array, View};
+
+    #[test]
+    fn map() {
+        // 2-D array:
+        // 1 2 3
+        // 4 5 6
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        let mapped = array.map(|val| val.to_string());
+
+        assert_eq!(mapped.shape(), &[2, 3]);
+        assert_eq!(mapped[[1, 2]], "6");
+    }
+
+    #[test]
+    fn map_preserves_layout() {
+        // 2-D array:
+        // 1 2 3
+        // 4 5 6
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]).transpose();
+
+        let mapped = array.mapv(|val| val * 10);
+
+        assert_eq!(mapped.strides(), array.strides());
+        assert_eq!(
+            mapped.flat().copied().collect::<Vec<usize>>(),
+            vec![10, 40, 20, 50, 30, 60]
+        );
+    }
+
+    #[test]
+    fn astype() {
+        let pixels = Array::init(vec![0u8, 128, 255, 64], [2, 2]).transpose();
+
+        let floats = pixels.astype::<f32>();
+
+        assert_eq!(floats, Array::init(vec![0.0, 255.0, 128.0, 64.0], [2, 2]));
+        assert_eq!(floats.strides(), pixels.strides());
+        assert!(Array::init(vec![1i32, -1], [2]).try_astype::<u8>().is_err());
+        assert_eq!(
+            Array::init(vec![1i32, 200], [2]).try_astype::<u8>(),
+            Ok(Array::init(vec![1u8, 200], [2]))
+        );
+        assert_eq!(
+            Array::init(vec![-1.5f32, 300.0, 7.9], [3]).astype_lossy::<u8>(),
+            Array::init(vec![0, 255, 7], [3])
+        );
+    }
+
+    #[test]
+    fn map_broadcast() {
+        let array = Array::init(vec![1, 2, 3], [1, 3]);
+
+        let broadcast = array.broadcast_to([2, 3]).unwrap();
+        let mut mapped = broadcast.mapv(|val| val * 10);
+
+        mapped[[0, 0]] = 0;
+
+        assert_eq!(
+            mapped.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 20, 30, 10, 20, 30]
+        );
+    }
+
+    #[test]
+    fn map_overlapping() {
+        // 1 2
+        // 2 3
+        let view = View::from_slice_with_strides(&[1, 2, 3], [2, 2], [1, 1]).unwrap();
+        let mut mapped = view.mapv(|val| val * 10);
+
+        assert_eq!(mapped.strides(), &[2, 1]);
+
+        mapped[[0, 1]] = 0;
+
+        assert_eq!(mapped, Array::init(vec![10, 0, 20, 30], [2, 2]));
+    }
+
+    #[test]
+    fn map_slice() {
+        // 2-D array:
+        // 1 2 3
+        // 4 5 6
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        let mapped = array.slice(&[0..2, 1..3]).mapv(|val| val * 10);
+
+        assert_eq!(mapped.strides(), &[2, 1]);
+        assert_eq!(
+            mapped.flat().copied().collect::<Vec<usize>>(),
+            vec![20, 30, 50, 60]
+        );
+    }
+
+    #[test]
+    fn for_each_mut() {
+        let mut array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        let mut count = 0;
+        array.for_each_mut(|val| {
+            count += 1;
+            *val *= count
+        });
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 4, 9, 16, 25, 36]
+        );
+    }
+
+    #[test]
+    fn map_inplace_strided() {
+        // 2-D array:
+        // 1 2 3
+        // 4 5 6
+        let mut array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3])
+            .transpose()
+            .slice_move(&[1..3, 0..2]);
+
+        // 2 5
+        // 3 6
+        let mut visited = vec![];
+        array.map_inplace(|val| {
+            visited.push(*val);
+            val * 10
+        });
+
+        assert_eq!(visited, vec![2, 5, 3, 6]);
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![20, 50, 30, 60]
+        );
+    }
+}
@@ -0,0 +1,291 @@
+use std::error::Error;
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io;
+use std::marker::PhantomData;
+use std::mem::size_of;
+use std::path::Path;
+
+use bytemuck::Pod;
+use memmap2::{Mmap, MmapMut, MmapOptions};
+
+use super::{checked_len, default_strides};
+use crate::{ArrayBase, CastError, ShapeError, Storage, StorageMut};
+
+#[derive(Debug)]
+pub enum MmapError {
+    Io(io::Error),
+    Cast(CastError),
+}
+
+impl fmt::Display for MmapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MmapError::Io(error) => write!(f, "I/O error: {}", error),
+            MmapError::Cast(error) => error.fmt(f),
+        }
+    }
+}
+
+impl Error for MmapError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            MmapError::Io(error) => Some(error),
+            MmapError::Cast(error) => Some(error),
+        }
+    }
+}
+
+impl From<io::Error> for MmapError {
+    fn from(error: io::Error) -> Self {
+        MmapError::Io(error)
+    }
+}
+
+impl From<CastError> for MmapError {
+    fn from(error: CastError) -> Self {
+        MmapError::Cast(error)
+    }
+}
+
+impl From<ShapeError> for MmapError {
+    fn from(error: ShapeError) -> Self {
+        MmapError::Cast(CastError::Shape(error))
+    }
+}
+
+// The bytes of a mapped file, which are checked to be aligned for `T` when the file is
+// opened, so the casts of `as_slice` can not fail
+pub struct MmapStorage<T> {
+    map: Mmap,
+    marker: PhantomData<T>,
+}
+
+impl<T: Pod> Storage for MmapStorage<T> {
+    type Elem = T;
+
+    fn as_slice(&self) -> &[T] {
+        bytemuck::cast_slice(&self.map)
+    }
+}
+
+pub struct MmapStorageMut<T> {
+    map: MmapMut,
+    marker: PhantomData<T>,
+}
+
+impl<T: Pod> Storage for MmapStorageMut<T> {
+    type Elem = T;
+
+    fn as_slice(&self) -> &[T] {
+        bytemuck::cast_slice(&self.map)
+    }
+}
+
+impl<T: Pod> StorageMut for MmapStorageMut<T> {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        bytemuck::cast_slice_mut(&mut self.map)
+    }
+}
+
+pub type MmapArray<T, const D: usize> = ArrayBase<MmapStorage<T>, D>;
+
+pub type MmapArrayMut<T, const D: usize> = ArrayBase<MmapStorageMut<T>, D>;
+
+// Length in bytes of `shape` elements of `T`
+fn byte_len<T, const D: usize>(shape: &[usize; D]) -> Result<usize, ShapeError> {
+    checked_len(shape)?
+        .checked_mul(size_of::<T>())
+        .ok_or(ShapeError::Overflow)
+}
+
+// The file has to hold exactly the elements of `shape` in row-major order from `offset`
+// on, and `offset` has to keep them aligned, since the pages of the map are.
+fn check_map<T: Pod, const D: usize>(bytes: &[u8], shape: &[usize; D]) -> Result<(), MmapError> {
+    bytemuck::try_cast_slice::<u8, T>(bytes).map_err(CastError::Pod)?;
+
+    let expected = checked_len(shape)?;
+    if bytes.len() != expected * size_of::<T>() {
+        return Err(ShapeError::IncompatibleSize {
+            len: bytes.len() / size_of::<T>(),
+            expected,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+fn map_len(file: &File, offset: u64) -> Result<usize, MmapError> {
+    let len = file.metadata()?.len().checked_sub(offset).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "Offset is past the end of the file",
+        )
+    })?;
+
+    Ok(len as usize)
+}
+
+impl<T: Pod, const D: usize> MmapArray<T, D> {
+    /// # Safety
+    ///
+    /// The file may not be modified or truncated, by this process or another one, while the
+    /// array maps it.
+    pub unsafe fn open<P: AsRef<Path>>(path: P, shape: [usize; D]) -> Result<Self, MmapError> {
+        Self::open_at(path, 0, shape)
+    }
+
+    // Skips the first `offset` bytes of the file, such as a header
+    /// # Safety
+    ///
+    /// The file may not be modified or truncated, by this process or another one, while the
+    /// array maps it.
+    pub unsafe fn open_at<P: AsRef<Path>>(
+        path: P,
+        offset: u64,
+        shape: [usize; D],
+    ) -> Result<Self, MmapError> {
+        let file = File::open(path)?;
+        let len = map_len(&file, offset)?;
+
+        // Safety: the caller keeps the file unchanged while it is mapped
+        let map = unsafe { MmapOptions::new().offset(offset).len(len).map(&file)? };
+
+        check_map::<T, D>(&map, &shape)?;
+
+        Ok(ArrayBase {
+            data: MmapStorage {
+                map,
+                marker: PhantomData,
+            },
+            shape,
+            strides: default_strides(&shape),
+            offset: 0,
+        })
+    }
+}
+
+impl<T: Pod, const D: usize> MmapArrayMut<T, D> {
+    // Writes through the array end up in the file
+    /// # Safety
+    ///
+    /// The file may not be modified or truncated other than through the array, by this
+    /// process or another one, while the array maps it.
+    pub unsafe fn open<P: AsRef<Path>>(path: P, shape: [usize; D]) -> Result<Self, MmapError> {
+        Self::open_at(path, 0, shape)
+    }
+
+    /// # Safety
+    ///
+    /// The file may not be modified or truncated other than through the array, by this
+    /// process or another one, while the array maps it.
+    pub unsafe fn open_at<P: AsRef<Path>>(
+        path: P,
+        offset: u64,
+        shape: [usize; D],
+    ) -> Result<Self, MmapError> {
+        let file = OpenOptions::new().read(true).write(true).open(path)?;
+        let len = map_len(&file, offset)?;
+
+        // Safety: the caller keeps the file from being changed except through the map
+        let map = unsafe { MmapOptions::new().offset(offset).len(len).map_mut(&file)? };
+
+        check_map::<T, D>(&map, &shape)?;
+
+        Ok(ArrayBase {
+            data: MmapStorageMut {
+                map,
+                marker: PhantomData,
+            },
+            shape,
+            strides: default_strides(&shape),
+            offset: 0,
+        })
+    }
+
+    // Creates or truncates the file at `path` to hold `shape` zeroed elements
+    /// # Safety
+    ///
+    /// The file may not be modified or truncated other than through the array, by this
+    /// process or another one, while the array maps it.
+    pub unsafe fn create<P: AsRef<Path>>(path: P, shape: [usize; D]) -> Result<Self, MmapError> {
+        let path = path.as_ref();
+        let len = byte_len::<T, D>(&shape)?;
+
+        File::create(path)?.set_len(len as u64)?;
+
+        Self::open(path, shape)
+    }
+
+    // Writes the changes made through the array back to the file, which otherwise happens
+    // at some point after the array is dropped
+    pub fn flush(&self) -> Result<(), MmapError> {
+        Ok(self.data.map.flush()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{s, Array};
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("nd_array_{}_{}.bin", name, std::process::id()))
+    }
+
+    #[test]
+    fn read_only() {
+        let path = temp_path("mmap");
+        let array = Array::from_shape_fn([4, 5], |[i, j]| (i * 5 + j) as f64);
+
+        let mut bytes = vec![0u8; 8];
+        bytes.extend_from_slice(array.as_bytes().unwrap());
+        std::fs::write(&path, &bytes).unwrap();
+
+        let mapped = unsafe { MmapArray::<f64, 2>::open_at(&path, 8, [4, 5]) }.unwrap();
+
+        assert_eq!(mapped, array);
+        assert_eq!(mapped.slice(&s![1..3, ..;2]), array.slice(&s![1..3, ..;2]));
+        assert_eq!(mapped.t().flat().copied().sum::<f64>(), 190.0);
+
+        assert!(matches!(
+            unsafe { MmapArray::<f64, 2>::open_at(&path, 8, [4, 4]) },
+            Err(MmapError::Cast(CastError::Shape(
+                ShapeError::IncompatibleSize { .. }
+            )))
+        ));
+        assert!(matches!(
+            unsafe { MmapArray::<f64, 1>::open_at(&path, 4, [20]) },
+            Err(MmapError::Cast(CastError::Pod(_)))
+        ));
+        assert!(matches!(
+            unsafe { MmapArray::<f64, 1>::open_at(&path, 200, [0]) },
+            Err(MmapError::Io(_))
+        ));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn read_write() {
+        let path = temp_path("mmap_mut");
+
+        {
+            let mut mapped = unsafe { MmapArrayMut::<i32, 2>::create(&path, [3, 4]) }.unwrap();
+
+            assert_eq!(mapped, Array::<i32, 2>::zeros([3, 4]));
+
+            mapped.slice_mut(&s![1.., ..]).map_inplace(|_| 7);
+            mapped[[0, 0]] = 1;
+            mapped.flush().unwrap();
+        }
+
+        let mapped = unsafe { MmapArray::<i32, 1>::open(&path, [12]) }.unwrap();
+
+        assert_eq!(mapped.flat().sum::<i32>(), 57);
+        assert_eq!(mapped[[0]], 1);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
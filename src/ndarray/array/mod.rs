@@ -1,84 +1,342 @@
 mod access;
+#[cfg(feature = "allocator")]
+mod allocator;
+#[cfg(feature = "approx")]
+mod approx_eq;
+mod arc;
+#[cfg(feature = "arrow")]
+mod arrow_interop;
+#[cfg(feature = "blas")]
+mod blas;
+mod broadcast;
 mod calc;
+mod chunked;
+mod cmp;
+mod complex;
+mod cow;
+mod csv;
+#[cfg(feature = "dlpack")]
+mod dlpack;
+mod dynamic;
+mod error;
+mod expr;
+mod format;
+mod gemm;
+#[cfg(feature = "gpu")]
+mod gpu;
+#[cfg(feature = "half")]
+mod half_precision;
+#[cfg(feature = "hdf5")]
+mod hdf5;
+#[cfg(feature = "image")]
+mod image_interop;
 mod iter;
+mod layout;
+mod linalg;
+mod macros;
+mod map;
+#[cfg(feature = "mmap")]
+mod mmap;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_interop;
+#[cfg(feature = "ndarray")]
+mod ndarray_interop;
+mod npy;
+#[cfg(feature = "npz")]
+mod npz;
+#[cfg(feature = "numpy")]
+mod numpy_interop;
 mod ops;
+mod pad;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "bytemuck")]
+mod pod;
+#[cfg(feature = "rand")]
+mod random;
+mod raw;
+#[cfg(feature = "serde")]
+mod serialization;
+#[cfg(feature = "simd")]
+mod simd;
+mod slice;
+mod stacking;
+mod storage;
+#[cfg(feature = "safetensors")]
+mod tensors;
 mod transformation;
+#[cfg(any(
+    feature = "blas",
+    feature = "half",
+    feature = "rayon",
+    feature = "simd"
+))]
+mod type_id;
+mod windows;
+mod zip;
+
+use std::mem::{ManuallyDrop, MaybeUninit};
+use std::ops::Range;
+
+use num_traits::{Float, FromPrimitive, Num, One, Zero};
+
+use iter::Indices;
+
+#[cfg(feature = "allocator")]
+pub use allocator::ArrayIn;
+pub use arc::ArcArray;
+#[cfg(feature = "arrow")]
+pub use arrow_interop::{ArrowConversionError, ArrowElement};
+pub use broadcast::{broadcast_shape, meshgrid, meshgrid_nd};
+pub use chunked::{ChunkError, ChunkLoader, ChunkedArray, ChunkedFlat, ChunkedView, NpyChunks};
+pub use cow::CowArray;
+pub use csv::CsvError;
+#[cfg(feature = "dlpack")]
+pub use dlpack::{
+    DLDataType, DLDevice, DLManagedTensor, DLTensor, DlpackElement, DlpackError, DL_CPU,
+};
+pub use dynamic::{ArrayD, DynArrayBase, ViewD, ViewMutD};
+pub use error::{BroadcastError, Error, IndexError, ShapeError, SliceError};
+pub use expr::{Expr, Expression};
+#[cfg(feature = "gpu")]
+pub use gpu::{GpuArray, GpuDevice, GpuElement, GpuError};
+#[cfg(feature = "hdf5")]
+pub use hdf5::{Hdf5Element, Hdf5Error, Hdf5Reader, Hdf5Writer};
+#[cfg(feature = "image")]
+pub use image_interop::{ChannelOrder, ImageConversionError};
+pub use layout::Order;
+pub use linalg::Dot;
+#[cfg(feature = "mmap")]
+pub use mmap::{MmapArray, MmapArrayMut, MmapError, MmapStorage, MmapStorageMut};
+#[cfg(feature = "nalgebra")]
+pub use nalgebra_interop::NalgebraShapeError;
+pub use npy::{NpyElement, NpyError};
+#[cfg(feature = "npz")]
+pub use npz::{NpzReader, NpzWriter};
+pub use ops::Scalar;
+pub use pad::PadMode;
+#[cfg(feature = "bytemuck")]
+pub use pod::CastError;
+#[cfg(feature = "simd")]
+pub use simd::SimdElement;
+pub use slice::{Slice, SliceBound};
+pub use storage::{Storage, StorageMut};
+#[cfg(feature = "safetensors")]
+pub use tensors::{SafeTensorsError, SafeTensorsReader, SafeTensorsWriter, TensorElement};
+pub use zip::{IntoProducer, Producer, ProducerMut, Zip};
+
+// The element at `indices` lives at `offset + Σ indices[axis] * strides[axis]` in `data`.
+// Negative strides walk an axis backwards and zero strides repeat a single element.
+#[derive(Clone, Copy)]
+pub struct ArrayBase<S, const D: usize> {
+    data: S,
+    shape: [usize; D],
+    strides: [isize; D],
+    offset: usize,
+}
+
+pub type Array<T, const D: usize> = ArrayBase<Vec<T>, D>;
+
+pub type View<'a, T, const D: usize> = ArrayBase<&'a [T], D>;
+
+pub type ViewMut<'a, T, const D: usize> = ArrayBase<&'a mut [T], D>;
+
+impl<T, const D: usize> Array<T, D> {
+    pub fn init(vec: Vec<T>, shape: [usize; D]) -> Self {
+        Self::try_init(vec, shape).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    pub fn try_init(vec: Vec<T>, shape: [usize; D]) -> Result<Self, ShapeError> {
+        let expected = checked_len(&shape)?;
+
+        if expected != vec.len() {
+            return Err(ShapeError::IncompatibleSize {
+                len: vec.len(),
+                expected,
+            });
+        }
+
+        Ok(Array {
+            data: vec,
+            shape,
+            strides: default_strides(&shape),
+            offset: 0,
+        })
+    }
 
-use std::borrow::Cow;
+    pub fn from_shape_fn<F: FnMut([usize; D]) -> T>(shape: [usize; D], mut f: F) -> Self {
+        let mut vec = Vec::with_capacity(len_of(&shape));
 
-use num_traits::{One, Zero};
+        let mut indices = Indices::init(shape);
+        while let Some(idx) = indices.next_front() {
+            vec.push(f(idx));
+        }
+
+        Array::init(vec, shape)
+    }
+
+    // An array whose elements are left uninitialized, for algorithms that write every one
+    // of them before reading any, which saves filling a large buffer first
+    pub fn uninit(shape: [usize; D]) -> Array<MaybeUninit<T>, D> {
+        let len = len_of(&shape);
 
-#[derive(Debug, Clone, Copy)]
-struct IdxMap {
-    m: isize,
-    b: isize,
+        let mut vec = Vec::with_capacity(len);
+        // Safety: `MaybeUninit` does not need to be initialized
+        unsafe { vec.set_len(len) };
+
+        Array::init(vec, shape)
+    }
 }
 
-impl IdxMap {
-    fn init() -> Self {
-        IdxMap { m: 1, b: 0 }
+impl<T, const D: usize> Array<MaybeUninit<T>, D> {
+    /// # Safety
+    ///
+    /// Every element of the array must have been initialized.
+    pub unsafe fn assume_init(self) -> Array<T, D> {
+        let mut vec = ManuallyDrop::new(self.data);
+
+        ArrayBase {
+            // Safety: `MaybeUninit<T>` has the layout of `T` and the caller guarantees the
+            // elements are initialized
+            data: Vec::from_raw_parts(vec.as_mut_ptr() as *mut T, vec.len(), vec.capacity()),
+            shape: self.shape,
+            strides: self.strides,
+            offset: self.offset,
+        }
     }
+}
 
-    fn map(&self, idx: usize) -> usize {
-        (self.m * (idx as isize) + self.b) as usize
+// Number of elements of `shape`. Like allocations in std, every element must be
+// addressable by an isize offset, which also bounds the strides of empty arrays.
+fn checked_len(shape: &[usize]) -> Result<usize, ShapeError> {
+    let non_empty_len = shape
+        .iter()
+        .filter(|len| **len != 0)
+        .try_fold(1usize, |acc, len| acc.checked_mul(*len));
+
+    match non_empty_len {
+        Some(len) if len <= isize::MAX as usize => Ok(if shape.contains(&0) { 0 } else { len }),
+        _ => Err(ShapeError::Overflow),
     }
+}
+
+fn len_of(shape: &[usize]) -> usize {
+    checked_len(shape).unwrap_or_else(|error| panic!("{}", error))
+}
 
-    fn append_b(&mut self, b: isize) {
-        self.b += self.m * b;
+fn default_strides<const D: usize>(shape: &[usize; D]) -> [isize; D] {
+    let mut strides = [0; D];
+    for axis in 0..D {
+        strides[axis] = shape[axis + 1..].iter().product::<usize>() as isize;
     }
+
+    strides
 }
 
-pub struct Array<'a, T: Clone, const D: usize> {
-    vec: Cow<'a, [T]>,
-    shape: [usize; D],
-    strides: [usize; D],
-    idx_maps: [IdxMap; D],
+impl<S, const D: usize> ArrayBase<S, D> {
+    pub fn shape(&self) -> &[usize; D] {
+        &self.shape
+    }
+
+    pub fn strides(&self) -> &[isize; D] {
+        &self.strides
+    }
+
+    fn is_in_bounds(&self, indices: &[usize; D]) -> bool {
+        indices
+            .iter()
+            .enumerate()
+            .all(|(axis, idx)| *idx < self.shape[axis])
+    }
+
+    fn offset_of(&self, indices: &[usize; D]) -> usize {
+        indices
+            .iter()
+            .enumerate()
+            .fold(self.offset as isize, |acc, (axis, axis_index)| {
+                acc + *axis_index as isize * self.strides[axis]
+            }) as usize
+    }
+
+    fn contiguous_range(&self) -> Option<Range<usize>> {
+        contiguous_range(&self.shape, &self.strides, self.offset)
+    }
 }
 
-impl<'a, T: Clone, const D: usize> Array<'a, T, D> {
-    pub fn init(vec: Vec<T>, shape: [usize; D]) -> Self {
-        let elem_count: usize = shape.iter().product();
+fn offset_at(indices: &[usize], strides: &[isize], offset: usize) -> usize {
+    indices
+        .iter()
+        .zip(strides)
+        .fold(offset as isize, |acc, (idx, stride)| {
+            acc + *idx as isize * stride
+        }) as usize
+}
+
+// The range of the data holding the elements when they are laid out in row-major order
+// without gaps
+fn contiguous_range(shape: &[usize], strides: &[isize], offset: usize) -> Option<Range<usize>> {
+    let len: usize = shape.iter().product();
 
-        if elem_count != vec.len() {
-            panic!(
-                "Number of elements in vec is not equal to dimension specification: {} != {}",
-                vec.len(),
-                elem_count
-            );
+    if len == 0 {
+        return Some(0..0);
+    }
+
+    let mut expected_stride = 1;
+    for axis in (0..shape.len()).rev() {
+        if shape[axis] != 1 && strides[axis] != expected_stride {
+            return None;
         }
 
-        let mut strides = [0; D];
-        for axis in 0..D {
-            strides[axis] = shape[axis + 1..].iter().fold(1, |acc, v| acc * v);
+        expected_stride *= shape[axis] as isize;
+    }
+
+    Some(offset..offset + len)
+}
+
+impl<T, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn view(&self) -> View<'_, T, D> {
+        ArrayBase {
+            data: self.data.as_slice(),
+            shape: self.shape,
+            strides: self.strides,
+            offset: self.offset,
         }
+    }
+}
 
-        Array {
-            vec: Cow::from(vec),
-            shape,
-            strides,
-            idx_maps: [IdxMap::init(); D],
+impl<T, S: StorageMut<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn view_mut(&mut self) -> ViewMut<'_, T, D> {
+        ArrayBase {
+            data: self.data.as_mut_slice(),
+            shape: self.shape,
+            strides: self.strides,
+            offset: self.offset,
         }
     }
+}
 
-    pub fn shape(&self) -> &[usize; D] {
-        &self.shape
+impl<T: Clone, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn to_owned(&self) -> Array<T, D> {
+        Array::init(self.flat().cloned().collect(), self.shape)
     }
+}
 
-    pub fn strides(&self) -> &[usize; D] {
-        &self.strides
+impl<T: Clone, const D: usize> Array<T, D> {
+    pub fn full(val: T, shape: [usize; D]) -> Self {
+        Array::init(vec![val; len_of(&shape)], shape)
     }
 
-    pub fn full(val: T, shape: [usize; D]) -> Array<'a, T, D> {
-        Array::init(vec![val; shape.iter().product()], shape)
+    pub fn from_elem(shape: [usize; D], elem: T) -> Self {
+        Array::full(elem, shape)
     }
 
-    pub fn full_like<'b, U: Clone>(val: T, array: &Array<'b, U, D>) -> Array<'a, T, D> {
-        Array::full(val, array.shape().clone())
+    pub fn full_like<S>(val: T, array: &ArrayBase<S, D>) -> Self {
+        Array::full(val, *array.shape())
     }
 }
 
-impl<'a, T: Clone> Array<'a, T, 1> {
-    pub fn arange<I: Iterator<Item = T>>(range: I) -> Array<'a, T, 1> {
+impl<T> Array<T, 1> {
+    pub fn arange<I: Iterator<Item = T>>(range: I) -> Self {
         let vec: Vec<T> = range.collect();
         let len = vec.len();
 
@@ -86,23 +344,78 @@ impl<'a, T: Clone> Array<'a, T, 1> {
     }
 }
 
-impl<'a, T: Clone + Zero, const D: usize> Array<'a, T, D> {
+impl<T: Copy + Num + FromPrimitive> Array<T, 1> {
+    pub fn linspace(start: T, end: T, n: usize) -> Self {
+        // With a single element there is no step, numpy returns just `start`
+        let step = if n > 1 {
+            (end - start) / T::from_usize(n - 1).unwrap()
+        } else {
+            T::zero()
+        };
+
+        Array::arange((0..n).map(|idx| {
+            if idx + 1 == n && n > 1 {
+                end
+            } else {
+                start + T::from_usize(idx).unwrap() * step
+            }
+        }))
+    }
+}
+
+impl<T: Float + FromPrimitive> Array<T, 1> {
+    pub fn range(start: T, end: T, step: T) -> Self {
+        if step.is_zero() {
+            panic!("Step of a range can not be zero");
+        }
+
+        let len = ((end - start) / step).ceil().to_usize().unwrap_or(0);
+
+        Array::arange((0..len).map(|idx| start + T::from_usize(idx).unwrap() * step))
+    }
+
+    pub fn logspace(base: T, start: T, end: T, n: usize) -> Self {
+        Array::linspace(start, end, n).mapv(|exp| base.powf(exp))
+    }
+}
+
+impl<T: Clone + Zero, const D: usize> Array<T, D> {
     pub fn zeros(shape: [usize; D]) -> Self {
-        Array::init(vec![T::zero(); shape.iter().product()], shape)
+        Array::init(vec![T::zero(); len_of(&shape)], shape)
     }
 
-    pub fn zeros_like<'b, U: Clone>(array: &Array<'b, U, D>) -> Array<'a, T, D> {
-        Array::zeros(array.shape().clone())
+    pub fn zeros_like<S>(array: &ArrayBase<S, D>) -> Self {
+        Array::zeros(*array.shape())
     }
 }
 
-impl<'a, T: Clone + One, const D: usize> Array<'a, T, D> {
+impl<T: Clone + One, const D: usize> Array<T, D> {
     pub fn ones(shape: [usize; D]) -> Self {
-        Array::init(vec![T::one(); shape.iter().product()], shape)
+        Array::init(vec![T::one(); len_of(&shape)], shape)
     }
 
-    pub fn ones_like<'b, U: Clone>(array: &Array<'b, U, D>) -> Array<'a, T, D> {
-        Array::ones(array.shape().clone())
+    pub fn ones_like<S>(array: &ArrayBase<S, D>) -> Self {
+        Array::ones(*array.shape())
+    }
+}
+
+impl<T: Clone + Zero + One, const D: usize> Array<T, D> {
+    pub fn identity(n: usize) -> Self {
+        let mut array = Array::zeros([n; D]);
+
+        // Elements with all indices equal are one stride sum apart
+        let step = array.strides.iter().sum::<isize>() as usize;
+        for idx in 0..n {
+            array.data[idx * step] = T::one();
+        }
+
+        array
+    }
+}
+
+impl<T: Clone + Zero + One> Array<T, 2> {
+    pub fn eye(n: usize) -> Self {
+        Array::identity(n)
     }
 }
 
@@ -120,6 +433,120 @@ mod tests {
         )
     }
 
+    #[test]
+    fn range() {
+        let array = Array::range(0.0, 1.0, 0.25);
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<f64>>(),
+            vec![0.0, 0.25, 0.5, 0.75]
+        );
+        assert_eq!(Array::range(0.0, 1.1, 0.5).shape(), &[3]);
+        assert_eq!(
+            Array::range(2.0, 0.0, -1.0)
+                .flat()
+                .copied()
+                .collect::<Vec<f32>>(),
+            vec![2.0, 1.0]
+        );
+        assert_eq!(Array::range(1.0, 0.0, 1.0).shape(), &[0]);
+    }
+
+    #[test]
+    fn try_init() {
+        let array = Array::try_init(vec![1, 2, 3, 4, 5, 6], [3, 2]).unwrap();
+
+        assert_eq!(array.shape(), &[3, 2]);
+        assert_eq!(array[[2, 0]], 5);
+        assert_eq!(
+            Array::try_init(vec![1, 2, 3, 4, 5], [3, 2]).err(),
+            Some(ShapeError::IncompatibleSize {
+                len: 5,
+                expected: 6
+            })
+        );
+    }
+
+    #[test]
+    fn checked_len() {
+        assert_eq!(super::checked_len(&[3, 4]), Ok(12));
+        assert_eq!(super::checked_len(&[0, isize::MAX as usize]), Ok(0));
+        assert_eq!(super::checked_len(&[]), Ok(1));
+        assert_eq!(
+            super::checked_len(&[usize::MAX, 2]),
+            Err(ShapeError::Overflow)
+        );
+        assert_eq!(
+            super::checked_len(&[isize::MAX as usize + 1, 0]),
+            Err(ShapeError::Overflow)
+        );
+    }
+
+    #[test]
+    fn try_init_overflow() {
+        assert_eq!(
+            Array::<u8, 2>::try_init(vec![], [usize::MAX, 2]).err(),
+            Some(ShapeError::Overflow)
+        );
+        assert_eq!(
+            Array::<u8, 2>::try_init(vec![], [0, usize::MAX]).err(),
+            Some(ShapeError::Overflow)
+        );
+        assert_eq!(
+            Array::<u8, 2>::try_init(vec![], [0, isize::MAX as usize])
+                .unwrap()
+                .strides(),
+            &[isize::MAX, 1]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Number of elements of the shape exceeds isize::MAX")]
+    fn zeros_overflow() {
+        Array::<u8, 2>::zeros([usize::MAX, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Number of elements does not match the shape: 5 != 6")]
+    fn init_size_mismatch() {
+        Array::init(vec![1, 2, 3, 4, 5], [3, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn range_zero_step() {
+        Array::range(0.0, 1.0, 0.0);
+    }
+
+    #[test]
+    fn linspace() {
+        let array = Array::linspace(0.0, 1.0, 5);
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<f64>>(),
+            vec![0.0, 0.25, 0.5, 0.75, 1.0]
+        );
+        assert_eq!(Array::linspace(0.0, 0.3, 4).flat().next_back(), Some(&0.3));
+        assert_eq!(
+            Array::linspace(3.0, 4.0, 1)
+                .flat()
+                .copied()
+                .collect::<Vec<f64>>(),
+            vec![3.0]
+        );
+        assert_eq!(Array::<f64, 1>::linspace(3.0, 4.0, 0).shape(), &[0]);
+    }
+
+    #[test]
+    fn logspace() {
+        let array = Array::logspace(10.0, 0.0, 3.0, 4);
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<f64>>(),
+            vec![1.0, 10.0, 100.0, 1000.0]
+        );
+    }
+
     #[test]
     fn zeros() {
         let array = Array::zeros([2, 4]);
@@ -132,7 +559,7 @@ mod tests {
 
     #[test]
     fn zeros_like() {
-        let array = Array::arange(0..8).reshape([2, 4]);
+        let array = Array::arange(0..8).reshape([2, 4]).unwrap();
 
         let zeros_like = Array::zeros_like(&array);
 
@@ -154,7 +581,7 @@ mod tests {
 
     #[test]
     fn ones_like() {
-        let array = Array::arange(0..8).reshape([2, 4]);
+        let array = Array::arange(0..8).reshape([2, 4]).unwrap();
 
         let ones_like = Array::ones_like(&array);
 
@@ -164,6 +591,29 @@ mod tests {
         )
     }
 
+    #[test]
+    fn eye() {
+        let array = Array::eye(3);
+
+        assert_eq!(array.shape(), &[3, 3]);
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 0, 0, 0, 1, 0, 0, 0, 1]
+        );
+        assert_eq!(Array::<f64, 2>::eye(0).shape(), &[0, 0]);
+    }
+
+    #[test]
+    fn identity() {
+        let array = Array::<usize, 3>::identity(2);
+
+        assert_eq!(array.shape(), &[2, 2, 2]);
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 0, 0, 0, 0, 0, 0, 1]
+        );
+    }
+
     #[test]
     fn full() {
         let array = Array::full(10, [2, 4]);
@@ -174,9 +624,41 @@ mod tests {
         )
     }
 
+    #[test]
+    fn from_shape_fn() {
+        let array = Array::from_shape_fn([2, 3], |[row, col]| 10 * row + col);
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 1, 2, 10, 11, 12]
+        );
+
+        let mut calls = 0;
+        let empty = Array::from_shape_fn([2, 0], |_| {
+            calls += 1;
+            0
+        });
+
+        assert_eq!(empty.shape(), &[2, 0]);
+        assert_eq!(calls, 0);
+    }
+
+    #[test]
+    fn from_elem() {
+        let array = Array::from_elem([2, 3], 'a');
+
+        assert_eq!(array.shape(), &[2, 3]);
+        assert!(array.flat().all(|val| *val == 'a'));
+
+        let empty = Array::from_elem([0, 3], 1);
+
+        assert_eq!(empty.shape(), &[0, 3]);
+        assert_eq!(empty.flat().len(), 0);
+    }
+
     #[test]
     fn full_like() {
-        let array = Array::arange(0..8).reshape([2, 4]);
+        let array = Array::arange(0..8).reshape([2, 4]).unwrap();
 
         let full_like = Array::full_like(10, &array);
 
@@ -185,4 +667,185 @@ mod tests {
             vec![10, 10, 10, 10, 10, 10, 10, 10]
         )
     }
+
+    #[test]
+    fn view() {
+        // 2-D array:
+        // 1 2 3
+        // 4 5 6
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        let view = array.view();
+
+        assert_eq!(view.shape(), &[2, 3]);
+        assert_eq!(
+            view.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 2, 3, 4, 5, 6]
+        )
+    }
+
+    #[test]
+    fn to_owned() {
+        fn center(array: &Array<usize, 2>) -> Array<usize, 2> {
+            array.slice(&[1..3, 1..3]).to_owned()
+        }
+
+        // 2-D array:
+        // 1   2  3  4
+        // 5   6  7  8
+        // 9  10 11 12
+        // 13 14 15 16
+        let array = Array::init((1..17).collect(), [4, 4]);
+
+        let center = center(&array);
+
+        assert_eq!(center.strides(), &[2, 1]);
+        assert_eq!(
+            center.flat().copied().collect::<Vec<usize>>(),
+            vec![6, 7, 10, 11]
+        )
+    }
+
+    #[test]
+    fn contiguous_range() {
+        // 2-D array:
+        // 1   2  3  4
+        // 5   6  7  8
+        // 9  10 11 12
+        // 13 14 15 16
+        let array = Array::init((1..17).collect(), [4, 4]);
+
+        assert_eq!(array.contiguous_range(), Some(0..16));
+        assert_eq!(array.slice(&[1..3, 0..4]).contiguous_range(), Some(4..12));
+        assert_eq!(array.slice(&[2..3, 1..3]).contiguous_range(), Some(9..11));
+        assert_eq!(array.slice(&[1..3, 1..3]).contiguous_range(), None);
+        assert_eq!(array.t().contiguous_range(), None);
+        assert_eq!(array.flip(1).contiguous_range(), None);
+    }
+
+    // Three decimal fixed point number, standing in for any numeric type outside of std
+    #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+    struct Fixed(i64);
+
+    impl std::ops::Add for Fixed {
+        type Output = Fixed;
+
+        fn add(self, rhs: Fixed) -> Fixed {
+            Fixed(self.0 + rhs.0)
+        }
+    }
+
+    impl std::ops::Sub for Fixed {
+        type Output = Fixed;
+
+        fn sub(self, rhs: Fixed) -> Fixed {
+            Fixed(self.0 - rhs.0)
+        }
+    }
+
+    impl std::ops::Mul for Fixed {
+        type Output = Fixed;
+
+        fn mul(self, rhs: Fixed) -> Fixed {
+            Fixed(self.0 * rhs.0 / 1000)
+        }
+    }
+
+    impl std::ops::Div for Fixed {
+        type Output = Fixed;
+
+        fn div(self, rhs: Fixed) -> Fixed {
+            Fixed(self.0 * 1000 / rhs.0)
+        }
+    }
+
+    impl std::ops::Rem for Fixed {
+        type Output = Fixed;
+
+        fn rem(self, rhs: Fixed) -> Fixed {
+            Fixed(self.0 % rhs.0)
+        }
+    }
+
+    impl Zero for Fixed {
+        fn zero() -> Self {
+            Fixed(0)
+        }
+
+        fn is_zero(&self) -> bool {
+            self.0 == 0
+        }
+    }
+
+    impl One for Fixed {
+        fn one() -> Self {
+            Fixed(1000)
+        }
+    }
+
+    impl Num for Fixed {
+        type FromStrRadixErr = std::num::ParseIntError;
+
+        fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+            i64::from_str_radix(str, radix).map(|val| Fixed(val * 1000))
+        }
+    }
+
+    impl FromPrimitive for Fixed {
+        fn from_i64(n: i64) -> Option<Self> {
+            n.checked_mul(1000).map(Fixed)
+        }
+
+        fn from_u64(n: u64) -> Option<Self> {
+            i64::from_u64(n).and_then(Fixed::from_i64)
+        }
+    }
+
+    impl crate::Scalar for Fixed {}
+
+    #[test]
+    fn user_defined_numbers() {
+        use crate::Dot;
+
+        let fixed = |vals: &[i64]| vals.iter().map(|&val| Fixed(val)).collect::<Vec<_>>();
+
+        let array = Array::linspace(Fixed(0), Fixed(1500), 4);
+
+        assert_eq!(array, Array::init(fixed(&[0, 500, 1000, 1500]), [4]));
+        assert_eq!(array.sum(), Fixed(3000));
+        assert_eq!(array.mean(), Fixed(750));
+        assert_eq!(array.dot(&Array::ones([4])), Fixed(3000));
+        assert_eq!(
+            &array * Fixed(2000) - Array::full(Fixed(500), [4]),
+            Array::init(fixed(&[-500, 500, 1500, 2500]), [4])
+        );
+
+        let matrix: Array<Fixed, 2> = Array::eye(2) + Array::zeros([2, 2]);
+
+        assert_eq!(matrix.dot(&matrix), matrix);
+        assert_eq!((&matrix + &matrix).prod(), Fixed(0));
+        assert_eq!(
+            matrix.sum_axis::<1>(0),
+            Array::init(fixed(&[1000, 1000]), [2])
+        );
+    }
+
+    #[test]
+    fn uninit() {
+        let mut array = Array::<String, 2>::uninit([2, 3]);
+
+        // Fills the elements through a transposed view
+        let mut transposed = array.view_mut().reversed_axes();
+        for j in 0..3 {
+            for i in 0..2 {
+                transposed[[j, i]].write(format!("{}{}", i, j));
+            }
+        }
+
+        let array = unsafe { array.assume_init() };
+
+        assert_eq!(array[[1, 2]], "12");
+        assert_eq!(array.t()[[0, 1]], "10");
+        assert_eq!(Array::<f64, 3>::uninit([2, 0, 4]).shape(), &[2, 0, 4]);
+    }
 }
@@ -0,0 +1,259 @@
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
+use nalgebra::{Const, DMatrix, DMatrixView, DVector, DVectorView, Dyn, SMatrix, SVector};
+
+use crate::{Array, ArrayBase, ShapeError, View};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NalgebraShapeError {
+    pub expected: Vec<usize>,
+    pub found: Vec<usize>,
+}
+
+impl fmt::Display for NalgebraShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Shape of the array does not match the matrix: {:?} != {:?}",
+            self.found, self.expected
+        )
+    }
+}
+
+impl Error for NalgebraShapeError {}
+
+impl<T: nalgebra::Scalar> From<Array<T, 2>> for DMatrix<T> {
+    // nalgebra stores matrices in column-major order, so arrays that are F contiguous
+    // over their whole vector are moved without copying and the others are copied.
+    fn from(array: Array<T, 2>) -> Self {
+        let len = array.data.len();
+        let [rows, cols] = array.shape;
+
+        if array.view().reversed_axes().contiguous_range() == Some(0..len) {
+            DMatrix::from_vec(rows, cols, array.data)
+        } else {
+            DMatrix::from_row_iterator(rows, cols, array.flat().cloned())
+        }
+    }
+}
+
+impl<T: nalgebra::Scalar> From<DMatrix<T>> for Array<T, 2> {
+    fn from(matrix: DMatrix<T>) -> Self {
+        let (rows, cols) = matrix.shape();
+
+        ArrayBase {
+            data: matrix.data.into(),
+            shape: [rows, cols],
+            strides: [1, rows as isize],
+            offset: 0,
+        }
+    }
+}
+
+impl<T: nalgebra::Scalar> From<Array<T, 1>> for DVector<T> {
+    fn from(array: Array<T, 1>) -> Self {
+        let len = array.data.len();
+
+        if array.contiguous_range() == Some(0..len) {
+            DVector::from_vec(array.data)
+        } else {
+            DVector::from_iterator(array.shape[0], array.flat().cloned())
+        }
+    }
+}
+
+impl<T: nalgebra::Scalar> From<DVector<T>> for Array<T, 1> {
+    fn from(vector: DVector<T>) -> Self {
+        let len = vector.len();
+
+        ArrayBase {
+            data: vector.data.into(),
+            shape: [len],
+            strides: [1],
+            offset: 0,
+        }
+    }
+}
+
+impl<T: nalgebra::Scalar, const R: usize, const C: usize> From<SMatrix<T, R, C>> for Array<T, 2> {
+    fn from(matrix: SMatrix<T, R, C>) -> Self {
+        ArrayBase {
+            data: matrix.iter().cloned().collect(),
+            shape: [R, C],
+            strides: [1, R as isize],
+            offset: 0,
+        }
+    }
+}
+
+impl<T: nalgebra::Scalar, const R: usize, const C: usize> TryFrom<Array<T, 2>>
+    for SMatrix<T, R, C>
+{
+    type Error = NalgebraShapeError;
+
+    fn try_from(array: Array<T, 2>) -> Result<Self, Self::Error> {
+        if array.shape != [R, C] {
+            return Err(NalgebraShapeError {
+                expected: vec![R, C],
+                found: array.shape.to_vec(),
+            });
+        }
+
+        Ok(SMatrix::from_row_iterator(array.flat().cloned()))
+    }
+}
+
+impl<T: nalgebra::Scalar, const N: usize> From<SVector<T, N>> for Array<T, 1> {
+    fn from(vector: SVector<T, N>) -> Self {
+        ArrayBase {
+            data: vector.iter().cloned().collect(),
+            shape: [N],
+            strides: [1],
+            offset: 0,
+        }
+    }
+}
+
+impl<T: nalgebra::Scalar, const N: usize> TryFrom<Array<T, 1>> for SVector<T, N> {
+    type Error = NalgebraShapeError;
+
+    fn try_from(array: Array<T, 1>) -> Result<Self, Self::Error> {
+        if array.shape != [N] {
+            return Err(NalgebraShapeError {
+                expected: vec![N],
+                found: array.shape.to_vec(),
+            });
+        }
+
+        Ok(SVector::from_iterator(array.flat().cloned()))
+    }
+}
+
+// nalgebra views walk their data with unsigned strides, so views with flipped axes
+// can not be borrowed.
+impl<'a, T: nalgebra::Scalar> TryFrom<View<'a, T, 2>> for DMatrixView<'a, T, Dyn, Dyn> {
+    type Error = ShapeError;
+
+    fn try_from(view: View<'a, T, 2>) -> Result<Self, Self::Error> {
+        let [rows, cols] = view.shape;
+        let [row_stride, col_stride] = view.strides;
+
+        if row_stride < 0 || col_stride < 0 {
+            return Err(ShapeError::IncompatibleLayout);
+        }
+
+        Ok(DMatrixView::from_slice_with_strides_generic(
+            &view.data[view.offset..],
+            Dyn(rows),
+            Dyn(cols),
+            Dyn(row_stride as usize),
+            Dyn(col_stride as usize),
+        ))
+    }
+}
+
+impl<'a, T: nalgebra::Scalar> TryFrom<View<'a, T, 1>> for DVectorView<'a, T, Dyn, Dyn> {
+    type Error = ShapeError;
+
+    fn try_from(view: View<'a, T, 1>) -> Result<Self, Self::Error> {
+        if view.strides[0] < 0 {
+            return Err(ShapeError::IncompatibleLayout);
+        }
+
+        Ok(DVectorView::from_slice_with_strides_generic(
+            &view.data[view.offset..],
+            Dyn(view.shape[0]),
+            Const::<1>,
+            Dyn(view.strides[0] as usize),
+            Dyn(0),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{Matrix2x3, Vector3};
+
+    use super::*;
+
+    #[test]
+    fn matrices() {
+        let array = Array::arange(0..6).reshape([2, 3]).unwrap();
+
+        let matrix = DMatrix::from(array.clone());
+
+        assert_eq!(matrix, DMatrix::from_row_slice(2, 3, &[0, 1, 2, 3, 4, 5]));
+        assert_eq!(Array::from(matrix), array);
+
+        let transposed = array.clone().reversed_axes();
+        let ptr = transposed.data.as_ptr();
+        let matrix = DMatrix::from(transposed);
+
+        assert_eq!(matrix, DMatrix::from_row_slice(3, 2, &[0, 3, 1, 4, 2, 5]));
+        assert_eq!(matrix.as_ptr(), ptr);
+
+        let flipped = array.flip(1).to_owned();
+
+        assert_eq!(
+            DMatrix::from(flipped),
+            DMatrix::from_row_slice(2, 3, &[2, 1, 0, 5, 4, 3])
+        );
+    }
+
+    #[test]
+    fn vectors() {
+        let array = Array::arange(0..3);
+        let ptr = array.data.as_ptr();
+
+        let vector = DVector::from(array);
+
+        assert_eq!(vector, DVector::from_vec(vec![0, 1, 2]));
+        assert_eq!(vector.as_ptr(), ptr);
+        assert_eq!(Array::from(vector), Array::arange(0..3));
+
+        let every_other = Array::arange(0..6).slice_move(&crate::s![..;2]);
+
+        assert_eq!(DVector::from(every_other), DVector::from_vec(vec![0, 2, 4]));
+    }
+
+    #[test]
+    fn fixed_size() {
+        let array = Array::arange(0..6).reshape([2, 3]).unwrap();
+
+        let matrix = Matrix2x3::try_from(array.clone()).unwrap();
+
+        assert_eq!(matrix, Matrix2x3::new(0, 1, 2, 3, 4, 5));
+        assert_eq!(Array::from(matrix), array);
+        assert_eq!(
+            SMatrix::<i32, 3, 2>::try_from(array).err(),
+            Some(NalgebraShapeError {
+                expected: vec![3, 2],
+                found: vec![2, 3]
+            })
+        );
+
+        let vector = Vector3::try_from(Array::arange(0..3)).unwrap();
+
+        assert_eq!(vector, Vector3::new(0, 1, 2));
+        assert_eq!(Array::from(vector), Array::arange(0..3));
+    }
+
+    #[test]
+    fn views() {
+        let array = Array::arange(0..12).reshape([3, 4]).unwrap();
+
+        let view = DMatrixView::try_from(array.slice(&crate::s![1.., ..;2])).unwrap();
+
+        assert_eq!(view, DMatrix::from_row_slice(2, 2, &[4, 6, 8, 10]));
+
+        let column = DVectorView::try_from(array.index_axis::<1>(1, 1)).unwrap();
+
+        assert_eq!(column, DVector::from_vec(vec![1, 5, 9]));
+        assert_eq!(
+            DMatrixView::try_from(array.flip(0)).err(),
+            Some(ShapeError::IncompatibleLayout)
+        );
+    }
+}
@@ -0,0 +1,283 @@
+use std::convert::TryFrom;
+
+use ::ndarray::{Array as NdArray, ArrayView, ArrayViewMut, Axis, Dim, Dimension, ShapeBuilder};
+
+use crate::{Array, ArrayBase, ShapeError, View, ViewMut};
+
+// `ndarray` only implements its dimension types up to six axes
+fn dim<const D: usize>(values: [usize; D]) -> Dim<[usize; D]>
+where
+    Dim<[usize; D]>: Dimension,
+{
+    let mut dim = Dim::<[usize; D]>::default();
+    dim.slice_mut().copy_from_slice(&values);
+
+    dim
+}
+
+impl<T: Clone, const D: usize> From<Array<T, D>> for NdArray<T, Dim<[usize; D]>>
+where
+    Dim<[usize; D]>: Dimension,
+{
+    // Arrays that are C or F contiguous over their whole vector are moved without copying,
+    // the others are copied in row-major order.
+    fn from(array: Array<T, D>) -> Self {
+        let len = array.data.len();
+        let transposed = ArrayBase {
+            data: (),
+            shape: array.shape,
+            strides: array.strides,
+            offset: array.offset,
+        }
+        .reversed_axes();
+
+        let shape = dim(array.shape);
+        let result = if array.contiguous_range() == Some(0..len) {
+            NdArray::from_shape_vec(shape, array.data)
+        } else if transposed.contiguous_range() == Some(0..len) {
+            NdArray::from_shape_vec(shape.f(), array.data)
+        } else {
+            NdArray::from_shape_vec(shape, array.to_owned().data)
+        };
+
+        result.expect("Shape of the array must be valid for ndarray")
+    }
+}
+
+impl<T, const D: usize> From<NdArray<T, Dim<[usize; D]>>> for Array<T, D>
+where
+    Dim<[usize; D]>: Dimension,
+{
+    // The vector of `ndarray` is reused with its shape, strides and offset
+    fn from(array: NdArray<T, Dim<[usize; D]>>) -> Self {
+        let mut shape = [0; D];
+        let mut strides = [0; D];
+        shape.copy_from_slice(array.shape());
+        strides.copy_from_slice(array.strides());
+
+        let (data, offset) = array.into_raw_vec_and_offset();
+
+        ArrayBase {
+            data,
+            shape,
+            strides,
+            offset: offset.unwrap_or(0),
+        }
+    }
+}
+
+// `ndarray` views are built from their lowest address with non-negative strides,
+// the axes that walk backwards are inverted afterwards.
+fn lowest_offset<const D: usize>(
+    shape: &[usize; D],
+    strides: &[isize; D],
+    offset: usize,
+) -> (usize, [usize; D]) {
+    let mut lowest = offset as isize;
+    let mut abs_strides = [0; D];
+
+    let empty = shape.contains(&0);
+    for axis in 0..D {
+        if strides[axis] < 0 && !empty {
+            lowest += (shape[axis] as isize - 1) * strides[axis];
+        }
+
+        abs_strides[axis] = strides[axis].unsigned_abs();
+    }
+
+    (lowest as usize, abs_strides)
+}
+
+impl<'a, T, const D: usize> From<View<'a, T, D>> for ArrayView<'a, T, Dim<[usize; D]>>
+where
+    Dim<[usize; D]>: Dimension,
+{
+    fn from(view: View<'a, T, D>) -> Self {
+        let (lowest, strides) = lowest_offset(&view.shape, &view.strides, view.offset);
+
+        // Safety: every element of the view lies in its borrowed data
+        let mut result = unsafe {
+            ArrayView::from_shape_ptr(
+                dim(view.shape).strides(dim(strides)),
+                view.data.as_ptr().add(lowest),
+            )
+        };
+
+        for axis in 0..D {
+            if view.strides[axis] < 0 {
+                result.invert_axis(Axis(axis));
+            }
+        }
+
+        result
+    }
+}
+
+impl<'a, T, const D: usize> From<ViewMut<'a, T, D>> for ArrayViewMut<'a, T, Dim<[usize; D]>>
+where
+    Dim<[usize; D]>: Dimension,
+{
+    fn from(view: ViewMut<'a, T, D>) -> Self {
+        let (lowest, strides) = lowest_offset(&view.shape, &view.strides, view.offset);
+
+        // Safety: every element of the view lies in its exclusively borrowed data
+        let mut result = unsafe {
+            ArrayViewMut::from_shape_ptr(
+                dim(view.shape).strides(dim(strides)),
+                view.data.as_mut_ptr().add(lowest),
+            )
+        };
+
+        for axis in 0..D {
+            if view.strides[axis] < 0 {
+                result.invert_axis(Axis(axis));
+            }
+        }
+
+        result
+    }
+}
+
+// Offset of the first element of a view from the start of the memory it covers
+fn offset_from<T>(first: *const T, start: *const T) -> usize {
+    (first as usize - start as usize) / std::mem::size_of::<T>().max(1)
+}
+
+// Views with gaps between their elements can not be borrowed as a slice without
+// also covering memory that other views may be mutating, so only views that are
+// contiguous in memory are converted.
+impl<'a, T, const D: usize> TryFrom<ArrayView<'a, T, Dim<[usize; D]>>> for View<'a, T, D>
+where
+    Dim<[usize; D]>: Dimension,
+{
+    type Error = ShapeError;
+
+    fn try_from(view: ArrayView<'a, T, Dim<[usize; D]>>) -> Result<Self, Self::Error> {
+        let mut shape = [0; D];
+        let mut strides = [0; D];
+        shape.copy_from_slice(view.shape());
+        strides.copy_from_slice(view.strides());
+
+        let first = view.as_ptr();
+        let data = view
+            .to_slice_memory_order()
+            .ok_or(ShapeError::IncompatibleLayout)?;
+
+        Ok(ArrayBase {
+            data,
+            shape,
+            strides,
+            offset: offset_from(first, data.as_ptr()),
+        })
+    }
+}
+
+impl<'a, T, const D: usize> TryFrom<ArrayViewMut<'a, T, Dim<[usize; D]>>> for ViewMut<'a, T, D>
+where
+    Dim<[usize; D]>: Dimension,
+{
+    type Error = ShapeError;
+
+    fn try_from(view: ArrayViewMut<'a, T, Dim<[usize; D]>>) -> Result<Self, Self::Error> {
+        let mut shape = [0; D];
+        let mut strides = [0; D];
+        shape.copy_from_slice(view.shape());
+        strides.copy_from_slice(view.strides());
+
+        let first = view.as_ptr();
+        let data = view
+            .into_slice_memory_order()
+            .ok_or(ShapeError::IncompatibleLayout)?;
+        let offset = offset_from(first, data.as_ptr());
+
+        Ok(ArrayBase {
+            data,
+            shape,
+            strides,
+            offset,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::ndarray::{arr2, s};
+
+    use super::*;
+
+    #[test]
+    fn into_ndarray() {
+        let array = Array::arange(0..6).reshape([2, 3]).unwrap();
+        let ptr = array.data.as_ptr();
+
+        let converted = NdArray::from(array);
+
+        assert_eq!(converted, arr2(&[[0, 1, 2], [3, 4, 5]]));
+        assert_eq!(converted.as_ptr(), ptr);
+
+        let transposed =
+            NdArray::from(Array::arange(0..6).reshape([2, 3]).unwrap().reversed_axes());
+
+        assert_eq!(transposed, arr2(&[[0, 3], [1, 4], [2, 5]]));
+        assert!(transposed.t().is_standard_layout());
+
+        let sliced = Array::arange(0..6)
+            .reshape([2, 3])
+            .unwrap()
+            .slice_move(&crate::s![.., ..;2])
+            .flip_move(1);
+
+        assert_eq!(NdArray::from(sliced), arr2(&[[2, 0], [5, 3]]));
+    }
+
+    #[test]
+    fn from_ndarray() {
+        let mut array = arr2(&[[0, 1, 2], [3, 4, 5]]);
+        array.slice_collapse(s![.., ..;-1]);
+        let ptr = array.as_ptr();
+
+        let converted = Array::from(array);
+
+        assert_eq!(converted, Array::init(vec![2, 1, 0, 5, 4, 3], [2, 3]));
+        assert_eq!(converted.strides(), &[3, -1]);
+        assert_eq!(&converted[[0, 0]] as *const i32, ptr);
+    }
+
+    #[test]
+    fn views_into_ndarray() {
+        let mut array = Array::arange(0..12).reshape([3, 4]).unwrap();
+
+        let view = ArrayView::from(array.slice(&crate::s![1.., ..;2]).flip_move(1));
+
+        assert_eq!(view, arr2(&[[6, 4], [10, 8]]));
+
+        let mut view = ArrayViewMut::from(array.slice_mut(&crate::s![.., 1..2]).flip_move(0));
+        view[[0, 0]] = 90;
+        view[[2, 0]] = 10;
+
+        assert_eq!(array[[2, 1]], 90);
+        assert_eq!(array[[0, 1]], 10);
+    }
+
+    #[test]
+    fn views_from_ndarray() {
+        let mut array = arr2(&[[0, 1, 2], [3, 4, 5]]);
+
+        let view = View::try_from(array.t()).unwrap();
+
+        assert_eq!(view, Array::init(vec![0, 3, 1, 4, 2, 5], [3, 2]));
+
+        let view = View::try_from(array.slice(s![..;-1, ..])).unwrap();
+
+        assert_eq!(view, Array::init(vec![3, 4, 5, 0, 1, 2], [2, 3]));
+        assert_eq!(
+            View::<i32, 2>::try_from(array.slice(s![.., ..2])).err(),
+            Some(ShapeError::IncompatibleLayout)
+        );
+
+        let mut view = ViewMut::try_from(array.slice_mut(s![1..2, ..;-1])).unwrap();
+        view[[0, 0]] = 50;
+
+        assert_eq!(array[[1, 2]], 50);
+    }
+}
@@ -0,0 +1,559 @@
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use crate::{Array, ArrayBase, ShapeError, Storage};
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+// Headers are padded so that the data starts at a multiple of this
+const ALIGNMENT: usize = 64;
+
+// Number of encoded bytes buffered before they are handed to the writer
+const WRITE_BLOCK_LEN: usize = 8192;
+
+pub trait NpyElement: Copy {
+    // Type descriptor of the element in the header, like `<f8`
+    const DESCR: &'static str;
+
+    fn read_le(bytes: &[u8]) -> Self;
+
+    fn write_le(self, out: &mut Vec<u8>);
+}
+
+macro_rules! impl_npy_element {
+    ($($ty:ty => $descr:expr),*) => {
+        $(impl NpyElement for $ty {
+            const DESCR: &'static str = $descr;
+
+            fn read_le(bytes: &[u8]) -> Self {
+                let mut le_bytes = [0; std::mem::size_of::<$ty>()];
+                le_bytes.copy_from_slice(bytes);
+
+                <$ty>::from_le_bytes(le_bytes)
+            }
+
+            fn write_le(self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+        })*
+    };
+}
+
+impl_npy_element!(
+    i8 => "|i1", i16 => "<i2", i32 => "<i4", i64 => "<i8",
+    u8 => "|u1", u16 => "<u2", u32 => "<u4", u64 => "<u8",
+    f32 => "<f4", f64 => "<f8"
+);
+
+#[derive(Debug)]
+pub enum NpyError {
+    Io(io::Error),
+    InvalidHeader(String),
+    DtypeMismatch {
+        expected: &'static str,
+        found: String,
+    },
+    RankMismatch {
+        expected: usize,
+        found: usize,
+    },
+    Shape(ShapeError),
+}
+
+impl fmt::Display for NpyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NpyError::Io(error) => write!(f, "I/O error: {}", error),
+            NpyError::InvalidHeader(reason) => write!(f, "Invalid npy header: {}", reason),
+            NpyError::DtypeMismatch { expected, found } => write!(
+                f,
+                "Data type of the file does not match the array: {} != {}",
+                found, expected
+            ),
+            NpyError::RankMismatch { expected, found } => write!(
+                f,
+                "Number of axes in the file does not match the array: {} != {}",
+                found, expected
+            ),
+            NpyError::Shape(error) => error.fmt(f),
+        }
+    }
+}
+
+impl Error for NpyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            NpyError::Io(error) => Some(error),
+            NpyError::Shape(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for NpyError {
+    fn from(error: io::Error) -> Self {
+        NpyError::Io(error)
+    }
+}
+
+impl From<ShapeError> for NpyError {
+    fn from(error: ShapeError) -> Self {
+        NpyError::Shape(error)
+    }
+}
+
+impl<T: NpyElement, const D: usize> Array<T, D> {
+    pub fn read_npy<P: AsRef<Path>>(path: P) -> Result<Self, NpyError> {
+        Array::read_npy_from(BufReader::new(File::open(path)?))
+    }
+
+    pub fn read_npy_from<R: Read>(reader: R) -> Result<Self, NpyError> {
+        let (shape, fortran_order, vec) = read_npy_parts(reader)?;
+
+        let shape = <[usize; D]>::try_from(shape).map_err(|shape| NpyError::RankMismatch {
+            expected: D,
+            found: shape.len(),
+        })?;
+
+        if fortran_order {
+            Ok(Array::try_init_f(vec, shape)?)
+        } else {
+            Ok(Array::try_init(vec, shape)?)
+        }
+    }
+}
+
+// The shape, whether the data is in Fortran order and the elements of a `.npy` file
+pub(crate) fn read_npy_parts<T: NpyElement, R: Read>(
+    mut reader: R,
+) -> Result<(Vec<usize>, bool, Vec<T>), NpyError> {
+    let mut preamble = [0; 8];
+    reader.read_exact(&mut preamble)?;
+
+    if &preamble[..6] != MAGIC {
+        return Err(NpyError::InvalidHeader(String::from(
+            "missing magic string",
+        )));
+    }
+
+    let header_len = match preamble[6] {
+        1 => {
+            let mut len = [0; 2];
+            reader.read_exact(&mut len)?;
+
+            u16::from_le_bytes(len) as usize
+        }
+        2 | 3 => {
+            let mut len = [0; 4];
+            reader.read_exact(&mut len)?;
+
+            u32::from_le_bytes(len) as usize
+        }
+        version => {
+            return Err(NpyError::InvalidHeader(format!(
+                "unsupported version {}",
+                version
+            )))
+        }
+    };
+
+    let header = read_bytes(&mut reader, header_len)?;
+
+    let header = String::from_utf8(header)
+        .map_err(|_| NpyError::InvalidHeader(String::from("header is not valid text")))?;
+
+    let descr = header_value(&header, "descr")?.trim_matches('\'');
+
+    // Single byte types have no byte order and may be written with either `|` or `<`
+    let single_byte = std::mem::size_of::<T>() == 1
+        && (descr.starts_with('|') || descr.starts_with('<'))
+        && descr.get(1..) == T::DESCR.get(1..);
+
+    if descr != T::DESCR && !single_byte {
+        return Err(NpyError::DtypeMismatch {
+            expected: T::DESCR,
+            found: descr.to_string(),
+        });
+    }
+
+    let fortran_order = match header_value(&header, "fortran_order")? {
+        "True" => true,
+        "False" => false,
+        value => {
+            return Err(NpyError::InvalidHeader(format!(
+                "invalid fortran_order: {}",
+                value
+            )))
+        }
+    };
+
+    let shape = parse_shape(header_value(&header, "shape")?)?;
+
+    let len = super::checked_len(&shape)?;
+    let size = std::mem::size_of::<T>();
+
+    let byte_len = len.checked_mul(size).ok_or(ShapeError::Overflow)?;
+
+    let bytes = read_bytes(&mut reader, byte_len)?;
+
+    let vec = bytes.chunks_exact(size).map(T::read_le).collect();
+
+    Ok((shape, fortran_order, vec))
+}
+
+// Reads exactly `len` bytes. The buffer grows with the data that actually arrives, so a
+// length taken from a corrupt header fails with an error instead of a huge allocation.
+fn read_bytes<R: Read>(reader: &mut R, len: usize) -> io::Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    reader.take(len as u64).read_to_end(&mut bytes)?;
+
+    if bytes.len() != len {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "file is shorter than its header says",
+        ));
+    }
+
+    Ok(bytes)
+}
+
+impl<T: NpyElement, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn write_npy<P: AsRef<Path>>(&self, path: P) -> Result<(), NpyError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+
+        self.write_npy_to(&mut writer)?;
+
+        Ok(writer.flush()?)
+    }
+
+    pub fn write_npy_to<W: Write>(&self, writer: W) -> Result<(), NpyError> {
+        // Column-major arrays are written as they are laid out instead of being reordered
+        let transposed = self.view().reversed_axes();
+        let fortran_order =
+            self.contiguous_range().is_none() && transposed.contiguous_range().is_some();
+
+        let source = if fortran_order {
+            transposed
+        } else {
+            self.view()
+        };
+
+        write_npy_parts(writer, &self.shape, fortran_order, source.flat())
+    }
+}
+
+// Writes `elems` in the order they are stored in the file
+pub(crate) fn write_npy_parts<'a, T: NpyElement + 'a, W: Write>(
+    mut writer: W,
+    shape: &[usize],
+    fortran_order: bool,
+    elems: impl Iterator<Item = &'a T>,
+) -> Result<(), NpyError> {
+    let shape = match shape.len() {
+        1 => format!("({},)", shape[0]),
+        _ => format!(
+            "({})",
+            shape
+                .iter()
+                .map(|len| len.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+    };
+
+    let mut header = format!(
+        "{{'descr': '{}', 'fortran_order': {}, 'shape': {}, }}",
+        T::DESCR,
+        if fortran_order { "True" } else { "False" },
+        shape
+    );
+
+    // Version 1 stores the header length in two bytes, larger headers need version 2
+    let version = if padded_len(header.len(), 2) <= u16::MAX as usize {
+        1
+    } else {
+        2
+    };
+    let header_len = padded_len(header.len(), if version == 1 { 2 } else { 4 });
+
+    header.push_str(&" ".repeat(header_len - header.len() - 1));
+    header.push('\n');
+
+    let mut bytes = Vec::with_capacity(MAGIC.len() + 6 + header.len());
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&[version, 0]);
+    if version == 1 {
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    } else {
+        let len = u32::try_from(header.len())
+            .map_err(|_| NpyError::InvalidHeader(String::from("header is too long")))?;
+        bytes.extend_from_slice(&len.to_le_bytes());
+    }
+    bytes.extend_from_slice(header.as_bytes());
+
+    writer.write_all(&bytes)?;
+
+    // The elements are encoded a block at a time instead of copying the whole array
+    bytes.clear();
+    for elem in elems {
+        elem.write_le(&mut bytes);
+
+        if bytes.len() >= WRITE_BLOCK_LEN {
+            writer.write_all(&bytes)?;
+            bytes.clear();
+        }
+    }
+
+    Ok(writer.write_all(&bytes)?)
+}
+
+// Length of the header including the padding spaces and the trailing newline
+fn padded_len(dict_len: usize, len_bytes: usize) -> usize {
+    let prefix_len = MAGIC.len() + 2 + len_bytes;
+    let unpadded = prefix_len + dict_len + 1;
+
+    unpadded + (ALIGNMENT - unpadded % ALIGNMENT) % ALIGNMENT - prefix_len
+}
+
+// Finds the raw value of `key` in the python dict literal of the header
+fn header_value<'a>(header: &'a str, key: &str) -> Result<&'a str, NpyError> {
+    let missing = || NpyError::InvalidHeader(format!("missing key '{}'", key));
+
+    let start = header.find(&format!("'{}'", key)).ok_or_else(missing)? + key.len() + 2;
+    let rest = header[start..]
+        .trim_start()
+        .strip_prefix(':')
+        .ok_or_else(missing)?;
+    let rest = rest.trim_start();
+
+    // The shape is a tuple and contains commas itself
+    let end = if rest.starts_with('(') {
+        rest.find(')').map(|end| end + 1)
+    } else {
+        rest.find([',', '}'])
+    };
+
+    Ok(rest[..end.ok_or_else(missing)?].trim())
+}
+
+fn parse_shape(tuple: &str) -> Result<Vec<usize>, NpyError> {
+    let invalid = || NpyError::InvalidHeader(format!("invalid shape: {}", tuple));
+
+    tuple
+        .strip_prefix('(')
+        .and_then(|tuple| tuple.strip_suffix(')'))
+        .ok_or_else(invalid)?
+        .split(',')
+        .map(str::trim)
+        .filter(|len| !len.is_empty())
+        .map(|len| len.parse().map_err(|_| invalid()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // File as written by numpy for the given header dict and data
+    fn npy_bytes(version: u8, dict: &str, data: &[u8]) -> Vec<u8> {
+        let len_bytes = if version == 1 { 2 } else { 4 };
+        let header = format!(
+            "{:width$}\n",
+            dict,
+            width = padded_len(dict.len(), len_bytes) - 1
+        );
+
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend_from_slice(&[version, 0]);
+        if version == 1 {
+            bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        } else {
+            bytes.extend_from_slice(&(header.len() as u32).to_le_bytes());
+        }
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(data);
+
+        bytes
+    }
+
+    #[test]
+    fn read_c_order() {
+        let data: Vec<u8> = (0..6i32).flat_map(|x| x.to_le_bytes()).collect();
+        let bytes = npy_bytes(
+            1,
+            "{'descr': '<i4', 'fortran_order': False, 'shape': (2, 3), }",
+            &data,
+        );
+
+        let array = Array::<i32, 2>::read_npy_from(&bytes[..]).unwrap();
+
+        assert_eq!(array, Array::init(vec![0, 1, 2, 3, 4, 5], [2, 3]));
+    }
+
+    #[test]
+    fn read_fortran_order() {
+        // 0 1 2
+        // 3 4 5
+        let data: Vec<u8> = [0.0, 3.0, 1.0, 4.0, 2.0, 5.0f64]
+            .iter()
+            .flat_map(|x| x.to_le_bytes())
+            .collect();
+        let bytes = npy_bytes(
+            1,
+            "{'descr': '<f8', 'fortran_order': True, 'shape': (2, 3), }",
+            &data,
+        );
+
+        let array = Array::<f64, 2>::read_npy_from(&bytes[..]).unwrap();
+
+        assert_eq!(
+            array,
+            Array::init(vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0], [2, 3])
+        );
+        assert_eq!(array.strides(), &[1, 2]);
+    }
+
+    #[test]
+    fn read_version_2() {
+        let bytes = npy_bytes(
+            2,
+            "{'descr': '|u1', 'fortran_order': False, 'shape': (4,), }",
+            &[1, 2, 3, 4],
+        );
+
+        let array = Array::<u8, 1>::read_npy_from(&bytes[..]).unwrap();
+
+        assert_eq!(array, Array::init(vec![1, 2, 3, 4], [4]));
+    }
+
+    #[test]
+    fn read_scalar() {
+        let bytes = npy_bytes(
+            1,
+            "{'descr': '<i8', 'fortran_order': False, 'shape': (), }",
+            &7i64.to_le_bytes(),
+        );
+
+        let array = Array::<i64, 0>::read_npy_from(&bytes[..]).unwrap();
+
+        assert_eq!(array[[]], 7);
+    }
+
+    #[test]
+    fn read_errors() {
+        let data: Vec<u8> = (0..6i32).flat_map(|x| x.to_le_bytes()).collect();
+        let bytes = npy_bytes(
+            1,
+            "{'descr': '<i4', 'fortran_order': False, 'shape': (2, 3), }",
+            &data,
+        );
+
+        assert!(matches!(
+            Array::<f32, 2>::read_npy_from(&bytes[..]),
+            Err(NpyError::DtypeMismatch {
+                expected: "<f4",
+                ..
+            })
+        ));
+        assert!(matches!(
+            Array::<i32, 1>::read_npy_from(&bytes[..]),
+            Err(NpyError::RankMismatch {
+                expected: 1,
+                found: 2
+            })
+        ));
+        assert!(matches!(
+            Array::<i32, 2>::read_npy_from(&bytes[..bytes.len() - 1]),
+            Err(NpyError::Io(_))
+        ));
+        assert!(matches!(
+            Array::<i32, 2>::read_npy_from(&b"not an npy file"[..]),
+            Err(NpyError::InvalidHeader(_))
+        ));
+
+        let big_endian = npy_bytes(
+            1,
+            "{'descr': '>i4', 'fortran_order': False, 'shape': (6,), }",
+            &data,
+        );
+        assert!(matches!(
+            Array::<i32, 1>::read_npy_from(&big_endian[..]),
+            Err(NpyError::DtypeMismatch { .. })
+        ));
+
+        // the shape claims far more data than the file holds
+        let huge = npy_bytes(
+            1,
+            "{'descr': '<i4', 'fortran_order': False, 'shape': (1000000000000,), }",
+            &data,
+        );
+        assert!(matches!(
+            Array::<i32, 1>::read_npy_from(&huge[..]),
+            Err(NpyError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn write_in_blocks() {
+        // spans several write blocks
+        let array = Array::from_shape_fn([100, 30], |[i, j]| (i * 30 + j) as f64);
+
+        let mut bytes = Vec::new();
+        array.write_npy_to(&mut bytes).unwrap();
+
+        assert_eq!(bytes.len() % ALIGNMENT, 0);
+        assert_eq!(Array::<f64, 2>::read_npy_from(&bytes[..]).unwrap(), array);
+    }
+
+    #[test]
+    fn write_matches_numpy() {
+        let array = Array::init((0..6).collect::<Vec<i32>>(), [2, 3]);
+        let data: Vec<u8> = (0..6i32).flat_map(|x| x.to_le_bytes()).collect();
+
+        let mut bytes = vec![];
+        array.write_npy_to(&mut bytes).unwrap();
+
+        assert_eq!(
+            bytes,
+            npy_bytes(
+                1,
+                "{'descr': '<i4', 'fortran_order': False, 'shape': (2, 3), }",
+                &data
+            )
+        );
+        assert_eq!((bytes.len() - data.len()) % ALIGNMENT, 0);
+    }
+
+    #[test]
+    fn write_layouts() {
+        let array = Array::init((0..12).map(|x| x as f32).collect(), [3, 4]);
+
+        for view in [array.view(), array.t(), array.slice(&crate::s![..;2, 1..])] {
+            let mut bytes = vec![];
+            view.write_npy_to(&mut bytes).unwrap();
+
+            assert_eq!(Array::<f32, 2>::read_npy_from(&bytes[..]).unwrap(), view);
+        }
+
+        let mut bytes = vec![];
+        array.t().write_npy_to(&mut bytes).unwrap();
+
+        assert!(String::from_utf8_lossy(&bytes).contains("'fortran_order': True"));
+    }
+
+    #[test]
+    fn write_and_read_file() {
+        let path = std::env::temp_dir().join(format!("nd_array_{}.npy", std::process::id()));
+        let array = Array::init(vec![1.5, -2.0, 3.25], [3]);
+
+        array.write_npy(&path).unwrap();
+        let read = Array::<f64, 1>::read_npy(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(read, array);
+    }
+}
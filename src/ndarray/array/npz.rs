@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Seek, Write};
+use std::path::Path;
+
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::{Array, ArrayBase, NpyElement, NpyError, Storage};
+
+// numpy stores every array of an archive as a `.npy` file named after the array
+const SUFFIX: &str = ".npy";
+
+pub struct NpzReader<R: Read + Seek> {
+    archive: ZipArchive<R>,
+}
+
+impl NpzReader<BufReader<File>> {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, NpyError> {
+        NpzReader::new(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<R: Read + Seek> NpzReader<R> {
+    pub fn new(reader: R) -> Result<Self, NpyError> {
+        let archive = ZipArchive::new(reader).map_err(io::Error::from)?;
+
+        Ok(NpzReader { archive })
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        self.archive
+            .file_names()
+            .map(|name| name.strip_suffix(SUFFIX).unwrap_or(name).to_string())
+            .collect()
+    }
+
+    pub fn by_name<T: NpyElement, const D: usize>(
+        &mut self,
+        name: &str,
+    ) -> Result<Array<T, D>, NpyError> {
+        let index = self
+            .archive
+            .index_for_name(&format!("{}{}", name, SUFFIX))
+            .or_else(|| self.archive.index_for_name(name))
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("No array named {} in the archive", name),
+                )
+            })?;
+
+        let file = self.archive.by_index(index).map_err(io::Error::from)?;
+
+        Array::read_npy_from(file)
+    }
+
+    pub fn read_all<T: NpyElement, const D: usize>(
+        &mut self,
+    ) -> Result<HashMap<String, Array<T, D>>, NpyError> {
+        self.names()
+            .into_iter()
+            .map(|name| {
+                let array = self.by_name(&name)?;
+
+                Ok((name, array))
+            })
+            .collect()
+    }
+}
+
+pub struct NpzWriter<W: Write + Seek> {
+    zip: ZipWriter<W>,
+    options: SimpleFileOptions,
+}
+
+impl NpzWriter<BufWriter<File>> {
+    pub fn create<P: AsRef<Path>>(path: P) -> Result<Self, NpyError> {
+        Ok(NpzWriter::new(BufWriter::new(File::create(path)?)))
+    }
+
+    pub fn create_compressed<P: AsRef<Path>>(path: P) -> Result<Self, NpyError> {
+        Ok(NpzWriter::new_compressed(BufWriter::new(File::create(
+            path,
+        )?)))
+    }
+}
+
+impl<W: Write + Seek> NpzWriter<W> {
+    // Stores the arrays uncompressed, like `numpy.savez`
+    pub fn new(writer: W) -> Self {
+        NpzWriter::with_compression(writer, CompressionMethod::Stored)
+    }
+
+    // Deflates the arrays, like `numpy.savez_compressed`
+    pub fn new_compressed(writer: W) -> Self {
+        NpzWriter::with_compression(writer, CompressionMethod::Deflated)
+    }
+
+    fn with_compression(writer: W, method: CompressionMethod) -> Self {
+        NpzWriter {
+            zip: ZipWriter::new(writer),
+            options: SimpleFileOptions::default().compression_method(method),
+        }
+    }
+
+    pub fn add_array<T: NpyElement, S: Storage<Elem = T>, const D: usize>(
+        &mut self,
+        name: &str,
+        array: &ArrayBase<S, D>,
+    ) -> Result<(), NpyError> {
+        let mut bytes = vec![];
+        array.write_npy_to(&mut bytes)?;
+
+        let options = self.options.large_file(bytes.len() >= u32::MAX as usize);
+        self.zip
+            .start_file(format!("{}{}", name, SUFFIX), options)
+            .map_err(io::Error::from)?;
+
+        Ok(self.zip.write_all(&bytes)?)
+    }
+
+    pub fn finish(self) -> Result<W, NpyError> {
+        let mut writer = self.zip.finish().map_err(io::Error::from)?;
+        writer.flush()?;
+
+        Ok(writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    fn archive(compressed: bool) -> Vec<u8> {
+        let weights = Array::init(vec![0.5, -1.0, 2.0, 4.0], [2, 2]);
+        let bias = Array::init(vec![1.0, 2.0], [2]);
+        let labels = Array::init(vec![3u8, 1, 4], [3]);
+
+        let cursor = Cursor::new(vec![]);
+        let mut writer = if compressed {
+            NpzWriter::new_compressed(cursor)
+        } else {
+            NpzWriter::new(cursor)
+        };
+
+        writer.add_array("weights", &weights.t()).unwrap();
+        writer.add_array("bias", &bias).unwrap();
+        writer.add_array("labels", &labels).unwrap();
+
+        writer.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn round_trip() {
+        for compressed in [false, true] {
+            let mut reader = NpzReader::new(Cursor::new(archive(compressed))).unwrap();
+
+            assert_eq!(reader.names(), vec!["weights", "bias", "labels"]);
+            assert_eq!(
+                reader.by_name::<f64, 2>("weights").unwrap(),
+                Array::init(vec![0.5, 2.0, -1.0, 4.0], [2, 2])
+            );
+            assert_eq!(
+                reader.by_name::<f64, 1>("bias").unwrap(),
+                Array::init(vec![1.0, 2.0], [2])
+            );
+            assert_eq!(
+                reader.by_name::<u8, 1>("labels.npy").unwrap(),
+                Array::init(vec![3, 1, 4], [3])
+            );
+        }
+    }
+
+    #[test]
+    fn compression() {
+        let zeros = Array::<f64, 2>::zeros([100, 100]);
+
+        let mut stored = NpzWriter::new(Cursor::new(vec![]));
+        let mut deflated = NpzWriter::new_compressed(Cursor::new(vec![]));
+        stored.add_array("zeros", &zeros).unwrap();
+        deflated.add_array("zeros", &zeros).unwrap();
+
+        let stored = stored.finish().unwrap().into_inner();
+        let deflated = deflated.finish().unwrap().into_inner();
+
+        assert!(deflated.len() * 10 < stored.len());
+        assert_eq!(
+            NpzReader::new(Cursor::new(deflated))
+                .unwrap()
+                .by_name::<f64, 2>("zeros")
+                .unwrap(),
+            zeros
+        );
+    }
+
+    #[test]
+    fn read_all() {
+        let mut reader = NpzReader::new(Cursor::new(archive(false))).unwrap();
+
+        assert!(matches!(
+            reader.read_all::<f64, 1>(),
+            Err(NpyError::RankMismatch {
+                expected: 1,
+                found: 2
+            })
+        ));
+
+        let mut writer = NpzWriter::new(Cursor::new(vec![]));
+        writer.add_array("x", &Array::arange(0..3i64)).unwrap();
+        writer.add_array("y", &Array::arange(3..5i64)).unwrap();
+
+        let arrays = NpzReader::new(Cursor::new(writer.finish().unwrap().into_inner()))
+            .unwrap()
+            .read_all::<i64, 1>()
+            .unwrap();
+
+        assert_eq!(arrays.len(), 2);
+        assert_eq!(arrays["y"], Array::init(vec![3, 4], [2]));
+    }
+
+    #[test]
+    fn missing_array() {
+        let mut reader = NpzReader::new(Cursor::new(archive(false))).unwrap();
+
+        match reader.by_name::<f64, 1>("missing") {
+            Err(NpyError::Io(error)) => assert_eq!(error.kind(), io::ErrorKind::NotFound),
+            _ => panic!("Expected a missing array to be reported as not found"),
+        }
+    }
+
+    #[test]
+    fn write_and_read_file() {
+        let path = std::env::temp_dir().join(format!("nd_array_{}.npz", std::process::id()));
+
+        let mut writer = NpzWriter::create_compressed(&path).unwrap();
+        writer
+            .add_array("a", &Array::init(vec![1, 2, 3, 4], [2, 2]))
+            .unwrap();
+        writer.finish().unwrap();
+
+        let read = NpzReader::open(&path).unwrap().by_name::<i32, 2>("a");
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(read.unwrap(), Array::init(vec![1, 2, 3, 4], [2, 2]));
+    }
+}
@@ -0,0 +1,107 @@
+use std::convert::TryFrom;
+
+use ::ndarray::{Array as NdArray, Dim, Dimension};
+use numpy::{Element, PyArray, PyReadonlyArray, PyReadwriteArray};
+use pyo3::{Bound, Python};
+
+use crate::{Array, ShapeError, View, ViewMut};
+
+// The conversions go through `ndarray`, whose arrays `numpy` already exposes to Python
+
+impl<T: Element + Clone, const D: usize> Array<T, D>
+where
+    Dim<[usize; D]>: Dimension,
+{
+    // The vector is handed over to numpy without copying when the array is C or F contiguous
+    pub fn into_pyarray(self, py: Python<'_>) -> Bound<'_, PyArray<T, Dim<[usize; D]>>> {
+        PyArray::from_owned_array(py, NdArray::from(self))
+    }
+}
+
+impl<'a, T: Element, const D: usize> View<'a, T, D>
+where
+    Dim<[usize; D]>: Dimension,
+{
+    // Borrows the memory of the numpy array, which must be contiguous
+    pub fn from_numpy(
+        array: &'a PyReadonlyArray<'_, T, Dim<[usize; D]>>,
+    ) -> Result<Self, ShapeError> {
+        View::try_from(array.as_array())
+    }
+}
+
+impl<'a, T: Element, const D: usize> ViewMut<'a, T, D>
+where
+    Dim<[usize; D]>: Dimension,
+{
+    pub fn from_numpy(
+        array: &'a mut PyReadwriteArray<'_, T, Dim<[usize; D]>>,
+    ) -> Result<Self, ShapeError> {
+        ViewMut::try_from(array.as_array_mut())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use numpy::{PyArrayMethods, PyUntypedArrayMethods};
+
+    use super::*;
+
+    // Most machines running the tests have no numpy installed, in which case there is
+    // nothing to exchange arrays with
+    fn has_numpy(py: Python<'_>) -> bool {
+        py.import("numpy").is_ok()
+    }
+
+    #[test]
+    fn into_pyarray() {
+        Python::initialize();
+        Python::attach(|py| {
+            if !has_numpy(py) {
+                return;
+            }
+
+            let array = Array::arange(0..6).reshape([2, 3]).unwrap();
+            let ptr = array.data.as_ptr();
+
+            let pyarray = array.into_pyarray(py);
+
+            assert_eq!(pyarray.shape(), &[2, 3]);
+            assert_eq!(pyarray.data() as *const i64, ptr);
+
+            let transposed = Array::arange(0..6).reshape([2, 3]).unwrap().reversed_axes();
+            let pyarray = transposed.into_pyarray(py);
+
+            assert_eq!(pyarray.strides(), &[8, 24]);
+            assert_eq!(pyarray.readonly().as_array()[[2, 1]], 5);
+        });
+    }
+
+    #[test]
+    fn views_from_numpy() {
+        Python::initialize();
+        Python::attach(|py| {
+            if !has_numpy(py) {
+                return;
+            }
+
+            let pyarray = Array::arange(0..6)
+                .reshape([2, 3])
+                .unwrap()
+                .into_pyarray(py);
+
+            let readonly = pyarray.readonly();
+            let view = View::from_numpy(&readonly).unwrap();
+
+            assert_eq!(view, Array::arange(0..6).reshape([2, 3]).unwrap());
+
+            drop(readonly);
+            let mut readwrite = pyarray.readwrite();
+            let mut view = ViewMut::from_numpy(&mut readwrite).unwrap();
+            view[[1, 2]] = 50;
+            drop(readwrite);
+
+            assert_eq!(pyarray.readonly().as_array()[[1, 2]], 50);
+        });
+    }
+}
@@ -1,154 +1,238 @@
-use std::{
-    borrow::Cow,
-    ops::{Add, Div, Mul, Neg, Sub},
-};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
-use crate::Array;
+use num_complex::Complex;
 
-impl<'a, T: Clone + Neg<Output = T>, const D: usize> Neg for Array<'a, T, D> {
-    type Output = Array<'a, T, D>;
+use super::broadcast::broadcast_pair;
+use crate::{Array, ArrayBase, Storage, StorageMut};
 
+impl<T: Clone + Neg<Output = T>, const D: usize> Neg for Array<T, D> {
+    type Output = Array<T, D>;
+
+    // Only the elements of the array are negated, not the rest of its data
     fn neg(mut self) -> Self::Output {
-        for idx in 0..self.vec.len() {
-            self.vec.to_mut()[idx] = -(self.vec[idx].clone());
-        }
+        self.map_inplace(|val| -(val.clone()));
 
         self
     }
 }
 
-impl<'a, 'b, T: Clone + Add<Output = T>, const D: usize> Add<&Array<'b, T, D>>
-    for &Array<'a, T, D>
-{
-    type Output = Array<'a, T, D>;
-
-    fn add(self, rhs: &Array<'b, T, D>) -> Self::Output {
-        (0..D).for_each(|axis| {
-            if self.shape[axis] != rhs.shape[axis] {
-                panic!(
-                    "Shape of array at axis: {} is not equall to right hand side: {} != {}",
-                    axis, self.shape[axis], rhs.shape[axis]
-                )
-            }
-        });
+// Marks the types that can be the right hand side of an operation with an array, user
+// defined numbers opt in with an empty impl. Coherence keeps the operations with the
+// scalar on the left hand side limited to the types of this crate.
+pub trait Scalar: Clone {}
 
-        let sum_vec = self
-            .flat()
-            .zip(rhs.flat())
-            .map(|(v1, v2)| v1.clone() + v2.clone())
-            .collect();
-
-        Array::init(sum_vec, self.shape().clone())
-    }
+macro_rules! impl_scalar {
+    ($($ty:ty),*) => {
+        $(impl Scalar for $ty {})*
+    };
 }
 
-impl<'a, 'b, T: Clone + Add<Output = T>, const D: usize> Add<Array<'b, T, D>> for Array<'a, T, D> {
-    type Output = Array<'a, T, D>;
+impl_scalar!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+impl_scalar!(Complex<f32>, Complex<f64>);
+#[cfg(feature = "half")]
+impl_scalar!(half::f16, half::bf16);
+
+macro_rules! impl_array_op {
+    ($op_trait:ident, $op_fn:ident, $op:tt) => {
+        impl<T, S1, S2, const D: usize> $op_trait<&ArrayBase<S2, D>> for &ArrayBase<S1, D>
+        where
+            T: Clone + $op_trait<Output = T>,
+            S1: Storage<Elem = T>,
+            S2: Storage<Elem = T>,
+        {
+            type Output = Array<T, D>;
+
+            fn $op_fn(self, rhs: &ArrayBase<S2, D>) -> Self::Output {
+                #[cfg(feature = "simd")]
+                if let Some(result) =
+                    super::simd::try_binary_op(self, rhs, super::simd::BinaryOp::$op_trait)
+                {
+                    return result;
+                }
+
+                let (lhs, rhs) = broadcast_pair(self, rhs);
+
+                let vec = lhs
+                    .flat()
+                    .zip(rhs.flat())
+                    .map(|(v1, v2)| v1.clone() $op v2.clone())
+                    .collect();
+
+                Array::init(vec, *lhs.shape())
+            }
+        }
 
-    fn add(self, rhs: Array<'b, T, D>) -> Self::Output {
-        &self + &rhs
-    }
-}
+        impl<T, S1, S2, const D: usize> $op_trait<ArrayBase<S2, D>> for &ArrayBase<S1, D>
+        where
+            T: Clone + $op_trait<Output = T>,
+            S1: Storage<Elem = T>,
+            S2: Storage<Elem = T>,
+        {
+            type Output = Array<T, D>;
 
-impl<'a, 'b, T: Clone + Sub<Output = T>, const D: usize> Sub<&Array<'b, T, D>>
-    for &Array<'a, T, D>
-{
-    type Output = Array<'a, T, D>;
-
-    fn sub(self, rhs: &Array<'b, T, D>) -> Self::Output {
-        (0..D).for_each(|axis| {
-            if self.shape[axis] != rhs.shape[axis] {
-                panic!(
-                    "Shape of array at axis: {} is not equall to right hand side: {} != {}",
-                    axis, self.shape[axis], rhs.shape[axis]
-                )
+            fn $op_fn(self, rhs: ArrayBase<S2, D>) -> Self::Output {
+                self $op &rhs
             }
-        });
+        }
 
-        let sum_vec = self
-            .flat()
-            .zip(rhs.flat())
-            .map(|(v1, v2)| v1.clone() - v2.clone())
-            .collect();
+        impl<T, S1, S2, const D: usize> $op_trait<&ArrayBase<S2, D>> for ArrayBase<S1, D>
+        where
+            T: Clone + $op_trait<Output = T>,
+            S1: Storage<Elem = T>,
+            S2: Storage<Elem = T>,
+        {
+            type Output = Array<T, D>;
 
-        Array::init(sum_vec, self.shape().clone())
-    }
-}
+            fn $op_fn(self, rhs: &ArrayBase<S2, D>) -> Self::Output {
+                &self $op rhs
+            }
+        }
 
-impl<'a, 'b, T: Clone + Sub<Output = T>, const D: usize> Sub<Array<'b, T, D>> for Array<'a, T, D> {
-    type Output = Array<'a, T, D>;
+        impl<T, S1, S2, const D: usize> $op_trait<ArrayBase<S2, D>> for ArrayBase<S1, D>
+        where
+            T: Clone + $op_trait<Output = T>,
+            S1: Storage<Elem = T>,
+            S2: Storage<Elem = T>,
+        {
+            type Output = Array<T, D>;
 
-    fn sub(self, rhs: Array<'b, T, D>) -> Self::Output {
-        &self - &rhs
-    }
+            fn $op_fn(self, rhs: ArrayBase<S2, D>) -> Self::Output {
+                &self $op &rhs
+            }
+        }
+    };
 }
 
-impl<'a, U: Clone, O: 'a + Clone, T: Clone + Mul<U, Output = O>, const D: usize> Mul<U>
-    for &Array<'a, T, D>
-{
-    type Output = Array<'a, O, D>;
-
-    fn mul(self, rhs: U) -> Self::Output {
-        let vec: Vec<O> = self
-            .vec
-            .iter()
-            .map(|val| val.clone() * rhs.clone())
-            .collect();
-
-        Array {
-            vec: Cow::from(vec),
-            shape: self.shape().clone(),
-            strides: self.strides().clone(),
-            idx_maps: self.idx_maps.clone(),
+impl_array_op!(Add, add, +);
+impl_array_op!(Sub, sub, -);
+impl_array_op!(Mul, mul, *);
+impl_array_op!(Div, div, /);
+
+macro_rules! impl_scalar_op {
+    ($op_trait:ident, $op_fn:ident, $op:tt) => {
+        impl<U, O, T, S, const D: usize> $op_trait<U> for &ArrayBase<S, D>
+        where
+            U: Scalar,
+            T: Clone + $op_trait<U, Output = O>,
+            S: Storage<Elem = T>,
+        {
+            type Output = Array<O, D>;
+
+            fn $op_fn(self, rhs: U) -> Self::Output {
+                self.map(|val| val.clone() $op rhs.clone())
+            }
         }
-    }
+
+        impl<U, O, T, S, const D: usize> $op_trait<U> for ArrayBase<S, D>
+        where
+            U: Scalar,
+            T: Clone + $op_trait<U, Output = O>,
+            S: Storage<Elem = T>,
+        {
+            type Output = Array<O, D>;
+
+            fn $op_fn(self, rhs: U) -> Self::Output {
+                (&self).$op_fn(rhs)
+            }
+        }
+    };
 }
 
-impl<'a, U: Clone, O: 'a + Clone, T: Clone + Mul<U, Output = O>, const D: usize> Mul<U>
-    for Array<'a, T, D>
-{
-    type Output = Array<'a, O, D>;
+impl_scalar_op!(Add, add, +);
+impl_scalar_op!(Sub, sub, -);
+impl_scalar_op!(Mul, mul, *);
+impl_scalar_op!(Div, div, /);
 
-    fn mul(self, rhs: U) -> Self::Output {
-        (&self).mul(rhs)
-    }
+macro_rules! impl_scalar_lhs_op {
+    ($op_trait:ident, $op_fn:ident, $op:tt, $($ty:ty),*) => {
+        $(
+            impl<S: Storage<Elem = $ty>, const D: usize> $op_trait<&ArrayBase<S, D>> for $ty {
+                type Output = Array<$ty, D>;
+
+                fn $op_fn(self, rhs: &ArrayBase<S, D>) -> Self::Output {
+                    rhs.map(|val| self $op *val)
+                }
+            }
+
+            impl<S: Storage<Elem = $ty>, const D: usize> $op_trait<ArrayBase<S, D>> for $ty {
+                type Output = Array<$ty, D>;
+
+                fn $op_fn(self, rhs: ArrayBase<S, D>) -> Self::Output {
+                    self $op &rhs
+                }
+            }
+        )*
+    };
 }
 
-impl<'a, U: Clone, O: 'a + Clone, T: Clone + Div<U, Output = O>, const D: usize> Div<U>
-    for &Array<'a, T, D>
-{
-    type Output = Array<'a, O, D>;
-
-    fn div(self, rhs: U) -> Self::Output {
-        let vec: Vec<O> = self
-            .vec
-            .iter()
-            .map(|val| val.clone() / rhs.clone())
-            .collect();
-
-        Array {
-            vec: Cow::from(vec),
-            shape: self.shape().clone(),
-            strides: self.strides().clone(),
-            idx_maps: self.idx_maps.clone(),
-        }
-    }
+macro_rules! impl_scalar_lhs_ops {
+    ($($ty:ty),*) => {
+        impl_scalar_lhs_op!(Add, add, +, $($ty),*);
+        impl_scalar_lhs_op!(Sub, sub, -, $($ty),*);
+        impl_scalar_lhs_op!(Mul, mul, *, $($ty),*);
+        impl_scalar_lhs_op!(Div, div, /, $($ty),*);
+    };
 }
 
-impl<'a, U: Clone, O: 'a + Clone, T: Clone + Div<U, Output = O>, const D: usize> Div<U>
-    for Array<'a, T, D>
-{
-    type Output = Array<'a, O, D>;
+impl_scalar_lhs_ops!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+impl_scalar_lhs_ops!(Complex<f32>, Complex<f64>);
+#[cfg(feature = "half")]
+impl_scalar_lhs_ops!(half::f16, half::bf16);
+
+macro_rules! impl_assign_op {
+    ($op_trait:ident, $op_fn:ident, $op:tt) => {
+        impl<T, S1, S2, const D: usize> $op_trait<&ArrayBase<S2, D>> for ArrayBase<S1, D>
+        where
+            T: Clone + $op_trait,
+            S1: StorageMut<Elem = T>,
+            S2: Storage<Elem = T>,
+        {
+            fn $op_fn(&mut self, rhs: &ArrayBase<S2, D>) {
+                let rhs = rhs
+                    .broadcast_to(self.shape)
+                    .unwrap_or_else(|error| panic!("{}", error));
+
+                for (val, rhs_val) in self.flat_mut().zip(rhs.flat()) {
+                    *val $op rhs_val.clone();
+                }
+            }
+        }
 
-    fn div(self, rhs: U) -> Self::Output {
-        (&self).div(rhs)
-    }
+        impl<T, S1, S2, const D: usize> $op_trait<ArrayBase<S2, D>> for ArrayBase<S1, D>
+        where
+            T: Clone + $op_trait,
+            S1: StorageMut<Elem = T>,
+            S2: Storage<Elem = T>,
+        {
+            fn $op_fn(&mut self, rhs: ArrayBase<S2, D>) {
+                *self $op &rhs;
+            }
+        }
+
+        impl<U, T, S, const D: usize> $op_trait<U> for ArrayBase<S, D>
+        where
+            U: Scalar,
+            T: $op_trait<U>,
+            S: StorageMut<Elem = T>,
+        {
+            fn $op_fn(&mut self, rhs: U) {
+                for val in self.flat_mut() {
+                    *val $op rhs.clone();
+                }
+            }
+        }
+    };
 }
 
+impl_assign_op!(AddAssign, add_assign, +=);
+impl_assign_op!(SubAssign, sub_assign, -=);
+impl_assign_op!(MulAssign, mul_assign, *=);
+impl_assign_op!(DivAssign, div_assign, /=);
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::s;
 
     #[test]
     fn neg() {
@@ -162,6 +246,13 @@ mod tests {
         );
     }
 
+    #[test]
+    fn neg_ignores_hidden_elements() {
+        let array = Array::init(vec![i32::MIN, 1, 2, 3], [4]).slice_move(&s![1..]);
+
+        assert_eq!(-array, Array::init(vec![-1, -2, -3], [3]));
+    }
+
     #[test]
     fn add() {
         let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
@@ -211,4 +302,211 @@ mod tests {
             vec![1, 2, 3, 4, 5, 6]
         );
     }
+
+    #[test]
+    fn add_scalar() {
+        let array: Array<i32, 2> = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        assert_eq!(
+            (&array + 5).flat().copied().collect::<Vec<i32>>(),
+            vec![6, 7, 8, 9, 10, 11]
+        );
+        assert_eq!(
+            (5 + &array).flat().copied().collect::<Vec<i32>>(),
+            vec![6, 7, 8, 9, 10, 11]
+        );
+    }
+
+    #[test]
+    fn sub_scalar() {
+        let array: Array<i32, 2> = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        assert_eq!(
+            (&array - 1).flat().copied().collect::<Vec<i32>>(),
+            vec![0, 1, 2, 3, 4, 5]
+        );
+        assert_eq!(
+            (10 - &array).flat().copied().collect::<Vec<i32>>(),
+            vec![9, 8, 7, 6, 5, 4]
+        );
+    }
+
+    #[test]
+    fn scalar_lhs() {
+        // 2-D array:
+        // 1 2 3
+        // 4 5 6
+        let array: Array<f64, 2> = Array::init(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], [2, 3]);
+
+        assert_eq!(
+            (2.0 * array.t()).flat().copied().collect::<Vec<f64>>(),
+            vec![2.0, 8.0, 4.0, 10.0, 6.0, 12.0]
+        );
+        assert_eq!(
+            (12.0 / &array).flat().copied().collect::<Vec<f64>>(),
+            vec![12.0, 6.0, 4.0, 3.0, 2.4, 2.0]
+        );
+    }
+
+    #[test]
+    fn mul_arrays() {
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+        let array2 = Array::init(vec![6, 5, 4, 3, 2, 1], [2, 3]);
+
+        let mul_array = &array * &array2;
+
+        assert_eq!(
+            mul_array.flat().copied().collect::<Vec<i32>>(),
+            vec![6, 10, 12, 12, 10, 6]
+        );
+    }
+
+    #[test]
+    fn div_arrays() {
+        let array = Array::init(vec![6, 10, 12, 12, 10, 6], [2, 3]);
+        let array2 = Array::init(vec![6, 5, 4, 3, 2, 1], [2, 3]);
+
+        let div_array = &array / &array2;
+
+        assert_eq!(
+            div_array.flat().copied().collect::<Vec<i32>>(),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+    }
+
+    #[test]
+    fn add_views() {
+        // 2-D array:
+        // 1 2 3
+        // 4 5 6
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        // 1 4    1 2    2 6
+        // 2 5 +  3 4  = 5 9
+        // 3 6    5 6    8 12
+        let sum_array = array.t() + array.reshape([3, 2]).unwrap();
+
+        assert_eq!(
+            sum_array.flat().copied().collect::<Vec<i32>>(),
+            vec![2, 6, 5, 9, 8, 12]
+        );
+    }
+
+    #[test]
+    fn add_broadcast() {
+        // 0      0 1 2 3    0 1 2 3
+        // 10  +           = 10 11 12 13
+        // 20                20 21 22 23
+        let column = Array::init(vec![0, 10, 20], [3, 1]);
+        let row = Array::init(vec![0, 1, 2, 3], [1, 4]);
+
+        let sum_array = &column + &row;
+
+        assert_eq!(sum_array.shape(), &[3, 4]);
+        assert_eq!(
+            sum_array.flat().copied().collect::<Vec<i32>>(),
+            vec![0, 1, 2, 3, 10, 11, 12, 13, 20, 21, 22, 23]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_shape_mismatch() {
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+        let array2 = Array::init(vec![1, 2, 3, 4, 5, 6], [3, 2]);
+
+        let _ = &array + &array2;
+    }
+
+    #[test]
+    fn add_assign() {
+        let mut array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+        let array2 = Array::init(vec![6, 5, 4, 3, 2, 1], [2, 3]);
+
+        array += &array2;
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<i32>>(),
+            vec![7, 7, 7, 7, 7, 7]
+        );
+    }
+
+    #[test]
+    fn sub_assign_broadcast() {
+        let mut array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+        let row = Array::init(vec![1, 2, 3], [1, 3]);
+
+        array -= row;
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<i32>>(),
+            vec![0, 0, 0, 3, 3, 3]
+        );
+    }
+
+    #[test]
+    fn assign_scalar() {
+        let mut array: Array<i32, 2> = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]).transpose();
+
+        array *= 4;
+        array /= 2;
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<i32>>(),
+            vec![2, 8, 4, 10, 6, 12]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_assign_shape_mismatch() {
+        let mut array = Array::init(vec![1, 2, 3], [1, 3]);
+        let array2 = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        array += &array2;
+    }
+
+    #[test]
+    fn assign_through_view_mut() {
+        // 0 0 0 0
+        // 0 0 0 0
+        // 0 0 0 0
+        let mut array = Array::init(vec![0; 12], [3, 4]);
+        let tile = Array::init(vec![1, 2, 3, 4], [2, 2]);
+
+        let mut view = array.slice_mut(&[1..3, 2..4]);
+        view += &tile;
+        view *= 10;
+
+        let mut column = array.slice_axis_mut(1, 0..1);
+        column -= 1;
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<i32>>(),
+            vec![-1, 0, 0, 0, -1, 0, 10, 20, -1, 0, 30, 40]
+        );
+    }
+
+    #[test]
+    fn borrowed_elements() {
+        // Elements that borrow their unit
+        #[derive(Clone, Debug, PartialEq)]
+        struct Quantity<'a>(i32, &'a str);
+
+        impl<'a> Add for Quantity<'a> {
+            type Output = Quantity<'a>;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                Quantity(self.0 + rhs.0, self.1)
+            }
+        }
+
+        let unit = String::from("m");
+        let array = Array::from_shape_fn([2], |[i]| Quantity(i as i32, &unit));
+
+        assert_eq!(
+            &array + &array,
+            Array::init(vec![Quantity(0, &unit), Quantity(2, &unit)], [2])
+        );
+    }
 }
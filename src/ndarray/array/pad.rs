@@ -0,0 +1,159 @@
+use super::iter::Indices;
+use super::len_of;
+use crate::{Array, ArrayBase, ShapeError, Storage};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PadMode<T> {
+    Constant(T),
+    Edge,
+    Reflect,
+}
+
+impl<T: Clone, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn pad(&self, widths: [(usize, usize); D], mode: PadMode<T>) -> Array<T, D> {
+        let mut shape = self.shape;
+        for (axis, (before, after)) in widths.iter().enumerate() {
+            if self.shape[axis] == 0 && before + after > 0 && !matches!(mode, PadMode::Constant(_))
+            {
+                panic!("Can not pad empty axis {} by repeating its elements", axis);
+            }
+
+            shape[axis] = before
+                .checked_add(*after)
+                .and_then(|width| width.checked_add(shape[axis]))
+                .unwrap_or_else(|| panic!("{}", ShapeError::Overflow));
+        }
+
+        let data = self.data.as_slice();
+        let mut vec = Vec::with_capacity(len_of(&shape));
+
+        let mut out_indices = Indices::init(shape);
+        'outer: while let Some(out_idx) = out_indices.next_front() {
+            let mut indices = [0; D];
+
+            for axis in 0..D {
+                let idx = out_idx[axis] as isize - widths[axis].0 as isize;
+
+                match source_index(idx, self.shape[axis], &mode) {
+                    Some(idx) => indices[axis] = idx,
+                    None => {
+                        if let PadMode::Constant(val) = &mode {
+                            vec.push(val.clone());
+                        }
+
+                        continue 'outer;
+                    }
+                }
+            }
+
+            vec.push(data[self.offset_of(&indices)].clone());
+        }
+
+        Array::init(vec, shape)
+    }
+}
+
+// Maps an index of the padded axis (relative to the start of the original axis) back
+// to an index of the original axis, or `None` if it must be filled with a constant.
+fn source_index<T>(idx: isize, len: usize, mode: &PadMode<T>) -> Option<usize> {
+    let len = len as isize;
+
+    if (0..len).contains(&idx) {
+        return Some(idx as usize);
+    }
+
+    match mode {
+        PadMode::Constant(_) => None,
+        PadMode::Edge => Some(idx.clamp(0, len - 1) as usize),
+        PadMode::Reflect => {
+            if len == 1 {
+                return Some(0);
+            }
+
+            let period = 2 * (len - 1);
+            let idx = idx.rem_euclid(period);
+
+            Some(if idx < len { idx } else { period - idx } as usize)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pad_constant() {
+        // 1 2
+        // 3 4
+        let array = Array::init(vec![1, 2, 3, 4], [2, 2]);
+
+        // 0 0 0 0 0
+        // 0 1 2 0 0
+        // 0 3 4 0 0
+        let padded = array.pad([(1, 0), (1, 2)], PadMode::Constant(0));
+
+        assert_eq!(padded.shape(), &[3, 5]);
+        assert_eq!(
+            padded.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 3, 4, 0, 0]
+        );
+    }
+
+    #[test]
+    fn pad_edge() {
+        let array = Array::init(vec![1, 2, 3], [3]);
+
+        let padded = array.pad([(2, 1)], PadMode::Edge);
+
+        assert_eq!(
+            padded.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 1, 1, 2, 3, 3]
+        );
+    }
+
+    #[test]
+    fn pad_reflect() {
+        let array = Array::init(vec![1, 2, 3], [3]);
+
+        assert_eq!(
+            array
+                .pad([(2, 2)], PadMode::Reflect)
+                .flat()
+                .copied()
+                .collect::<Vec<usize>>(),
+            vec![3, 2, 1, 2, 3, 2, 1]
+        );
+        assert_eq!(
+            array
+                .pad([(5, 0)], PadMode::Reflect)
+                .flat()
+                .copied()
+                .collect::<Vec<usize>>(),
+            vec![2, 1, 2, 3, 2, 1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn pad_reflect_2d() {
+        // 1 2 3
+        // 4 5 6
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        let flipped = array.flip(0);
+        let padded = flipped.pad([(1, 0), (0, 1)], PadMode::Reflect);
+
+        assert_eq!(
+            padded.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 2, 3, 2, 4, 5, 6, 5, 1, 2, 3, 2]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn pad_empty_edge() {
+        let array = Array::<usize, 1>::init(vec![], [0]);
+
+        array.pad([(1, 1)], PadMode::Edge);
+    }
+}
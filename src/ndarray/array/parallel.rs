@@ -0,0 +1,738 @@
+use std::marker::PhantomData;
+use std::ops::{Add, Range};
+
+use num_traits::Zero;
+
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+use rayon::slice::ParallelSliceMut;
+
+use super::calc::pairwise_sum;
+use super::iter::{AxisView, Iter, IterMut};
+use super::len_of;
+use crate::{Array, ArrayBase, Storage, StorageMut, View, ViewMut};
+
+impl<T: Sync, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn par_flat(&self) -> ParIter<'_, T, D> {
+        ParIter { view: self.view() }
+    }
+
+    pub fn par_mapv<U, F>(&self, f: F) -> Array<U, D>
+    where
+        T: Clone,
+        U: Send,
+        F: Fn(T) -> U + Sync + Send,
+    {
+        let mut vec = Vec::with_capacity(len_of(&self.shape));
+        self.par_flat()
+            .with_min_len(MIN_CHUNK_LEN)
+            .map(|val| f(val.clone()))
+            .collect_into_vec(&mut vec);
+
+        Array::init(vec, self.shape)
+    }
+
+    pub fn par_axis_view(&self, axis: usize) -> ParAxisView<'_, T, D> {
+        ParAxisView {
+            iter: self.axis_view(axis),
+        }
+    }
+}
+
+impl<T: Send, S: StorageMut<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn par_flat_mut(&mut self) -> ParIterMut<'_, T, D> {
+        ParIterMut {
+            view: ArrayBase {
+                data: self.data.as_mut_slice().as_mut_ptr(),
+                shape: self.shape,
+                strides: self.strides,
+                offset: self.offset,
+            },
+            marker: PhantomData,
+        }
+    }
+
+    pub fn par_map_inplace<F: Fn(&T) -> T + Sync + Send>(&mut self, f: F) {
+        // Rows of a contiguous array are split into chunks of the buffer, other layouts
+        // are split by position in row-major order and walked with their strides.
+        match self.contiguous_range() {
+            Some(range) => self.data.as_mut_slice()[range]
+                .par_chunks_mut(MIN_CHUNK_LEN)
+                .for_each(|chunk| chunk.iter_mut().for_each(|val| *val = f(val))),
+            None => self
+                .par_flat_mut()
+                .with_min_len(MIN_CHUNK_LEN)
+                .for_each(|val| *val = f(val)),
+        }
+    }
+
+    // Every subview gets its own part of the buffer, so the subviews along `axis` must not
+    // interleave in memory, like the rows of a row-major array or the columns of a
+    // column-major one do not.
+    pub fn par_axis_view_mut(&mut self, axis: usize) -> ParAxisViewMut<'_, T, D> {
+        if axis >= D {
+            panic!("Axis out of bound: {} > {}", axis, D);
+        }
+
+        // Number of positions of the buffer between the first and last element of a subview
+        let span = if self.shape.contains(&0) {
+            0
+        } else {
+            (0..D)
+                .filter(|&other| other != axis)
+                .map(|other| self.strides[other].unsigned_abs() * (self.shape[other] - 1))
+                .sum::<usize>()
+                + 1
+        };
+
+        if self.shape[axis] > 1 && self.strides[axis].unsigned_abs() < span {
+            panic!(
+                "Subviews along axis {} interleave in memory and can not be borrowed mutably in parallel",
+                axis
+            );
+        }
+
+        ParAxisViewMut {
+            views: AxisViewsMut {
+                data: self.data.as_mut_slice(),
+                shape: self.shape,
+                strides: self.strides,
+                offset: self.offset,
+                axis,
+            },
+        }
+    }
+}
+
+// Below this many elements per task the scheduling costs more than the work saved
+const MIN_CHUNK_LEN: usize = 4096;
+
+// Reductions halve the positions until they reach chunks of `MIN_CHUNK_LEN` elements and
+// combine the partial results pairwise. The tree only depends on the number of elements,
+// not on the number of threads or on which thread ran first, so float sums are the same
+// on every run.
+impl<T: Sync, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn par_fold<B, I, F, C>(&self, init: I, fold: F, combine: C) -> B
+    where
+        B: Send,
+        I: Fn() -> B + Sync,
+        F: Fn(B, &T) -> B + Sync,
+        C: Fn(B, B) -> B + Sync,
+    {
+        tree_reduce(
+            self.view(),
+            0..len_of(&self.shape),
+            &|iter| iter.fold(init(), &fold),
+            &combine,
+        )
+    }
+
+    pub fn par_reduce<F>(&self, f: F) -> Option<T>
+    where
+        T: Clone + Send,
+        F: Fn(T, T) -> T + Sync,
+    {
+        self.par_fold(
+            || None,
+            |acc, val| match acc {
+                Some(acc) => Some(f(acc, val.clone())),
+                None => Some(val.clone()),
+            },
+            |lhs, rhs| match (lhs, rhs) {
+                (Some(lhs), Some(rhs)) => Some(f(lhs, rhs)),
+                (lhs, rhs) => lhs.or(rhs),
+            },
+        )
+    }
+
+    pub fn par_sum(&self) -> T
+    where
+        T: Clone + Add<Output = T> + Zero + Send,
+    {
+        tree_reduce(
+            self.view(),
+            0..len_of(&self.shape),
+            &|iter| pairwise_sum(iter.cloned()),
+            &|lhs, rhs| lhs + rhs,
+        )
+    }
+
+    pub fn par_max(&self) -> Option<T>
+    where
+        T: Clone + PartialOrd + Send,
+    {
+        self.par_reduce(|max, val| if val <= max { max } else { val })
+    }
+
+    pub fn par_min(&self) -> Option<T>
+    where
+        T: Clone + PartialOrd + Send,
+    {
+        self.par_reduce(|min, val| if val >= min { min } else { val })
+    }
+}
+
+fn tree_reduce<'a, T, B, L, C, const D: usize>(
+    view: View<'a, T, D>,
+    range: Range<usize>,
+    leaf: &L,
+    combine: &C,
+) -> B
+where
+    T: Sync,
+    B: Send,
+    L: Fn(Iter<'a, T, D>) -> B + Sync,
+    C: Fn(B, B) -> B + Sync,
+{
+    if range.len() <= MIN_CHUNK_LEN {
+        return leaf(Iter::init_range(view, range));
+    }
+
+    let mid = range.start + range.len() / 2;
+    let (lhs, rhs) = rayon::join(
+        || tree_reduce(view, range.start..mid, leaf, combine),
+        || tree_reduce(view, mid..range.end, leaf, combine),
+    );
+
+    combine(lhs, rhs)
+}
+
+// Visits the elements in row-major order like `flat`. The threads split the positions of
+// that order between them, so non-contiguous views are split as evenly as contiguous ones.
+pub struct ParIter<'a, T, const D: usize> {
+    view: View<'a, T, D>,
+}
+
+impl<'a, T: Sync, const D: usize> ParallelIterator for ParIter<'a, T, D> {
+    type Item = &'a T;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<'a, T: Sync, const D: usize> IndexedParallelIterator for ParIter<'a, T, D> {
+    fn len(&self) -> usize {
+        len_of(&self.view.shape)
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(FlatProducer {
+            view: self.view,
+            range: 0..self.len(),
+        })
+    }
+}
+
+struct FlatProducer<'a, T, const D: usize> {
+    view: View<'a, T, D>,
+    range: Range<usize>,
+}
+
+impl<'a, T: Sync, const D: usize> Producer for FlatProducer<'a, T, D> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, D>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter::init_range(self.view, self.range)
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let mid = self.range.start + index;
+
+        (
+            FlatProducer {
+                view: self.view,
+                range: self.range.start..mid,
+            },
+            FlatProducer {
+                view: self.view,
+                range: mid..self.range.end,
+            },
+        )
+    }
+}
+
+pub struct ParIterMut<'a, T, const D: usize> {
+    view: ArrayBase<*mut T, D>,
+    marker: PhantomData<&'a mut T>,
+}
+
+// Safety: the iterator stands for a mutable borrow of the elements, which can be sent
+// to another thread as long as the elements can.
+unsafe impl<'a, T: Send, const D: usize> Send for ParIterMut<'a, T, D> {}
+
+impl<'a, T: Send, const D: usize> ParallelIterator for ParIterMut<'a, T, D> {
+    type Item = &'a mut T;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<'a, T: Send, const D: usize> IndexedParallelIterator for ParIterMut<'a, T, D> {
+    fn len(&self) -> usize {
+        len_of(&self.view.shape)
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(FlatProducerMut {
+            view: self.view,
+            range: 0..self.len(),
+            marker: PhantomData,
+        })
+    }
+}
+
+struct FlatProducerMut<'a, T, const D: usize> {
+    view: ArrayBase<*mut T, D>,
+    range: Range<usize>,
+    marker: PhantomData<&'a mut T>,
+}
+
+// Safety: producers are only ever split into disjoint ranges of positions
+unsafe impl<'a, T: Send, const D: usize> Send for FlatProducerMut<'a, T, D> {}
+
+impl<'a, T: Send, const D: usize> Producer for FlatProducerMut<'a, T, D> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T, D>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        // Safety: the range of this producer is disjoint from the ranges of all others
+        unsafe { IterMut::init_range(self.view, self.range) }
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let mid = self.range.start + index;
+
+        (
+            FlatProducerMut {
+                view: self.view,
+                range: self.range.start..mid,
+                marker: PhantomData,
+            },
+            FlatProducerMut {
+                view: self.view,
+                range: mid..self.range.end,
+                marker: PhantomData,
+            },
+        )
+    }
+}
+
+pub struct ParAxisView<'a, T, const D: usize> {
+    iter: AxisView<'a, T, D>,
+}
+
+impl<'a, T: Sync, const D: usize> ParallelIterator for ParAxisView<'a, T, D> {
+    type Item = View<'a, T, D>;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<'a, T: Sync, const D: usize> IndexedParallelIterator for ParAxisView<'a, T, D> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(self.iter)
+    }
+}
+
+impl<'a, T: Sync, const D: usize> Producer for AxisView<'a, T, D> {
+    type Item = View<'a, T, D>;
+    type IntoIter = Self;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        AxisView::split_at(self, index)
+    }
+}
+
+pub struct ParAxisViewMut<'a, T, const D: usize> {
+    views: AxisViewsMut<'a, T, D>,
+}
+
+// The subviews still to be visited, with `shape[axis]` counting them. `data` only covers
+// their part of the buffer, which splitting cuts in two between neighbouring subviews.
+struct AxisViewsMut<'a, T, const D: usize> {
+    data: &'a mut [T],
+    shape: [usize; D],
+    strides: [isize; D],
+    offset: usize,
+    axis: usize,
+}
+
+impl<'a, T, const D: usize> AxisViewsMut<'a, T, D> {
+    // Position of the lowest element of the subview at `idx`
+    fn start_of(&self, idx: usize) -> isize {
+        let lowest: isize = (0..D)
+            .filter(|&axis| axis != self.axis && self.shape[axis] > 0)
+            .map(|axis| self.strides[axis].min(0) * (self.shape[axis] - 1) as isize)
+            .sum();
+
+        self.offset as isize + lowest + idx as isize * self.strides[self.axis]
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let len = self.shape[self.axis];
+        let stride = self.strides[self.axis];
+
+        // With a negative stride the first subviews sit at the end of the buffer
+        let cut = if stride >= 0 && index < len {
+            self.start_of(index)
+        } else if stride < 0 && index > 0 {
+            self.start_of(index - 1)
+        } else {
+            self.data.len() as isize
+        };
+        let cut = cut.clamp(0, self.data.len() as isize) as usize;
+
+        let (mut left_shape, mut right_shape) = (self.shape, self.shape);
+        left_shape[self.axis] = index;
+        right_shape[self.axis] = len - index;
+
+        let (low, high) = self.data.split_at_mut(cut);
+        let right_offset = self.offset as isize + index as isize * stride;
+        let (left, left_offset, right, right_offset) = if stride >= 0 {
+            (low, self.offset as isize, high, right_offset - cut as isize)
+        } else {
+            (high, self.offset as isize - cut as isize, low, right_offset)
+        };
+
+        // Empty halves may point anywhere, their offset is never used
+        (
+            AxisViewsMut {
+                data: left,
+                shape: left_shape,
+                strides: self.strides,
+                offset: left_offset.max(0) as usize,
+                axis: self.axis,
+            },
+            AxisViewsMut {
+                data: right,
+                shape: right_shape,
+                strides: self.strides,
+                offset: right_offset.max(0) as usize,
+                axis: self.axis,
+            },
+        )
+    }
+
+    fn take(&mut self) -> Self {
+        std::mem::replace(
+            self,
+            AxisViewsMut {
+                data: &mut [],
+                shape: self.shape,
+                strides: self.strides,
+                offset: 0,
+                axis: self.axis,
+            },
+        )
+    }
+
+    fn into_view(self) -> ViewMut<'a, T, D> {
+        ArrayBase {
+            data: self.data,
+            shape: self.shape,
+            strides: self.strides,
+            offset: self.offset,
+        }
+    }
+}
+
+impl<'a, T, const D: usize> Iterator for AxisViewsMut<'a, T, D> {
+    type Item = ViewMut<'a, T, D>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.shape[self.axis] == 0 {
+            return None;
+        }
+
+        let (first, rest) = self.take().split_at(1);
+        *self = rest;
+
+        Some(first.into_view())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.shape[self.axis], Some(self.shape[self.axis]))
+    }
+}
+
+impl<'a, T, const D: usize> DoubleEndedIterator for AxisViewsMut<'a, T, D> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let len = self.shape[self.axis];
+        if len == 0 {
+            return None;
+        }
+
+        let (rest, last) = self.take().split_at(len - 1);
+        *self = rest;
+
+        Some(last.into_view())
+    }
+}
+
+impl<'a, T, const D: usize> ExactSizeIterator for AxisViewsMut<'a, T, D> {}
+
+impl<'a, T: Send, const D: usize> ParallelIterator for ParAxisViewMut<'a, T, D> {
+    type Item = ViewMut<'a, T, D>;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<'a, T: Send, const D: usize> IndexedParallelIterator for ParAxisViewMut<'a, T, D> {
+    fn len(&self) -> usize {
+        self.views.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(self.views)
+    }
+}
+
+impl<'a, T: Send, const D: usize> Producer for AxisViewsMut<'a, T, D> {
+    type Item = ViewMut<'a, T, D>;
+    type IntoIter = Self;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        AxisViewsMut::split_at(self, index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{s, Array};
+
+    #[test]
+    fn par_flat() {
+        let array = Array::arange(0..1000).reshape([10, 100]).unwrap();
+
+        assert_eq!(array.par_flat().sum::<i32>(), array.sum());
+        assert_eq!(
+            array.par_flat().copied().collect::<Vec<_>>(),
+            array.flat().copied().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn par_flat_non_contiguous() {
+        let array = Array::arange(0..1000).reshape([10, 100]).unwrap();
+
+        for view in [array.t(), array.flip(1), array.slice(&s![1..9;3, 5..95;2])] {
+            assert_eq!(view.par_flat().len(), view.flat().len());
+            assert_eq!(
+                view.par_flat().with_min_len(7).copied().collect::<Vec<_>>(),
+                view.flat().copied().collect::<Vec<_>>()
+            );
+        }
+
+        let empty = array.slice(&s![3..3, ..]);
+
+        assert_eq!(empty.par_flat().count(), 0);
+    }
+
+    #[test]
+    fn par_mapv() {
+        let array = Array::arange(0..10000).reshape([100, 100]).unwrap();
+
+        assert_eq!(array.par_mapv(|val| val * 2), array.mapv(|val| val * 2));
+        assert_eq!(
+            array.t().par_mapv(|val| val as f64),
+            array.t().mapv(|val| val as f64)
+        );
+    }
+
+    #[test]
+    fn par_map_inplace() {
+        let mut array = Array::arange(0..10000).reshape([100, 100]).unwrap();
+
+        array.par_map_inplace(|val| val + 1);
+
+        assert_eq!(array, Array::arange(1..10001).reshape([100, 100]).unwrap());
+
+        array.slice_mut(&s![..;2, 10..90]).par_map_inplace(|_| 0);
+        array
+            .view_mut()
+            .reversed_axes()
+            .par_flat_mut()
+            .for_each(|val| *val *= -1);
+
+        let expected = Array::from_shape_fn([100, 100], |[i, j]| {
+            if i % 2 == 0 && (10..90).contains(&j) {
+                0
+            } else {
+                -((i * 100 + j) as i32 + 1)
+            }
+        });
+
+        assert_eq!(array, expected);
+    }
+
+    #[test]
+    fn par_axis_view() {
+        let array = Array::arange(0..60).reshape([3, 4, 5]).unwrap();
+
+        for axis in 0..3 {
+            let sums: Vec<i32> = array.par_axis_view(axis).map(|view| view.sum()).collect();
+
+            assert_eq!(sums, array.sum_across(axis));
+        }
+    }
+
+    #[test]
+    fn par_axis_view_mut() {
+        let batch = Array::from_shape_fn([8, 3, 5], |[i, j, k]| (i * 100 + j * 10 + k) as f64);
+
+        // Each image is normalized to zero mean, whichever way the batch is laid out
+        let normalize = |mut image: ViewMut<f64, 3>| {
+            let mean = image.mean();
+            image.map_inplace(|val| val - mean);
+        };
+        let expected = Array::from_shape_fn([8, 3, 5], |[_, j, k]| (j * 10 + k) as f64 - 12.0);
+
+        let mut array = batch.clone();
+        array.par_axis_view_mut(0).for_each(normalize);
+
+        assert_eq!(array, expected);
+
+        let mut flipped = batch.flip(0).to_owned();
+        flipped
+            .view_mut()
+            .flip_move(0)
+            .par_axis_view_mut(0)
+            .for_each(normalize);
+
+        assert_eq!(flipped.flip(0), expected);
+
+        // The batch axis comes last, but is still the outermost one in memory
+        let mut permuted = batch.clone();
+        permuted
+            .view_mut()
+            .permuted_axes_move([1, 2, 0])
+            .par_axis_view_mut(2)
+            .with_max_len(1)
+            .for_each(|mut image| {
+                let mean = image.mean();
+                image.map_inplace(|val| val - mean);
+            });
+
+        assert_eq!(permuted, expected);
+
+        let mut sliced = batch.clone();
+        sliced
+            .slice_mut(&s![1..;3, .., 1..4])
+            .par_axis_view_mut(0)
+            .for_each(|mut image| image.map_inplace(|_| 0.0));
+
+        let zeroed = Array::from_shape_fn([8, 3, 5], |[i, j, k]| {
+            if i % 3 == 1 && (1..4).contains(&k) {
+                0.0
+            } else {
+                batch[[i, j, k]]
+            }
+        });
+
+        assert_eq!(sliced, zeroed);
+    }
+
+    #[test]
+    #[should_panic]
+    fn par_axis_view_mut_interleaved() {
+        let mut array = Array::<i32, 2>::zeros([4, 4]);
+
+        array.par_axis_view_mut(1).for_each(|_| {});
+    }
+
+    #[test]
+    fn par_reductions() {
+        let array = Array::from_shape_fn([300, 300], |[i, j]| ((i * 300 + j) % 1000) as f64 * 0.1);
+
+        // The same tree is used whatever the number of threads
+        let sums: Vec<f64> = [1, 3, 8]
+            .iter()
+            .map(|&threads| {
+                rayon::ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .build()
+                    .unwrap()
+                    .install(|| array.t().par_sum())
+            })
+            .collect();
+
+        assert!(sums.iter().all(|sum| sum.to_bits() == sums[0].to_bits()));
+        assert!((sums[0] - array.sum()).abs() < 1e-6);
+
+        assert_eq!(array.flip(1).par_max(), Some(99.9));
+
+        let sliced = array.slice(&s![1..;7, 1..;7]);
+
+        assert_eq!(sliced.par_min(), sliced.min());
+        assert_eq!(
+            array.par_fold(
+                || 0,
+                |count, val| count + (*val > 50.0) as usize,
+                |lhs, rhs| lhs + rhs
+            ),
+            array.flat().filter(|val| **val > 50.0).count()
+        );
+        // Partial results are combined in order, so the operation only has to be associative
+        let array = Array::arange(1..20000u64);
+
+        assert_eq!(array.par_reduce(|lhs, _| lhs), Some(1));
+        assert_eq!(array.par_reduce(|_, rhs| rhs), Some(19999));
+
+        let empty = Array::<i32, 2>::zeros([0, 3]);
+
+        assert_eq!(empty.par_sum(), 0);
+        assert_eq!(empty.par_max(), None);
+        assert_eq!(empty.par_reduce(|lhs, rhs| lhs + rhs), None);
+    }
+}
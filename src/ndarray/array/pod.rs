@@ -0,0 +1,220 @@
+use std::error::Error;
+use std::fmt;
+use std::mem::size_of;
+
+use bytemuck::{Pod, PodCastError};
+
+use super::{checked_len, default_strides};
+use crate::{Array, ArrayBase, ShapeError, Storage, StorageMut, View, ViewMut};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CastError {
+    Pod(PodCastError),
+    Shape(ShapeError),
+}
+
+impl fmt::Display for CastError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CastError::Pod(error) => error.fmt(f),
+            CastError::Shape(error) => error.fmt(f),
+        }
+    }
+}
+
+impl Error for CastError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CastError::Pod(error) => Some(error),
+            CastError::Shape(error) => Some(error),
+        }
+    }
+}
+
+impl From<PodCastError> for CastError {
+    fn from(error: PodCastError) -> Self {
+        CastError::Pod(error)
+    }
+}
+
+impl From<ShapeError> for CastError {
+    fn from(error: ShapeError) -> Self {
+        CastError::Shape(error)
+    }
+}
+
+// Every row keeps its bytes, so the last axis grows or shrinks by the ratio of the sizes
+fn cast_shape<T, U, const D: usize>(mut shape: [usize; D]) -> Result<[usize; D], CastError> {
+    let (from, to) = (size_of::<T>(), size_of::<U>());
+
+    if from == 0 || to == 0 {
+        return if from == to {
+            Ok(shape)
+        } else {
+            Err(CastError::Pod(PodCastError::SizeMismatch))
+        };
+    }
+
+    match shape.last_mut() {
+        Some(last) if *last * from % to == 0 => {
+            *last = *last * from / to;
+
+            Ok(shape)
+        }
+        None if from == to => Ok(shape),
+        _ => Err(CastError::Pod(PodCastError::OutputSliceWouldHaveSlop)),
+    }
+}
+
+// Only arrays in row-major order can be reinterpreted, since their rows are the
+// only runs of bytes that another element type can tile.
+impl<T: Pod, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn cast<U: Pod>(&self) -> Result<View<'_, U, D>, CastError> {
+        let range = self
+            .contiguous_range()
+            .ok_or(ShapeError::IncompatibleLayout)?;
+        let shape = cast_shape::<T, U, D>(self.shape)?;
+
+        Ok(ArrayBase {
+            data: bytemuck::try_cast_slice(&self.data.as_slice()[range])?,
+            shape,
+            strides: default_strides(&shape),
+            offset: 0,
+        })
+    }
+
+    pub fn as_bytes(&self) -> Result<&[u8], ShapeError> {
+        let range = self
+            .contiguous_range()
+            .ok_or(ShapeError::IncompatibleLayout)?;
+
+        Ok(bytemuck::cast_slice(&self.data.as_slice()[range]))
+    }
+}
+
+impl<T: Pod, S: StorageMut<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn cast_mut<U: Pod>(&mut self) -> Result<ViewMut<'_, U, D>, CastError> {
+        let range = self
+            .contiguous_range()
+            .ok_or(ShapeError::IncompatibleLayout)?;
+        let shape = cast_shape::<T, U, D>(self.shape)?;
+
+        Ok(ArrayBase {
+            data: bytemuck::try_cast_slice_mut(&mut self.data.as_mut_slice()[range])?,
+            shape,
+            strides: default_strides(&shape),
+            offset: 0,
+        })
+    }
+}
+
+impl<'a, T: Pod, const D: usize> View<'a, T, D> {
+    // The bytes must be aligned for `T`, `Array::from_bytes` copies them otherwise
+    pub fn from_bytes(bytes: &'a [u8], shape: [usize; D]) -> Result<Self, CastError> {
+        let data: &[T] = bytemuck::try_cast_slice(bytes)?;
+        let expected = checked_len(&shape)?;
+
+        if data.len() != expected {
+            return Err(CastError::Shape(ShapeError::IncompatibleSize {
+                len: data.len(),
+                expected,
+            }));
+        }
+
+        Ok(ArrayBase {
+            data,
+            shape,
+            strides: default_strides(&shape),
+            offset: 0,
+        })
+    }
+}
+
+impl<T: Pod, const D: usize> Array<T, D> {
+    pub fn from_bytes(bytes: &[u8], shape: [usize; D]) -> Result<Self, CastError> {
+        if bytes.len() % size_of::<T>() != 0 {
+            return Err(CastError::Pod(PodCastError::OutputSliceWouldHaveSlop));
+        }
+
+        Ok(Array::try_init(bytemuck::pod_collect_to_vec(bytes), shape)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cast() {
+        let array = Array::init(vec![1.0f32, 2.0, 3.0, 4.0], [2, 2]);
+
+        let bytes = array.cast::<u8>().unwrap();
+
+        assert_eq!(bytes.shape(), &[2, 8]);
+        assert_eq!(bytes.cast::<f32>().unwrap(), array);
+        assert_eq!(array.as_bytes().unwrap(), bytes.data);
+
+        let halves = array.cast::<u16>().unwrap();
+
+        assert_eq!(halves.shape(), &[2, 4]);
+        assert_eq!(halves.cast::<f32>().unwrap(), array);
+        assert_eq!(
+            array.slice(&crate::s![..1, ..1]).cast::<u64>().err(),
+            Some(CastError::Pod(PodCastError::OutputSliceWouldHaveSlop))
+        );
+        assert_eq!(
+            array.t().cast::<u8>().err(),
+            Some(CastError::Shape(ShapeError::IncompatibleLayout))
+        );
+    }
+
+    #[test]
+    fn cast_mut() {
+        let mut array = Array::init(vec![0u32; 4], [4]);
+
+        array.cast_mut::<u8>().unwrap()[[4]] = 1;
+
+        assert_eq!(array[[1]], u32::from_ne_bytes([1, 0, 0, 0]));
+    }
+
+    #[test]
+    fn from_bytes() {
+        let bytes: Vec<u8> = [1.5f64, -2.0, 0.25]
+            .iter()
+            .flat_map(|value| value.to_ne_bytes())
+            .collect();
+
+        let array = Array::<f64, 1>::from_bytes(&bytes, [3]).unwrap();
+
+        assert_eq!(array, Array::init(vec![1.5, -2.0, 0.25], [3]));
+        assert_eq!(
+            View::<f64, 1>::from_bytes(array.as_bytes().unwrap(), [3]).unwrap(),
+            array
+        );
+        assert_eq!(
+            Array::<f64, 2>::from_bytes(&bytes, [2, 2]).err(),
+            Some(CastError::Shape(ShapeError::IncompatibleSize {
+                len: 3,
+                expected: 4
+            }))
+        );
+        assert_eq!(
+            Array::<f64, 1>::from_bytes(&bytes[1..], [3]).err(),
+            Some(CastError::Pod(PodCastError::OutputSliceWouldHaveSlop))
+        );
+
+        let words = Array::init(vec![0u32; 3], [3]);
+        let unaligned = &words.as_bytes().unwrap()[1..9];
+
+        assert_eq!(
+            View::<u32, 1>::from_bytes(unaligned, [2]).err(),
+            Some(CastError::Pod(
+                PodCastError::TargetAlignmentGreaterAndInputNotAligned
+            ))
+        );
+        assert_eq!(
+            Array::<u32, 1>::from_bytes(unaligned, [2]).unwrap(),
+            Array::init(vec![0, 0], [2])
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use rand::distributions::Distribution;
+use rand::Rng;
+
+use super::len_of;
+use crate::Array;
+
+impl<T, const D: usize> Array<T, D> {
+    pub fn random<Dist: Distribution<T>>(shape: [usize; D], distribution: Dist) -> Self {
+        Array::random_using(shape, distribution, &mut rand::thread_rng())
+    }
+
+    pub fn random_using<Dist, R>(shape: [usize; D], distribution: Dist, rng: &mut R) -> Self
+    where
+        Dist: Distribution<T>,
+        R: Rng + ?Sized,
+    {
+        let vec = distribution.sample_iter(rng).take(len_of(&shape)).collect();
+
+        Array::init(vec, shape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use rand_distr::{StandardNormal, Uniform};
+
+    use crate::Array;
+
+    #[test]
+    fn random_uniform() {
+        let array = Array::random([3, 4], Uniform::new(-1.0, 1.0));
+
+        assert_eq!(array.shape(), &[3, 4]);
+        assert!(array.flat().all(|val: &f64| (-1.0..1.0).contains(val)));
+    }
+
+    #[test]
+    fn random_using_seed() {
+        let lhs: Array<f32, 2> =
+            Array::random_using([2, 5], StandardNormal, &mut StdRng::seed_from_u64(7));
+        let rhs: Array<f32, 2> =
+            Array::random_using([2, 5], StandardNormal, &mut StdRng::seed_from_u64(7));
+
+        assert_eq!(
+            lhs.flat().collect::<Vec<&f32>>(),
+            rhs.flat().collect::<Vec<&f32>>()
+        );
+        assert!(lhs.flat().any(|val| *val != lhs[[0, 0]]));
+    }
+
+    #[test]
+    fn random_standard_normal() {
+        let array: Array<f64, 1> = Array::random([10_000], StandardNormal);
+
+        assert!(array.mean().abs() < 0.1);
+        assert!((array.std(0) - 1.0).abs() < 0.1);
+    }
+}
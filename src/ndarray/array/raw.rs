@@ -0,0 +1,249 @@
+use std::slice;
+
+use super::checked_len;
+use crate::{ArrayBase, ShapeError, View, ViewMut};
+
+// Offsets of the lowest and the highest element relative to the first one, `None` for
+// empty arrays
+fn span<const D: usize>(
+    shape: &[usize; D],
+    strides: &[isize; D],
+) -> Result<Option<(isize, isize)>, ShapeError> {
+    checked_len(shape)?;
+
+    if shape.contains(&0) {
+        return Ok(None);
+    }
+
+    let (mut lowest, mut highest) = (0isize, 0isize);
+    for axis in 0..D {
+        let last = (shape[axis] as isize - 1)
+            .checked_mul(strides[axis])
+            .ok_or(ShapeError::Overflow)?;
+
+        lowest = lowest
+            .checked_add(last.min(0))
+            .ok_or(ShapeError::Overflow)?;
+        highest = highest
+            .checked_add(last.max(0))
+            .ok_or(ShapeError::Overflow)?;
+    }
+
+    Ok(Some((lowest, highest)))
+}
+
+// Distinct indices are at distinct offsets if every stride steps over all the elements
+// reachable with the smaller ones
+pub(crate) fn is_unaliased<const D: usize>(shape: &[usize; D], strides: &[isize; D]) -> bool {
+    let mut axes: Vec<(usize, usize)> = (0..D)
+        .filter(|&axis| shape[axis] > 1)
+        .map(|axis| (shape[axis], strides[axis].unsigned_abs()))
+        .collect();
+    axes.sort_by_key(|&(_, stride)| stride);
+
+    let mut reach = 0usize;
+    for (len, stride) in axes {
+        if stride <= reach {
+            return false;
+        }
+
+        reach = match (len - 1)
+            .checked_mul(stride)
+            .and_then(|last| reach.checked_add(last))
+        {
+            Some(reach) => reach,
+            None => return false,
+        };
+    }
+
+    true
+}
+
+// The borrowed slice spans from the lowest to the highest element
+fn check_slice<const D: usize>(
+    len: usize,
+    shape: &[usize; D],
+    strides: &[isize; D],
+) -> Result<(), ShapeError> {
+    match span(shape, strides)? {
+        Some((lowest, _)) if lowest < 0 => Err(ShapeError::IncompatibleLayout),
+        Some((_, highest)) if highest as usize >= len => Err(ShapeError::IncompatibleSize {
+            len,
+            expected: highest as usize + 1,
+        }),
+        _ => Ok(()),
+    }
+}
+
+impl<'a, T, const D: usize> View<'a, T, D> {
+    // The element at `indices` is read at `ptr + Σ indices[axis] * strides[axis]`
+    /// # Safety
+    ///
+    /// Every element must be valid for reads and not be modified for `'a`, and the offset
+    /// of every element must fit in an isize.
+    pub unsafe fn from_raw_parts(ptr: *const T, shape: [usize; D], strides: [isize; D]) -> Self {
+        let (lowest, highest) = span(&shape, &strides)
+            .unwrap_or_else(|error| panic!("{}", error))
+            .unwrap_or((0, -1));
+
+        ArrayBase {
+            data: slice::from_raw_parts(ptr.offset(lowest), (highest - lowest + 1) as usize),
+            shape,
+            strides,
+            offset: (-lowest) as usize,
+        }
+    }
+
+    // The first element is the first one of `slice`, which has to hold every element.
+    // Elements may share a position, like in a sliding window. Such views are only read
+    // through, and everything that turns a view into an owned or writable array copies its
+    // elements into a layout without aliasing instead of keeping the strides.
+    pub fn from_slice_with_strides(
+        slice: &'a [T],
+        shape: [usize; D],
+        strides: [isize; D],
+    ) -> Result<Self, ShapeError> {
+        check_slice(slice.len(), &shape, &strides)?;
+
+        Ok(ArrayBase {
+            data: slice,
+            shape,
+            strides,
+            offset: 0,
+        })
+    }
+}
+
+impl<'a, T, const D: usize> ViewMut<'a, T, D> {
+    /// # Safety
+    ///
+    /// Every element must be valid for reads and writes and not be accessed otherwise for
+    /// `'a`, the offset of every element must fit in an isize and no two elements may be at
+    /// the same address.
+    pub unsafe fn from_raw_parts_mut(ptr: *mut T, shape: [usize; D], strides: [isize; D]) -> Self {
+        let (lowest, highest) = span(&shape, &strides)
+            .unwrap_or_else(|error| panic!("{}", error))
+            .unwrap_or((0, -1));
+
+        ArrayBase {
+            data: slice::from_raw_parts_mut(ptr.offset(lowest), (highest - lowest + 1) as usize),
+            shape,
+            strides,
+            offset: (-lowest) as usize,
+        }
+    }
+
+    // Like `View::from_slice_with_strides`, but elements may also not share a position of
+    // `slice`, since they could be written through two references at once
+    pub fn from_slice_with_strides_mut(
+        slice: &'a mut [T],
+        shape: [usize; D],
+        strides: [isize; D],
+    ) -> Result<Self, ShapeError> {
+        check_slice(slice.len(), &shape, &strides)?;
+
+        if !is_unaliased(&shape, &strides) {
+            return Err(ShapeError::IncompatibleLayout);
+        }
+
+        Ok(ArrayBase {
+            data: slice,
+            shape,
+            strides,
+            offset: 0,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Array, CowArray};
+
+    #[test]
+    fn strided_slices() {
+        let data: Vec<i32> = (0..12).collect();
+
+        // Every other column of a 3x4 matrix
+        let view = View::from_slice_with_strides(&data, [3, 2], [4, 2]).unwrap();
+
+        assert_eq!(view, Array::init(vec![0, 2, 4, 6, 8, 10], [3, 2]));
+
+        // A sliding window of length 3
+        let windows = View::from_slice_with_strides(&data, [10, 3], [1, 1]).unwrap();
+
+        assert_eq!(
+            windows.slice(&[9..10, 0..3]).flatten(),
+            Array::init(vec![9, 10, 11], [3])
+        );
+
+        assert_eq!(
+            View::from_slice_with_strides(&data, [3, 5], [4, 1]).unwrap_err(),
+            ShapeError::IncompatibleSize {
+                len: 12,
+                expected: 13
+            }
+        );
+        assert_eq!(
+            View::from_slice_with_strides(&data, [2], [-1]).unwrap_err(),
+            ShapeError::IncompatibleLayout
+        );
+        assert!(View::from_slice_with_strides(&data[..0], [0, 5], [5, 1]).is_ok());
+
+        let mut data = data;
+
+        assert!(ViewMut::from_slice_with_strides_mut(&mut data, [10, 3], [1, 1]).is_err());
+        assert!(ViewMut::from_slice_with_strides_mut(&mut data, [4], [0]).is_err());
+
+        let mut columns = ViewMut::from_slice_with_strides_mut(&mut data, [4, 3], [1, 4]).unwrap();
+        columns[[3, 0]] = -1;
+
+        assert_eq!(data[3], -1);
+    }
+
+    #[test]
+    fn overlapping_views_become_unaliased() {
+        let data = [1, 2, 3];
+
+        // 1 2
+        // 2 3
+        let view = View::from_slice_with_strides(&data, [2, 2], [1, 1]).unwrap();
+
+        let mut mapped = view.map(|val| val * 10);
+        let mut owned = view.to_owned();
+        let mut cow: CowArray<'_, i32, 2> = view.into();
+
+        mapped[[0, 1]] = 0;
+        owned[[0, 1]] = 0;
+        cow[[0, 1]] = 0;
+
+        assert_eq!(mapped, Array::init(vec![10, 0, 20, 30], [2, 2]));
+        assert_eq!(owned, Array::init(vec![1, 0, 2, 3], [2, 2]));
+        assert_eq!(cow.into_owned(), owned);
+        assert_eq!(view, Array::init(vec![1, 2, 2, 3], [2, 2]));
+    }
+
+    #[test]
+    fn raw_parts() {
+        let mut data: Vec<f64> = (0..6).map(f64::from).collect();
+
+        // Rows in reverse order, starting at the last one
+        let view = unsafe { View::from_raw_parts(data.as_ptr().add(3), [2, 3], [-3, 1]) };
+
+        assert_eq!(
+            view,
+            Array::init(vec![3.0, 4.0, 5.0, 0.0, 1.0, 2.0], [2, 3])
+        );
+
+        let mut view = unsafe { ViewMut::from_raw_parts_mut(data.as_mut_ptr(), [3, 2], [1, 3]) };
+        view[[2, 1]] = 50.0;
+
+        assert_eq!(data[5], 50.0);
+
+        let empty = unsafe {
+            View::<u8, 2>::from_raw_parts(std::ptr::NonNull::dangling().as_ptr(), [0, 3], [3, 1])
+        };
+
+        assert_eq!(empty.shape(), &[0, 3]);
+    }
+}
@@ -0,0 +1,132 @@
+use std::convert::TryFrom;
+
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Array, ArrayBase, Storage};
+
+// Arrays are stored as their shape and their elements in logical (row-major)
+// order, so views serialize the same way as the arrays they show.
+#[derive(Serialize)]
+#[serde(rename = "Array")]
+struct ArrayRef<'a, E> {
+    shape: &'a [usize],
+    data: E,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Array")]
+struct ArrayOwned<T> {
+    shape: Vec<usize>,
+    data: Vec<T>,
+}
+
+struct Elems<'a, S, const D: usize>(&'a ArrayBase<S, D>);
+
+impl<'a, T: Serialize, S: Storage<Elem = T>, const D: usize> Serialize for Elems<'a, S, D> {
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        serializer.collect_seq(self.0.flat())
+    }
+}
+
+impl<T: Serialize, S: Storage<Elem = T>, const D: usize> Serialize for ArrayBase<S, D> {
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        ArrayRef {
+            shape: &self.shape,
+            data: Elems(self),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const D: usize> Deserialize<'de> for Array<T, D> {
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        let ArrayOwned { shape, data } = ArrayOwned::deserialize(deserializer)?;
+
+        let shape = <[usize; D]>::try_from(shape).map_err(|shape| {
+            De::Error::custom(format!(
+                "expected a shape with {} axes, found {:?}",
+                D, shape
+            ))
+        })?;
+
+        Array::try_init(data, shape).map_err(De::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{assert_de_tokens_error, assert_ser_tokens, assert_tokens, Token};
+
+    use crate::Array;
+
+    fn array_tokens(shape: &[usize], data: &[Token]) -> Vec<Token> {
+        let mut tokens = vec![
+            Token::Struct {
+                name: "Array",
+                len: 2,
+            },
+            Token::Str("shape"),
+            Token::Seq {
+                len: Some(shape.len()),
+            },
+        ];
+        tokens.extend(shape.iter().map(|&len| Token::U64(len as u64)));
+        tokens.extend([
+            Token::SeqEnd,
+            Token::Str("data"),
+            Token::Seq {
+                len: Some(data.len()),
+            },
+        ]);
+        tokens.extend_from_slice(data);
+        tokens.extend([Token::SeqEnd, Token::StructEnd]);
+
+        tokens
+    }
+
+    fn ints(data: &[i32]) -> Vec<Token> {
+        data.iter().map(|&x| Token::I32(x)).collect()
+    }
+
+    #[test]
+    fn serialize() {
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        assert_ser_tokens(&array, &array_tokens(&[2, 3], &ints(&[1, 2, 3, 4, 5, 6])));
+    }
+
+    #[test]
+    fn serialize_view() {
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        assert_ser_tokens(
+            &array.t(),
+            &array_tokens(&[3, 2], &ints(&[1, 4, 2, 5, 3, 6])),
+        );
+        assert_ser_tokens(
+            &array.slice(&crate::s![.., ..;2]).flip(0),
+            &array_tokens(&[2, 2], &ints(&[4, 6, 1, 3])),
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let array = Array::init(vec![0.5, -1.0, 2.25, 4.0], [2, 1, 2]);
+        let data: Vec<_> = [0.5, -1.0, 2.25, 4.0].map(Token::F64).into();
+
+        assert_tokens(&array, &array_tokens(&[2, 1, 2], &data));
+    }
+
+    #[test]
+    fn deserialize_invalid() {
+        assert_de_tokens_error::<Array<i32, 2>>(
+            &array_tokens(&[2, 2], &ints(&[1, 2, 3])),
+            "Number of elements does not match the shape: 3 != 4",
+        );
+        assert_de_tokens_error::<Array<i32, 2>>(
+            &array_tokens(&[3], &ints(&[1, 2, 3])),
+            "expected a shape with 2 axes, found [3]",
+        );
+    }
+}
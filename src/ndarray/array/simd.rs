@@ -0,0 +1,356 @@
+use std::convert::{TryFrom, TryInto};
+use std::ops::{Add, Mul, Sub};
+
+use num_traits::Zero;
+use wide::{f32x8, f64x4, i32x8};
+
+use super::calc::pairwise_sum;
+use super::type_id::{self, cast_slice, cast_vec};
+use crate::{Array, ArrayBase, Storage, StorageMut};
+
+// Elements with a vector type of `wide`. Integer lanes wrap around on overflow, like the
+// scalar operations of a release build, so the operators and sums only use them when debug
+// assertions are off and the scalar operations would wrap as well.
+pub trait SimdElement: Copy + Default + Zero + Add<Output = Self> + 'static {
+    type Vector: Copy
+        + Add<Output = Self::Vector>
+        + Sub<Output = Self::Vector>
+        + Mul<Output = Self::Vector>;
+
+    const LANES: usize;
+
+    fn splat(val: Self) -> Self::Vector;
+
+    // Reads the first `LANES` values of `vals`
+    fn load(vals: &[Self]) -> Self::Vector;
+
+    // Writes the first `out.len()` lanes of `vector`
+    fn store(vector: Self::Vector, out: &mut [Self]);
+
+    // Appends the first `len` lanes of `vector`
+    fn push(vector: Self::Vector, out: &mut Vec<Self>, len: usize);
+
+    fn reduce_add(vector: Self::Vector) -> Self;
+
+    // `None` when the lanes can not be divided, as for integers
+    fn div(lhs: Self::Vector, rhs: Self::Vector) -> Option<Self::Vector>;
+}
+
+macro_rules! impl_simd_element {
+    ($ty:ty, $vector:ty, $lanes:expr, $div:expr) => {
+        impl SimdElement for $ty {
+            type Vector = $vector;
+
+            const LANES: usize = $lanes;
+
+            fn splat(val: Self) -> Self::Vector {
+                <$vector>::splat(val)
+            }
+
+            #[inline]
+            fn load(vals: &[Self]) -> Self::Vector {
+                <$vector>::new(vals[..$lanes].try_into().unwrap())
+            }
+
+            #[inline]
+            fn store(vector: Self::Vector, out: &mut [Self]) {
+                // Whole vectors are written without a call to `memcpy`
+                match <&mut [Self; $lanes]>::try_from(&mut *out) {
+                    Ok(out) => *out = vector.to_array(),
+                    Err(_) => {
+                        let len = out.len();
+
+                        out.copy_from_slice(&vector.to_array()[..len]);
+                    }
+                }
+            }
+
+            #[inline]
+            fn push(vector: Self::Vector, out: &mut Vec<Self>, len: usize) {
+                out.extend_from_slice(&vector.to_array()[..len]);
+            }
+
+            fn reduce_add(vector: Self::Vector) -> Self {
+                vector.reduce_add()
+            }
+
+            fn div(lhs: Self::Vector, rhs: Self::Vector) -> Option<Self::Vector> {
+                let div: fn(Self::Vector, Self::Vector) -> Option<Self::Vector> = $div;
+
+                div(lhs, rhs)
+            }
+        }
+    };
+}
+
+impl_simd_element!(f32, f32x8, 8, |lhs, rhs| Some(lhs / rhs));
+impl_simd_element!(f64, f64x4, 4, |lhs, rhs| Some(lhs / rhs));
+impl_simd_element!(i32, i32x8, 8, |_, _| None);
+
+impl<T: SimdElement, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    // Maps whole vectors of elements at once. The lanes past the last element are filled
+    // with zeros and their results are dropped.
+    pub fn map_simd<F: Fn(T::Vector) -> T::Vector>(&self, f: F) -> Array<T, D> {
+        match self.contiguous_range() {
+            Some(range) => Array::init(map_lanes(&self.data.as_slice()[range], f), self.shape),
+            None => {
+                let mut array = self.to_owned();
+                array.map_inplace_simd(f);
+
+                array
+            }
+        }
+    }
+}
+
+impl<T: SimdElement, S: StorageMut<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn map_inplace_simd<F: Fn(T::Vector) -> T::Vector>(&mut self, f: F) {
+        match self.contiguous_range() {
+            Some(range) => {
+                for chunk in self.data.as_mut_slice()[range].chunks_mut(T::LANES) {
+                    T::store(f(load_padded(chunk)), chunk);
+                }
+            }
+            None => {
+                let mapped = self.map_simd(f);
+
+                self.flat_mut()
+                    .zip(mapped.flat())
+                    .for_each(|(val, mapped)| *val = *mapped);
+            }
+        }
+    }
+}
+
+fn load_padded<E: SimdElement>(vals: &[E]) -> E::Vector {
+    if vals.len() == E::LANES {
+        return E::load(vals);
+    }
+
+    let mut padded = vec![E::zero(); E::LANES];
+    padded[..vals.len()].copy_from_slice(vals);
+
+    E::load(&padded)
+}
+
+// The output is appended to rather than zeroed and overwritten, which would cost another
+// pass over memory that is already the bottleneck
+fn map_lanes<E: SimdElement>(vals: &[E], f: impl Fn(E::Vector) -> E::Vector) -> Vec<E> {
+    let mut out = Vec::with_capacity(vals.len());
+
+    let mut chunks = vals.chunks_exact(E::LANES);
+    for chunk in chunks.by_ref() {
+        E::push(f(E::load(chunk)), &mut out, E::LANES);
+    }
+
+    // Only the last partial vector is padded
+    let tail = chunks.remainder();
+    if !tail.is_empty() {
+        E::push(f(load_padded(tail)), &mut out, tail.len());
+    }
+
+    out
+}
+
+fn zip_lanes<E: SimdElement>(
+    lhs: &[E],
+    rhs: &[E],
+    f: impl Fn(E::Vector, E::Vector) -> E::Vector,
+) -> Vec<E> {
+    let mut out = Vec::with_capacity(lhs.len());
+
+    for (lhs, rhs) in lhs.chunks_exact(E::LANES).zip(rhs.chunks_exact(E::LANES)) {
+        E::push(f(E::load(lhs), E::load(rhs)), &mut out, E::LANES);
+    }
+
+    let start = out.len();
+    if start < lhs.len() {
+        let vector = f(load_padded(&lhs[start..]), load_padded(&rhs[start..]));
+
+        E::push(vector, &mut out, lhs.len() - start);
+    }
+
+    out
+}
+
+// Sums blocks with vectors and the sums of the blocks pairwise, which keeps the rounding
+// error close to that of the scalar `pairwise_sum`
+fn sum_lanes<E: SimdElement>(vals: &[E]) -> E {
+    const BLOCK_LEN: usize = 1024;
+
+    pairwise_sum(vals.chunks(BLOCK_LEN).map(|block| {
+        let sum = block
+            .chunks(E::LANES)
+            .fold(E::splat(E::zero()), |acc, chunk| acc + load_padded(chunk));
+
+        E::reduce_add(sum)
+    }))
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl BinaryOp {
+    fn apply<E: SimdElement>(self, lhs: &[E], rhs: &[E]) -> Option<Vec<E>> {
+        Some(match self {
+            BinaryOp::Add => zip_lanes(lhs, rhs, |lhs, rhs| lhs + rhs),
+            BinaryOp::Sub => zip_lanes(lhs, rhs, |lhs, rhs| lhs - rhs),
+            BinaryOp::Mul => zip_lanes(lhs, rhs, |lhs, rhs| lhs * rhs),
+            BinaryOp::Div => {
+                // Integer division panics on zero, which is left to the scalar operation
+                E::div(E::splat(E::zero()), E::splat(E::zero()))?;
+
+                zip_lanes(lhs, rhs, |lhs, rhs| E::div(lhs, rhs).unwrap())
+            }
+        })
+    }
+}
+
+macro_rules! dispatch {
+    ($ty:ty, $f:ident::<$($generic:tt),*>($($arg:expr),*)) => {
+        if type_id::is::<$ty, f32>() {
+            $f::<f32, $($generic),*>($($arg),*)
+        } else if type_id::is::<$ty, f64>() {
+            $f::<f64, $($generic),*>($($arg),*)
+        } else if type_id::is::<$ty, i32>() && !cfg!(debug_assertions) {
+            $f::<i32, $($generic),*>($($arg),*)
+        } else {
+            None
+        }
+    };
+}
+
+// Arrays of the same shape in row-major order, the others are broadcast by the caller
+pub(crate) fn try_binary_op<T, S1, S2, const D: usize>(
+    lhs: &ArrayBase<S1, D>,
+    rhs: &ArrayBase<S2, D>,
+    op: BinaryOp,
+) -> Option<Array<T, D>>
+where
+    S1: Storage<Elem = T>,
+    S2: Storage<Elem = T>,
+{
+    if lhs.shape != rhs.shape {
+        return None;
+    }
+
+    let lhs_vals = &lhs.data.as_slice()[lhs.contiguous_range()?];
+    let rhs_vals = &rhs.data.as_slice()[rhs.contiguous_range()?];
+
+    let vec = dispatch!(T, binary_op::<T>(lhs_vals, rhs_vals, op))?;
+
+    Some(Array::init(vec, lhs.shape))
+}
+
+fn binary_op<E: SimdElement, T>(lhs: &[T], rhs: &[T], op: BinaryOp) -> Option<Vec<T>> {
+    let vec = op.apply::<E>(cast_slice(lhs), cast_slice(rhs))?;
+
+    Some(cast_vec(vec))
+}
+
+pub(crate) fn try_sum<T, S, const D: usize>(array: &ArrayBase<S, D>) -> Option<T>
+where
+    S: Storage<Elem = T>,
+{
+    let vals = &array.data.as_slice()[array.contiguous_range()?];
+
+    dispatch!(T, sum::<T>(vals))
+}
+
+fn sum<E: SimdElement, T>(vals: &[T]) -> Option<T> {
+    Some(type_id::cast(sum_lanes::<E>(cast_slice(vals))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s;
+
+    #[test]
+    fn binary_ops() {
+        // 19 elements leave a partial vector for every lane count
+        let lhs = Array::from_shape_fn([19], |[i]| i as f32 * 0.5 - 3.0);
+        let rhs = Array::from_shape_fn([19], |[i]| (i % 5) as f32 + 1.0);
+
+        let scalar = |f: fn(f32, f32) -> f32| {
+            Array::init(
+                lhs.flat().zip(rhs.flat()).map(|(l, r)| f(*l, *r)).collect(),
+                [19],
+            )
+        };
+
+        assert_eq!(&lhs + &rhs, scalar(|l, r| l + r));
+        assert_eq!(&lhs - &rhs, scalar(|l, r| l - r));
+        assert_eq!(&lhs * &rhs, scalar(|l, r| l * r));
+        assert_eq!(&lhs / &rhs, scalar(|l, r| l / r));
+
+        let lhs = Array::from_shape_fn([3, 7], |[i, j]| (i * 7 + j) as i32 - 10);
+
+        assert_eq!(
+            &lhs + lhs.flip(1),
+            Array::from_shape_fn([3, 7], |[i, _]| i as i32 * 14 - 14)
+        );
+        assert_eq!(&lhs / 3, lhs.mapv(|val| val / 3));
+        assert_eq!(
+            (&lhs - 1).sum(),
+            lhs.mapv(|val| val - 1).flat().sum::<i32>()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic(expected = "overflow"))]
+    fn integer_overflow() {
+        let lhs = Array::from_shape_fn([19], |[i]| i32::MAX - i as i32);
+        let rhs = Array::from_shape_fn([19], |[i]| i as i32 + 1);
+
+        // Wraps around in release builds like the scalar addition
+        assert_eq!((&lhs + &rhs).sum(), i32::MIN.wrapping_mul(19));
+    }
+
+    #[test]
+    fn sums() {
+        let array = Array::from_shape_fn([1000, 3], |[i, j]| (i * 3 + j) as f64 * 0.001);
+
+        assert!((array.sum() - 4498.5).abs() < 1e-9);
+        assert!((array.slice(&s![..;2, ..]).sum() - 2247.0).abs() < 1e-9);
+        assert_eq!(Array::arange(0..1001).sum(), 500500);
+        assert_eq!(Array::<f32, 1>::zeros([0]).sum(), 0.0);
+    }
+
+    #[test]
+    fn map_simd() {
+        let mut array = Array::from_shape_fn([5, 3], |[i, j]| (i * 3 + j) as f64);
+
+        let squares = array.map_simd(|val| val * val + f64::splat(1.0));
+
+        assert_eq!(squares, array.mapv(|val| val * val + 1.0));
+        assert_eq!(
+            array.t().map_simd(|val| val.sqrt()),
+            array.t().mapv(f64::sqrt)
+        );
+
+        array.slice_mut(&s![1.., 1..]).map_inplace_simd(|val| -val);
+        array
+            .view_mut()
+            .reversed_axes()
+            .map_inplace_simd(|val| val * f64::splat(2.0));
+
+        assert_eq!(
+            array,
+            Array::from_shape_fn([5, 3], |[i, j]| {
+                let val = (i * 3 + j) as f64 * 2.0;
+
+                if i > 0 && j > 0 {
+                    -val
+                } else {
+                    val
+                }
+            })
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use std::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliceBound {
+    Start(usize),
+    End(usize),
+}
+
+impl SliceBound {
+    pub fn resolve(self, len: usize) -> Option<usize> {
+        match self {
+            SliceBound::Start(idx) => Some(idx),
+            SliceBound::End(idx) => len.checked_sub(idx),
+        }
+    }
+}
+
+impl From<usize> for SliceBound {
+    fn from(idx: usize) -> Self {
+        SliceBound::Start(idx)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Slice {
+    pub start: SliceBound,
+    pub end: Option<SliceBound>,
+    pub step: usize,
+}
+
+impl Slice {
+    pub fn new(start: SliceBound, end: Option<SliceBound>, step: usize) -> Self {
+        Slice { start, end, step }
+    }
+
+    pub fn last(n: usize) -> Self {
+        Slice::new(SliceBound::End(n), None, 1)
+    }
+
+    pub fn step_by(self, step: usize) -> Self {
+        Slice { step, ..self }
+    }
+}
+
+impl From<Range<usize>> for Slice {
+    fn from(range: Range<usize>) -> Self {
+        Slice::new(range.start.into(), Some(range.end.into()), 1)
+    }
+}
+
+impl From<RangeInclusive<usize>> for Slice {
+    fn from(range: RangeInclusive<usize>) -> Self {
+        Slice::new((*range.start()).into(), Some((*range.end() + 1).into()), 1)
+    }
+}
+
+impl From<RangeFrom<usize>> for Slice {
+    fn from(range: RangeFrom<usize>) -> Self {
+        Slice::new(range.start.into(), None, 1)
+    }
+}
+
+impl From<RangeTo<usize>> for Slice {
+    fn from(range: RangeTo<usize>) -> Self {
+        Slice::new(0.into(), Some(range.end.into()), 1)
+    }
+}
+
+impl From<RangeToInclusive<usize>> for Slice {
+    fn from(range: RangeToInclusive<usize>) -> Self {
+        Slice::new(0.into(), Some((range.end + 1).into()), 1)
+    }
+}
+
+impl From<RangeFull> for Slice {
+    fn from(_: RangeFull) -> Self {
+        Slice::new(0.into(), None, 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_ranges() {
+        use SliceBound::Start;
+
+        assert_eq!(Slice::from(1..4), Slice::new(Start(1), Some(Start(4)), 1));
+        assert_eq!(Slice::from(1..=4), Slice::new(Start(1), Some(Start(5)), 1));
+        assert_eq!(Slice::from(2..), Slice::new(Start(2), None, 1));
+        assert_eq!(Slice::from(..3), Slice::new(Start(0), Some(Start(3)), 1));
+        assert_eq!(Slice::from(..=3), Slice::new(Start(0), Some(Start(4)), 1));
+        assert_eq!(Slice::from(..).step_by(2), Slice::new(Start(0), None, 2));
+    }
+
+    #[test]
+    fn resolve_bounds() {
+        assert_eq!(SliceBound::Start(2).resolve(5), Some(2));
+        assert_eq!(SliceBound::End(2).resolve(5), Some(3));
+        assert_eq!(SliceBound::End(5).resolve(5), Some(0));
+        assert_eq!(SliceBound::End(6).resolve(5), None);
+    }
+}
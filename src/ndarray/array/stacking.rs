@@ -0,0 +1,345 @@
+use arrayvec::ArrayVec;
+
+use super::iter::Indices;
+use super::len_of;
+use crate::{Array, ArrayBase, ShapeError, Storage, View};
+
+impl<T: Clone, const D: usize> Array<T, D> {
+    pub fn concatenate<S: Storage<Elem = T>>(axis: usize, arrays: &[&ArrayBase<S, D>]) -> Self {
+        if axis >= D {
+            panic!("Axis out of bound: {} >= {}", axis, D);
+        }
+
+        let first = match arrays.first() {
+            Some(first) => first,
+            None => panic!("At least one array is needed for concatenation"),
+        };
+
+        let mut shape = first.shape;
+        shape[axis] = 0;
+
+        for array in arrays {
+            for other_axis in (0..D).filter(|other_axis| *other_axis != axis) {
+                if array.shape[other_axis] != first.shape[other_axis] {
+                    panic!(
+                        "Shapes can not be concatenated along axis {}: {:?} and {:?}",
+                        axis, first.shape, array.shape
+                    );
+                }
+            }
+
+            shape[axis] = shape[axis]
+                .checked_add(array.shape[axis])
+                .unwrap_or_else(|| panic!("{}", ShapeError::Overflow));
+        }
+
+        // Walk all indices of the axes before the concatenation axis and append the
+        // contiguous run of elements each array holds at that index.
+        let mut outer_shape = shape;
+        for len in outer_shape[axis..].iter_mut() {
+            *len = 1;
+        }
+
+        let mut vec = Vec::with_capacity(len_of(&shape));
+
+        let mut outer_indices = Indices::init(outer_shape);
+        while let Some(outer_idx) = outer_indices.next_front() {
+            for array in arrays {
+                let slice: ArrayVec<_, D> = (0..D)
+                    .map(|slice_axis| {
+                        if slice_axis < axis {
+                            outer_idx[slice_axis]..outer_idx[slice_axis] + 1
+                        } else {
+                            0..array.shape[slice_axis]
+                        }
+                    })
+                    .collect();
+
+                vec.extend(array.slice(&slice.into_inner().unwrap()).flat().cloned());
+            }
+        }
+
+        Array::init(vec, shape)
+    }
+
+    pub fn stack<S: Storage<Elem = T>, const R: usize>(
+        axis: usize,
+        arrays: &[&ArrayBase<S, R>],
+    ) -> Self {
+        if D != R + 1 {
+            panic!(
+                "Rank of the result must be one more than rank of the arrays: {} != {}",
+                D,
+                R + 1
+            );
+        }
+
+        let views: Vec<View<'_, T, D>> = arrays
+            .iter()
+            .map(|array| array.view().insert_axis(axis))
+            .collect();
+
+        Array::concatenate(axis, &views.iter().collect::<Vec<_>>())
+    }
+}
+
+impl<T: Clone, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn tile(&self, reps: [usize; D]) -> Array<T, D> {
+        let mut shape = self.shape;
+        for (len, rep) in shape.iter_mut().zip(reps.iter()) {
+            *len = len
+                .checked_mul(*rep)
+                .unwrap_or_else(|| panic!("{}", ShapeError::Overflow));
+        }
+
+        self.gather(shape, |axis, idx| idx % self.shape[axis])
+    }
+
+    pub fn repeat(&self, axis: usize, n: usize) -> Array<T, D> {
+        if axis >= D {
+            panic!("Axis out of bound: {} >= {}", axis, D);
+        }
+
+        let mut shape = self.shape;
+        shape[axis] = shape[axis]
+            .checked_mul(n)
+            .unwrap_or_else(|| panic!("{}", ShapeError::Overflow));
+
+        self.gather(
+            shape,
+            |gather_axis, idx| {
+                if gather_axis == axis {
+                    idx / n
+                } else {
+                    idx
+                }
+            },
+        )
+    }
+
+    // Builds an array of `shape` whose element at each index is read from this array
+    // at the index that `source` maps every axis of it to.
+    fn gather<F: Fn(usize, usize) -> usize>(&self, shape: [usize; D], source: F) -> Array<T, D> {
+        let data = self.data.as_slice();
+        let mut vec = Vec::with_capacity(len_of(&shape));
+
+        let mut out_indices = Indices::init(shape);
+        while let Some(out_idx) = out_indices.next_front() {
+            let mut indices = [0; D];
+            for (axis, idx) in indices.iter_mut().enumerate() {
+                *idx = source(axis, out_idx[axis]);
+            }
+
+            vec.push(data[self.offset_of(&indices)].clone());
+        }
+
+        Array::init(vec, shape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Array;
+
+    #[test]
+    fn concatenate_rows() {
+        // 1 2 3     7 8 9
+        // 4 5 6
+        let lhs = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+        let rhs = Array::init(vec![7, 8, 9], [1, 3]);
+
+        let array = Array::concatenate(0, &[&lhs, &rhs]);
+
+        assert_eq!(array.shape(), &[3, 3]);
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 2, 3, 4, 5, 6, 7, 8, 9]
+        );
+    }
+
+    #[test]
+    fn concatenate_columns() {
+        // 1 2     5
+        // 3 4     6
+        let lhs = Array::init(vec![1, 2, 3, 4], [2, 2]);
+        let rhs = Array::init(vec![5, 6], [2, 1]);
+
+        let array = Array::concatenate(1, &[&lhs, &rhs, &lhs]);
+
+        assert_eq!(array.shape(), &[2, 5]);
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 2, 5, 1, 2, 3, 4, 6, 3, 4]
+        );
+    }
+
+    #[test]
+    fn concatenate_views() {
+        // 2-D array:
+        // 1 2 3
+        // 4 5 6
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        let transposed = array.t();
+        let flipped = array.flip(1);
+        let lhs = flipped.t();
+
+        let concatenated = Array::concatenate(1, &[&lhs, &transposed]);
+
+        assert_eq!(concatenated.shape(), &[3, 4]);
+        assert_eq!(
+            concatenated.flat().copied().collect::<Vec<usize>>(),
+            vec![3, 6, 1, 4, 2, 5, 2, 5, 1, 4, 3, 6]
+        );
+    }
+
+    #[test]
+    fn concatenate_3d() {
+        let lhs = Array::init((0..8).collect(), [2, 2, 2]);
+        let rhs = Array::init((8..12).collect(), [2, 1, 2]);
+
+        let array = Array::concatenate(1, &[&lhs, &rhs]);
+
+        assert_eq!(array.shape(), &[2, 3, 2]);
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 1, 2, 3, 8, 9, 4, 5, 6, 7, 10, 11]
+        );
+    }
+
+    #[test]
+    fn stack() {
+        let frame0 = Array::init(vec![1, 2, 3, 4], [2, 2]);
+        let frame1 = Array::init(vec![5, 6, 7, 8], [2, 2]);
+
+        let video: Array<usize, 3> = Array::stack(0, &[&frame0, &frame1]);
+
+        assert_eq!(video.shape(), &[2, 2, 2]);
+        assert_eq!(
+            video.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 2, 3, 4, 5, 6, 7, 8]
+        );
+
+        let stacked: Array<usize, 3> = Array::stack(2, &[&frame0, &frame1]);
+
+        assert_eq!(stacked.shape(), &[2, 2, 2]);
+        assert_eq!(
+            stacked.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 5, 2, 6, 3, 7, 4, 8]
+        );
+    }
+
+    #[test]
+    fn stack_vectors() {
+        let row0 = Array::init(vec![1, 2, 3], [3]);
+        let row1 = Array::init(vec![4, 5, 6], [3]);
+
+        let rows: Array<usize, 2> = Array::stack(0, &[&row0.view(), &row1.flip(0)]);
+        let columns: Array<usize, 2> = Array::stack(1, &[&row0, &row1]);
+
+        assert_eq!(rows.shape(), &[2, 3]);
+        assert_eq!(
+            rows.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 2, 3, 6, 5, 4]
+        );
+        assert_eq!(columns.shape(), &[3, 2]);
+        assert_eq!(
+            columns.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 4, 2, 5, 3, 6]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn stack_shape_mismatch() {
+        let lhs = Array::init(vec![1, 2, 3], [3]);
+        let rhs = Array::init(vec![1, 2], [2]);
+
+        let _: Array<usize, 2> = Array::stack(0, &[&lhs, &rhs]);
+    }
+
+    #[test]
+    fn tile() {
+        // 1 2
+        let array = Array::init(vec![1, 2], [1, 2]);
+
+        // 1 2 1 2 1 2
+        // 1 2 1 2 1 2
+        let tiled = array.tile([2, 3]);
+
+        assert_eq!(tiled.shape(), &[2, 6]);
+        assert_eq!(
+            tiled.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2]
+        );
+    }
+
+    #[test]
+    fn tile_view() {
+        // 1 2
+        // 3 4
+        let array = Array::init(vec![1, 2, 3, 4], [2, 2]);
+
+        let transposed = array.t();
+        let tiled = transposed.tile([1, 2]);
+
+        assert_eq!(
+            tiled.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 3, 1, 3, 2, 4, 2, 4]
+        );
+        assert_eq!(array.tile([0, 1]).shape(), &[0, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Number of elements of the shape exceeds isize::MAX")]
+    fn tile_overflow() {
+        let array = Array::init(vec![1, 2, 3, 4], [2, 2]);
+
+        array.tile([usize::MAX, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Number of elements of the shape exceeds isize::MAX")]
+    fn repeat_overflow() {
+        let array = Array::init(vec![1, 2, 3, 4], [2, 2]);
+
+        array.repeat(1, isize::MAX as usize);
+    }
+
+    #[test]
+    fn repeat() {
+        // 1 2
+        // 3 4
+        let array = Array::init(vec![1, 2, 3, 4], [2, 2]);
+
+        let rows = array.repeat(0, 2);
+        let columns = array.repeat(1, 3);
+
+        assert_eq!(rows.shape(), &[4, 2]);
+        assert_eq!(
+            rows.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 2, 1, 2, 3, 4, 3, 4]
+        );
+        assert_eq!(columns.shape(), &[2, 6]);
+        assert_eq!(
+            columns.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 1, 1, 2, 2, 2, 3, 3, 3, 4, 4, 4]
+        );
+        assert_eq!(array.repeat(1, 0).shape(), &[2, 0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn concatenate_shape_mismatch() {
+        let lhs = Array::init(vec![1, 2, 3, 4], [2, 2]);
+        let rhs = Array::init(vec![5, 6, 7], [1, 3]);
+
+        Array::concatenate(0, &[&lhs, &rhs]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn concatenate_nothing() {
+        Array::<usize, 2>::concatenate::<Vec<usize>>(0, &[]);
+    }
+}
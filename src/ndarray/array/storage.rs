@@ -0,0 +1,45 @@
+pub trait Storage {
+    type Elem;
+
+    fn as_slice(&self) -> &[Self::Elem];
+}
+
+pub trait StorageMut: Storage {
+    fn as_mut_slice(&mut self) -> &mut [Self::Elem];
+}
+
+impl<T> Storage for Vec<T> {
+    type Elem = T;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> StorageMut for Vec<T> {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T> Storage for &[T] {
+    type Elem = T;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> Storage for &mut [T] {
+    type Elem = T;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> StorageMut for &mut [T] {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+}
@@ -0,0 +1,350 @@
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+use memmap2::Mmap;
+use safetensors::tensor::{Metadata, SafeTensorError, SafeTensors, View};
+use safetensors::Dtype;
+
+use crate::{Array, ArrayBase, NpyElement, Storage};
+
+// Safetensors files store little-endian, row-major data just like `.npy` files
+pub trait TensorElement: NpyElement {
+    const DTYPE: Dtype;
+}
+
+macro_rules! impl_tensor_element {
+    ($($ty:ty => $dtype:ident),*) => {
+        $(impl TensorElement for $ty {
+            const DTYPE: Dtype = Dtype::$dtype;
+        })*
+    };
+}
+
+impl_tensor_element!(
+    i8 => I8, i16 => I16, i32 => I32, i64 => I64,
+    u8 => U8, u16 => U16, u32 => U32, u64 => U64,
+    f32 => F32, f64 => F64
+);
+
+#[derive(Debug)]
+pub enum SafeTensorsError {
+    Io(io::Error),
+    Format(SafeTensorError),
+    DtypeMismatch { expected: Dtype, found: Dtype },
+    RankMismatch { expected: usize, found: usize },
+}
+
+impl fmt::Display for SafeTensorsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SafeTensorsError::Io(error) => write!(f, "I/O error: {}", error),
+            SafeTensorsError::Format(error) => write!(f, "Invalid safetensors data: {}", error),
+            SafeTensorsError::DtypeMismatch { expected, found } => write!(
+                f,
+                "Data type of the tensor does not match the array: {} != {}",
+                found, expected
+            ),
+            SafeTensorsError::RankMismatch { expected, found } => write!(
+                f,
+                "Number of axes of the tensor does not match the array: {} != {}",
+                found, expected
+            ),
+        }
+    }
+}
+
+impl Error for SafeTensorsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SafeTensorsError::Io(error) => Some(error),
+            SafeTensorsError::Format(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for SafeTensorsError {
+    fn from(error: io::Error) -> Self {
+        SafeTensorsError::Io(error)
+    }
+}
+
+impl From<SafeTensorError> for SafeTensorsError {
+    fn from(error: SafeTensorError) -> Self {
+        SafeTensorsError::Format(error)
+    }
+}
+
+// Only the header is parsed up front, tensors are decoded when they are asked for
+pub struct SafeTensorsReader<B: AsRef<[u8]>> {
+    bytes: B,
+    header_len: usize,
+    metadata: Metadata,
+}
+
+impl SafeTensorsReader<Vec<u8>> {
+    // Reads the whole file into memory
+    pub fn read<P: AsRef<Path>>(path: P) -> Result<Self, SafeTensorsError> {
+        SafeTensorsReader::new(std::fs::read(path)?)
+    }
+}
+
+impl SafeTensorsReader<Mmap> {
+    // Maps the file instead of reading it, so only the tensors that are decoded get loaded
+    /// # Safety
+    ///
+    /// The file may not be modified or truncated, by this process or another one, while the
+    /// reader maps it.
+    pub unsafe fn open<P: AsRef<Path>>(path: P) -> Result<Self, SafeTensorsError> {
+        let file = File::open(path)?;
+
+        // Safety: the caller keeps the file unchanged while it is mapped
+        let mmap = unsafe { Mmap::map(&file)? };
+
+        SafeTensorsReader::new(mmap)
+    }
+}
+
+impl<B: AsRef<[u8]>> SafeTensorsReader<B> {
+    pub fn new(bytes: B) -> Result<Self, SafeTensorsError> {
+        let (header_len, metadata) = SafeTensors::read_metadata(bytes.as_ref())?;
+
+        Ok(SafeTensorsReader {
+            bytes,
+            header_len,
+            metadata,
+        })
+    }
+
+    // Names of the tensors in the order their data is stored
+    pub fn names(&self) -> Vec<String> {
+        self.metadata.offset_keys()
+    }
+
+    pub fn tensor<T: TensorElement, const D: usize>(
+        &self,
+        name: &str,
+    ) -> Result<Array<T, D>, SafeTensorsError> {
+        let info = self
+            .metadata
+            .info(name)
+            .ok_or_else(|| SafeTensorError::TensorNotFound(name.to_string()))?;
+
+        if info.dtype != T::DTYPE {
+            return Err(SafeTensorsError::DtypeMismatch {
+                expected: T::DTYPE,
+                found: info.dtype,
+            });
+        }
+
+        let shape = <[usize; D]>::try_from(info.shape.as_slice()).map_err(|_| {
+            SafeTensorsError::RankMismatch {
+                expected: D,
+                found: info.shape.len(),
+            }
+        })?;
+
+        // The data starts after the 8 bytes of the header length and the header itself
+        let (start, end) = info.data_offsets;
+        let data_start = 8 + self.header_len;
+        let bytes = &self.bytes.as_ref()[data_start + start..data_start + end];
+
+        let vec = bytes
+            .chunks_exact(std::mem::size_of::<T>())
+            .map(T::read_le)
+            .collect();
+
+        Ok(Array::init(vec, shape))
+    }
+}
+
+struct Tensor {
+    dtype: Dtype,
+    shape: Vec<usize>,
+    data: Vec<u8>,
+}
+
+impl View for &Tensor {
+    fn dtype(&self) -> Dtype {
+        self.dtype
+    }
+
+    fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    fn data(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(&self.data)
+    }
+
+    fn data_len(&self) -> usize {
+        self.data.len()
+    }
+}
+
+#[derive(Default)]
+pub struct SafeTensorsWriter {
+    tensors: Vec<(String, Tensor)>,
+}
+
+impl SafeTensorsWriter {
+    pub fn new() -> Self {
+        SafeTensorsWriter::default()
+    }
+
+    pub fn add_array<T: TensorElement, S: Storage<Elem = T>, const D: usize>(
+        &mut self,
+        name: &str,
+        array: &ArrayBase<S, D>,
+    ) {
+        if self.tensors.iter().any(|(other, _)| other == name) {
+            panic!("A tensor named {} was already added", name);
+        }
+
+        let len: usize = array.shape.iter().product();
+        let mut data = Vec::with_capacity(len * std::mem::size_of::<T>());
+        for elem in array.flat() {
+            elem.write_le(&mut data);
+        }
+
+        let tensor = Tensor {
+            dtype: T::DTYPE,
+            shape: array.shape.to_vec(),
+            data,
+        };
+
+        self.tensors.push((name.to_string(), tensor));
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, SafeTensorsError> {
+        let tensors = self.tensors.iter().map(|(name, tensor)| (name, tensor));
+
+        Ok(safetensors::serialize(tensors, None)?)
+    }
+
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> Result<(), SafeTensorsError> {
+        let tensors = self.tensors.iter().map(|(name, tensor)| (name, tensor));
+
+        Ok(safetensors::serialize_to_file(
+            tensors,
+            None,
+            path.as_ref(),
+        )?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tensors() -> SafeTensorsWriter {
+        let mut writer = SafeTensorsWriter::new();
+
+        writer.add_array(
+            "weight",
+            &Array::init(vec![0.5f32, -1.0, 2.0, 4.0], [2, 2]).t(),
+        );
+        writer.add_array("bias", &Array::init(vec![1.0f32, 2.0], [2]));
+        writer.add_array("steps", &Array::init(vec![7i64], []));
+
+        writer
+    }
+
+    #[test]
+    fn round_trip() {
+        let reader = SafeTensorsReader::new(tensors().to_bytes().unwrap()).unwrap();
+
+        let mut names = reader.names();
+        names.sort();
+        assert_eq!(names, vec!["bias", "steps", "weight"]);
+
+        assert_eq!(
+            reader.tensor::<f32, 2>("weight").unwrap(),
+            Array::init(vec![0.5, 2.0, -1.0, 4.0], [2, 2])
+        );
+        assert_eq!(
+            reader.tensor::<f32, 1>("bias").unwrap(),
+            Array::init(vec![1.0, 2.0], [2])
+        );
+        assert_eq!(
+            reader.tensor::<i64, 0>("steps").unwrap(),
+            Array::init(vec![7], [])
+        );
+    }
+
+    #[test]
+    fn read_external() {
+        // Written by the reference implementation
+        let bytes = b"<\x00\x00\x00\x00\x00\x00\x00{\"test\":{\"dtype\":\"I32\",\"shape\":[2,2],\"data_offsets\":[0,16]}}\x01\x00\x00\x00\x02\x00\x00\x00\x03\x00\x00\x00\x04\x00\x00\x00";
+
+        let reader = SafeTensorsReader::new(&bytes[..]).unwrap();
+
+        assert_eq!(
+            reader.tensor::<i32, 2>("test").unwrap(),
+            Array::init(vec![1, 2, 3, 4], [2, 2])
+        );
+    }
+
+    #[test]
+    fn mismatches() {
+        let reader = SafeTensorsReader::new(tensors().to_bytes().unwrap()).unwrap();
+
+        assert!(matches!(
+            reader.tensor::<f64, 2>("weight"),
+            Err(SafeTensorsError::DtypeMismatch {
+                expected: Dtype::F64,
+                found: Dtype::F32
+            })
+        ));
+        assert!(matches!(
+            reader.tensor::<f32, 1>("weight"),
+            Err(SafeTensorsError::RankMismatch {
+                expected: 1,
+                found: 2
+            })
+        ));
+        assert!(matches!(
+            reader.tensor::<f32, 1>("missing"),
+            Err(SafeTensorsError::Format(SafeTensorError::TensorNotFound(_)))
+        ));
+        assert!(matches!(
+            SafeTensorsReader::new(vec![1, 2, 3]),
+            Err(SafeTensorsError::Format(_))
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "A tensor named bias was already added")]
+    fn duplicate_name() {
+        let mut writer = tensors();
+
+        writer.add_array("bias", &Array::init(vec![3.0f32], [1]));
+    }
+
+    #[test]
+    fn write_and_open_file() {
+        let path =
+            std::env::temp_dir().join(format!("nd_array_{}.safetensors", std::process::id()));
+
+        tensors().write(&path).unwrap();
+
+        let bias = unsafe { SafeTensorsReader::open(&path) }
+            .unwrap()
+            .tensor::<f32, 1>("bias");
+        let weight = SafeTensorsReader::read(&path)
+            .unwrap()
+            .tensor::<f32, 2>("weight");
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(bias.unwrap(), Array::init(vec![1.0, 2.0], [2]));
+        assert_eq!(
+            weight.unwrap(),
+            Array::init(vec![0.5, 2.0, -1.0, 4.0], [2, 2])
+        );
+    }
+}
@@ -1,105 +1,341 @@
-use std::borrow::Cow;
+use super::{checked_len, default_strides};
+use crate::{Array, ArrayBase, Order, ShapeError, Storage, View};
 
-use crate::Array;
-
-impl<'a, T: Clone, const D: usize> Array<'a, T, D> {
-    pub fn transpose(mut self) -> Array<'a, T, D> {
+impl<S, const D: usize> ArrayBase<S, D> {
+    pub fn reversed_axes(mut self) -> Self {
         self.shape.reverse();
         self.strides.reverse();
-        self.idx_maps.reverse();
 
         self
     }
 
-    pub fn t(&'a self) -> Array<'a, T, D> {
-        let mut shape = self.shape.clone();
-        let mut strides = self.strides.clone();
-        let mut idx_maps = self.idx_maps.clone();
+    pub fn transpose(self) -> Self {
+        self.reversed_axes()
+    }
+
+    pub fn insert_axis<const R: usize>(self, axis: usize) -> ArrayBase<S, R> {
+        if axis > D {
+            panic!("Axis out of bound: {} > {}", axis, D);
+        }
+
+        if R != D + 1 {
+            panic!(
+                "Rank of the result must be one more than rank of the array: {} != {}",
+                R,
+                D + 1
+            );
+        }
+
+        let mut shape = [1; R];
+        let mut strides = [1; R];
 
-        shape.reverse();
-        strides.reverse();
-        idx_maps.reverse();
+        for old_axis in 0..D {
+            let new_axis = if old_axis < axis {
+                old_axis
+            } else {
+                old_axis + 1
+            };
 
-        Array {
-            vec: Cow::from(&*self.vec),
+            shape[new_axis] = self.shape[old_axis];
+            strides[new_axis] = self.strides[old_axis];
+        }
+
+        if axis < D {
+            strides[axis] = self.strides[axis] * self.shape[axis] as isize;
+        }
+
+        ArrayBase {
+            data: self.data,
             shape,
             strides,
-            idx_maps,
+            offset: self.offset,
         }
     }
 
-    pub fn flip(&'a self, axis: usize) -> Array<'a, T, D> {
+    pub fn permuted_axes_move(mut self, axes: [usize; D]) -> Self {
+        let mut seen = [false; D];
+        for &axis in axes.iter() {
+            if axis >= D || seen[axis] {
+                panic!("Axes are not a permutation of 0..{}: {:?}", D, axes);
+            }
+
+            seen[axis] = true;
+        }
+
+        let shape = self.shape;
+        let strides = self.strides;
+
+        for (new_axis, &axis) in axes.iter().enumerate() {
+            self.shape[new_axis] = shape[axis];
+            self.strides[new_axis] = strides[axis];
+        }
+
+        self
+    }
+
+    pub fn flip_move(mut self, axis: usize) -> Self {
         if axis >= D {
             panic!("Axis out of bounds")
         }
 
-        let mut idx_maps = self.idx_maps.clone();
+        if self.shape[axis] > 0 {
+            self.offset = (self.offset as isize
+                + (self.shape[axis] - 1) as isize * self.strides[axis])
+                as usize;
+        }
 
-        let idx_map = &mut idx_maps[axis];
+        self.strides[axis] = -self.strides[axis];
 
-        idx_map.append_b((self.shape[axis] - 1) as isize);
-        idx_map.m *= -1;
+        self
+    }
+}
 
-        Array {
-            vec: Cow::from(&*self.vec),
-            shape: self.shape.clone(),
-            strides: self.strides.clone(),
-            idx_maps,
-        }
+impl<T, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn t(&self) -> View<'_, T, D> {
+        self.view().transpose()
+    }
+
+    pub fn flip(&self, axis: usize) -> View<'_, T, D> {
+        self.view().flip_move(axis)
     }
 
-    pub fn swap_axes(&'a self, axis0: usize, axis1: usize) -> Array<'a, T, D> {
+    pub fn swap_axes(&self, axis0: usize, axis1: usize) -> View<'_, T, D> {
         if axis0 >= D || axis1 >= D {
             panic!("Axis out of bounds")
         }
 
-        let mut shape = self.shape.clone();
-        let mut strides = self.strides.clone();
-        let mut idx_maps = self.idx_maps.clone();
+        let mut view = self.view();
+
+        view.shape.swap(axis0, axis1);
+        view.strides.swap(axis0, axis1);
 
-        shape.swap(axis0, axis1);
-        strides.swap(axis0, axis1);
-        idx_maps.swap(axis0, axis1);
+        view
+    }
+
+    pub fn permuted_axes(&self, axes: [usize; D]) -> View<'_, T, D> {
+        self.view().permuted_axes_move(axes)
+    }
 
-        Array {
-            vec: Cow::from(&*self.vec),
+    pub fn index_axis<const R: usize>(&self, axis: usize, index: usize) -> View<'_, T, R> {
+        if axis >= D {
+            panic!("Axis out of bound: {} >= {}", axis, D);
+        }
+
+        if R + 1 != D {
+            panic!(
+                "Rank of the result must be one less than rank of the array: {} != {}",
+                R,
+                D - 1
+            );
+        }
+
+        if index >= self.shape[axis] {
+            panic!(
+                "Index out of bounds for axis {}: {} >= {}",
+                axis, index, self.shape[axis]
+            );
+        }
+
+        let mut shape = [0; R];
+        let mut strides = [0; R];
+
+        for new_axis in 0..R {
+            let old_axis = if new_axis < axis {
+                new_axis
+            } else {
+                new_axis + 1
+            };
+
+            shape[new_axis] = self.shape[old_axis];
+            strides[new_axis] = self.strides[old_axis];
+        }
+
+        ArrayBase {
+            data: self.data.as_slice(),
             shape,
             strides,
-            idx_maps,
+            offset: (self.offset as isize + index as isize * self.strides[axis]) as usize,
         }
     }
+}
 
-    pub fn reshape<const S: usize>(&self, shape: [usize; S]) -> Array<'a, T, S> {
-        // TODO: Check wether cloning is necessary
+impl<T: Clone, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn reshape<const R: usize>(&self, shape: [usize; R]) -> Result<Array<T, R>, ShapeError> {
+        let len: usize = self.shape.iter().product();
+        let expected = checked_len(&shape)?;
 
-        let vec = self.flat().cloned().collect();
+        if len != expected {
+            return Err(ShapeError::IncompatibleSize { len, expected });
+        }
 
-        Array::init(vec, shape)
+        let vec = match self.contiguous_range() {
+            Some(range) => self.data.as_slice()[range].to_vec(),
+            None => self.flat().cloned().collect(),
+        };
+
+        Ok(Array::init(vec, shape))
+    }
+
+    pub fn reshape_clone<const R: usize>(
+        &self,
+        shape: [usize; R],
+    ) -> Result<Array<T, R>, ShapeError> {
+        match self.view().into_shape(shape) {
+            Ok(view) => Ok(view.to_owned()),
+            Err(ShapeError::IncompatibleLayout) => self.reshape(shape),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn roll(&self, axis: usize, shift: isize) -> Array<T, D> {
+        if axis >= D {
+            panic!("Axis out of bound: {} >= {}", axis, D);
+        }
+
+        let len = self.shape[axis];
+        if len == 0 {
+            return self.to_owned();
+        }
+
+        // The last `shift` elements of the axis wrap around to its front
+        let shift = shift.rem_euclid(len as isize) as usize;
+        let (head, tail) = self.split_at(axis, len - shift);
+
+        Array::concatenate(axis, &[&tail, &head])
     }
 
-    pub fn flatten(&self) -> Array<'a, T, 1> {
+    pub fn flatten(&self) -> Array<T, 1> {
         let vec = self.flat().cloned().collect();
 
-        Array::init(vec, [self.vec.len()])
+        Array::init(vec, [self.shape.iter().product()])
+    }
+
+    pub fn ravel(&self) -> Array<T, 1> {
+        self.flatten()
+    }
+
+    // Reads the elements in `order` and lays them out in the new shape in the same order
+    pub fn reshape_with_order<const R: usize>(
+        &self,
+        shape: [usize; R],
+        order: Order,
+    ) -> Result<Array<T, R>, ShapeError> {
+        match order {
+            Order::RowMajor => self.reshape(shape),
+            Order::ColumnMajor => {
+                let mut reversed = shape;
+                reversed.reverse();
+
+                Ok(self.t().reshape(reversed)?.reversed_axes())
+            }
+        }
+    }
+
+    pub fn flatten_with_order(&self, order: Order) -> Array<T, 1> {
+        match order {
+            Order::RowMajor => self.flatten(),
+            Order::ColumnMajor => self.t().flatten(),
+        }
     }
 
-    pub fn ravel(&self) -> Array<'a, T, 1> {
-        self.reshape([self.vec.len()])
+    pub fn ravel_with_order(&self, order: Order) -> Array<T, 1> {
+        self.flatten_with_order(order)
     }
 }
 
-impl<'a, T: Clone + Default, const D: usize> Array<'a, T, D> {
-    pub fn resize<const S: usize>(mut self, shape: [usize; S]) -> Array<'a, T, S> {
-        let new_size = shape.iter().product();
-        let old_size = self.shape().iter().product();
+impl<'a, T, const D: usize> View<'a, T, D> {
+    pub fn into_shape_with_order<const R: usize>(
+        self,
+        shape: [usize; R],
+        order: Order,
+    ) -> Result<View<'a, T, R>, ShapeError> {
+        match order {
+            Order::RowMajor => self.into_shape(shape),
+            Order::ColumnMajor => {
+                let mut reversed = shape;
+                reversed.reverse();
+
+                Ok(self.reversed_axes().into_shape(reversed)?.reversed_axes())
+            }
+        }
+    }
+
+    pub fn into_shape<const R: usize>(
+        self,
+        shape: [usize; R],
+    ) -> Result<View<'a, T, R>, ShapeError> {
+        let len: usize = self.shape.iter().product();
+        let expected = checked_len(&shape)?;
+
+        if len != expected {
+            return Err(ShapeError::IncompatibleSize { len, expected });
+        }
+
+        if len == 0 {
+            return Ok(ArrayBase {
+                data: self.data,
+                shape,
+                strides: default_strides(&shape),
+                offset: 0,
+            });
+        }
 
-        if new_size > old_size {
-            self.vec.to_mut().resize_with(new_size, || T::default());
-        } else {
-            self.vec.to_mut().truncate(new_size);
+        // Axes of length one do not constrain the layout, so they are skipped
+        let axes: Vec<(usize, isize)> = (0..D)
+            .filter(|&axis| self.shape[axis] != 1)
+            .map(|axis| (self.shape[axis], self.strides[axis]))
+            .collect();
+
+        // Match groups of old axes with groups of new axes that hold the same
+        // number of elements. Each group of old axes must be contiguous with
+        // respect to itself to be expressible with a single stride sequence.
+        let mut strides = [1; R];
+        let (mut old_axis, mut new_axis) = (0, 0);
+        while old_axis < axes.len() && new_axis < R {
+            let (mut old_end, mut new_end) = (old_axis + 1, new_axis + 1);
+            let (mut old_len, mut new_len) = (axes[old_axis].0, shape[new_axis]);
+
+            while old_len != new_len {
+                if new_len < old_len {
+                    new_len *= shape[new_end];
+                    new_end += 1;
+                } else {
+                    old_len *= axes[old_end].0;
+                    old_end += 1;
+                }
+            }
+
+            if (old_axis..old_end - 1)
+                .any(|axis| axes[axis].1 != axes[axis + 1].0 as isize * axes[axis + 1].1)
+            {
+                return Err(ShapeError::IncompatibleLayout);
+            }
+
+            strides[new_end - 1] = axes[old_end - 1].1;
+            for axis in (new_axis + 1..new_end).rev() {
+                strides[axis - 1] = strides[axis] * shape[axis] as isize;
+            }
+
+            old_axis = old_end;
+            new_axis = new_end;
         }
 
-        self.reshape(shape)
+        Ok(ArrayBase {
+            data: self.data,
+            shape,
+            strides,
+            offset: self.offset,
+        })
+    }
+}
+
+impl<T: Clone + Default, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    pub fn resize<const R: usize>(&self, shape: [usize; R]) -> Array<T, R> {
+        let mut vec: Vec<T> = self.flat().cloned().collect();
+
+        vec.resize_with(shape.iter().product(), T::default);
+
+        Array::init(vec, shape)
     }
 }
 
@@ -118,7 +354,7 @@ mod tests {
         // 1 2
         // 3 4
         // 5 6
-        let array = array.reshape([3, 2]);
+        let array = array.reshape([3, 2]).unwrap();
 
         assert_eq!(array[[0, 0]], 1);
         assert_eq!(array[[0, 1]], 2);
@@ -128,6 +364,154 @@ mod tests {
         assert_eq!(array[[2, 1]], 6);
     }
 
+    #[test]
+    fn reshape_to_different_rank() {
+        let array = Array::arange(0..6);
+
+        let matrix = array.reshape([2, 3]).unwrap();
+
+        assert_eq!(matrix.shape(), &[2, 3]);
+        assert_eq!(matrix[[1, 0]], 3);
+
+        let cube = matrix.reshape([1, 2, 3]).unwrap();
+
+        assert_eq!(cube.shape(), &[1, 2, 3]);
+        assert_eq!(
+            cube.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 1, 2, 3, 4, 5]
+        );
+    }
+
+    #[test]
+    fn reshape_slice() {
+        let array = Array::init((0..12).collect(), [3, 4]);
+
+        let rows = array.slice(&[1..3, 0..4]).reshape([8]).unwrap();
+        let block = array.slice(&[0..2, 1..3]).reshape([4]).unwrap();
+
+        assert_eq!(
+            rows.flat().copied().collect::<Vec<usize>>(),
+            vec![4, 5, 6, 7, 8, 9, 10, 11]
+        );
+        assert_eq!(
+            block.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 2, 5, 6]
+        );
+    }
+
+    #[test]
+    fn reshape_incompatible_size() {
+        let array = Array::arange(0..6);
+
+        assert_eq!(
+            array.reshape([4, 2]).err(),
+            Some(ShapeError::IncompatibleSize {
+                len: 6,
+                expected: 8
+            })
+        );
+    }
+
+    #[test]
+    fn reshape_overflow() {
+        let array = Array::arange(0..6);
+
+        assert_eq!(
+            array.reshape([usize::MAX, 3]).err(),
+            Some(ShapeError::Overflow)
+        );
+        assert_eq!(
+            array.view().into_shape([usize::MAX, 3]).err(),
+            Some(ShapeError::Overflow)
+        );
+    }
+
+    #[test]
+    fn into_shape_contiguous() {
+        let array = Array::init((0..12).collect(), [3, 4]);
+
+        let view = array.view().into_shape([2, 2, 3]).unwrap();
+
+        assert_eq!(view.strides(), &[6, 3, 1]);
+        assert_eq!(view[[1, 0, 2]], 8);
+
+        let rows = array.slice(&[1..3, 0..4]).into_shape([8]).unwrap();
+
+        assert!(std::ptr::eq(&rows[[0]], &array[[1, 0]]));
+        assert_eq!(
+            rows.flat().copied().collect::<Vec<usize>>(),
+            vec![4, 5, 6, 7, 8, 9, 10, 11]
+        );
+    }
+
+    #[test]
+    fn into_shape_strided() {
+        // 0  1  2  3
+        // 4  5  6  7
+        // 8  9 10 11
+        let array = Array::init((0..12).collect(), [3, 4]);
+
+        // splitting a strided axis only needs new strides
+        let columns = array.slice(&[0..3, 1..3]);
+        let split = columns.into_shape([3, 2, 1]).unwrap();
+        assert_eq!(split.strides()[..2], [4, 1]);
+        assert_eq!(
+            split.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 2, 5, 6, 9, 10]
+        );
+
+        // splitting the rows of a transposed array keeps its column-major order
+        let transposed = array.t();
+        let split = transposed.into_shape([2, 2, 3]).unwrap();
+        assert_eq!(split.strides(), &[2, 1, 4]);
+        assert_eq!(split[[1, 0, 2]], array[[2, 2]]);
+    }
+
+    #[test]
+    fn into_shape_incompatible_layout() {
+        let array = Array::init((0..12).collect::<Vec<usize>>(), [3, 4]);
+
+        let block = array.slice(&[0..2, 0..2]);
+        assert_eq!(
+            block.into_shape([4]).err(),
+            Some(ShapeError::IncompatibleLayout)
+        );
+
+        let transposed = array.t();
+        assert_eq!(
+            transposed.into_shape([12]).err(),
+            Some(ShapeError::IncompatibleLayout)
+        );
+
+        let flipped = array.flip(0);
+        assert_eq!(
+            flipped.into_shape([12]).err(),
+            Some(ShapeError::IncompatibleLayout)
+        );
+
+        assert_eq!(
+            array.view().into_shape([5]).err(),
+            Some(ShapeError::IncompatibleSize {
+                len: 12,
+                expected: 5
+            })
+        );
+    }
+
+    #[test]
+    fn reshape_clone() {
+        let array = Array::init((0..12).collect(), [3, 4]);
+
+        let block = array.slice(&[0..2, 0..2]).reshape_clone([4]).unwrap();
+        let rows = array.slice(&[1..3, 0..4]).reshape_clone([2, 4]).unwrap();
+
+        assert_eq!(
+            block.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 1, 4, 5]
+        );
+        assert_eq!(rows[[1, 3]], 11);
+    }
+
     #[test]
     fn transpose() {
         // 2-D array:
@@ -165,7 +549,7 @@ mod tests {
         // reshape the array to a 2x3 2-D array:
         // 1 4 2
         // 5 3 6
-        let array = array.reshape([2, 3]);
+        let array = array.reshape([2, 3]).unwrap();
 
         assert_eq!(array[[0, 0]], 1);
         assert_eq!(array[[0, 1]], 4);
@@ -175,6 +559,59 @@ mod tests {
         assert_eq!(array[[1, 2]], 6);
     }
 
+    #[test]
+    fn t_is_a_zero_copy_view() {
+        // 2-D array:
+        // 1 2 3
+        // 4 5 6
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+
+        let transposed = array.t();
+
+        assert_eq!(transposed.shape(), &[3, 2]);
+        assert_eq!(transposed.strides(), &[1, 3]);
+        assert!(std::ptr::eq(&transposed[[0, 0]], &array[[0, 0]]));
+        assert_eq!(
+            transposed.flat().copied().collect::<Vec<usize>>(),
+            vec![1, 4, 2, 5, 3, 6]
+        );
+        assert_eq!(
+            transposed.flat().rev().copied().collect::<Vec<usize>>(),
+            vec![6, 3, 5, 2, 4, 1]
+        );
+
+        let rows = transposed
+            .axis_view(0)
+            .map(|view| view.flat().copied().collect())
+            .collect::<Vec<Vec<usize>>>();
+
+        assert_eq!(rows, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+    }
+
+    #[test]
+    fn reversed_axes() {
+        // 3-D array of shape 2x3x4 holding 0..24
+        let array = Array::init((0..24).collect(), [2, 3, 4]);
+
+        let reversed = array.view().reversed_axes();
+
+        assert_eq!(reversed.shape(), &[4, 3, 2]);
+        assert_eq!(reversed.strides(), &[1, 4, 12]);
+        assert_eq!(reversed[[3, 1, 0]], array[[0, 1, 3]]);
+        assert_eq!(
+            reversed.flat().take(6).copied().collect::<Vec<usize>>(),
+            vec![0, 12, 4, 16, 8, 20]
+        );
+
+        let slices = reversed
+            .axis_view(2)
+            .map(|view| view.flat().copied().collect::<Vec<usize>>())
+            .collect::<Vec<Vec<usize>>>();
+
+        assert_eq!(slices[0], vec![0, 4, 8, 1, 5, 9, 2, 6, 10, 3, 7, 11]);
+        assert_eq!(slices[1][0], 12);
+    }
+
     #[test]
     fn flip() {
         // 2-D array:
@@ -193,6 +630,63 @@ mod tests {
         );
     }
 
+    #[test]
+    fn flip_strides() {
+        // 0  1  2  3
+        // 4  5  6  7
+        // 8  9 10 11
+        let array = Array::init((0..12).collect(), [3, 4]);
+
+        let flipped = array.flip(1);
+
+        assert_eq!(flipped.strides(), &[4, -1]);
+        assert!(std::ptr::eq(&flipped[[1, 0]], &array[[1, 3]]));
+        assert_eq!(
+            flipped
+                .flat()
+                .rev()
+                .take(5)
+                .copied()
+                .collect::<Vec<usize>>(),
+            vec![8, 9, 10, 11, 4]
+        );
+
+        let twice = flipped.flip(0);
+
+        assert_eq!(twice.strides(), &[-4, -1]);
+        assert_eq!(
+            twice.flat().copied().collect::<Vec<usize>>(),
+            (0..12).rev().collect::<Vec<usize>>()
+        );
+    }
+
+    #[test]
+    fn flip_move() {
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]).flip_move(0);
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<usize>>(),
+            vec![4, 5, 6, 1, 2, 3]
+        );
+        assert_eq!(array.to_owned().strides(), &[3, 1]);
+    }
+
+    #[test]
+    fn flip_reshape() {
+        // 0  1  2  3
+        // 4  5  6  7
+        let array = Array::init((0..8).collect(), [2, 4]);
+
+        let flipped = array.flip(1);
+        let split = flipped.into_shape([2, 2, 2]).unwrap();
+
+        assert_eq!(split.strides(), &[4, -2, -1]);
+        assert_eq!(
+            split.flat().copied().collect::<Vec<usize>>(),
+            vec![3, 2, 1, 0, 7, 6, 5, 4]
+        );
+    }
+
     #[test]
     fn swap_axis() {
         // 2-D array:
@@ -206,6 +700,207 @@ mod tests {
         assert_eq!(swapped_array[[2, 0]], 3);
     }
 
+    #[test]
+    fn swap_axes_strided() {
+        // 3-D array of shape 2x3x4 holding 0..24
+        let array = Array::init((0..24).collect(), [2, 3, 4]);
+
+        let swapped = array.swap_axes(0, 2);
+
+        assert_eq!(swapped.shape(), &[4, 3, 2]);
+        assert_eq!(swapped[[3, 2, 1]], array[[1, 2, 3]]);
+        assert_eq!(
+            swapped
+                .swap_axes(0, 2)
+                .to_owned()
+                .flat()
+                .copied()
+                .collect::<Vec<usize>>(),
+            (0..24).collect::<Vec<usize>>()
+        );
+    }
+
+    #[test]
+    fn permuted_axes() {
+        // 3-D array of shape 2x3x4 holding 0..24
+        let array = Array::init((0..24).collect(), [2, 3, 4]);
+
+        let permuted = array.permuted_axes([2, 0, 1]);
+
+        assert_eq!(permuted.shape(), &[4, 2, 3]);
+        assert_eq!(permuted.strides(), &[1, 12, 4]);
+
+        for (indices, val) in array.indexed_iter() {
+            let [i, j, k] = indices;
+            assert_eq!(permuted[[k, i, j]], *val);
+        }
+
+        assert_eq!(
+            permuted.flat().take(6).copied().collect::<Vec<usize>>(),
+            vec![0, 4, 8, 12, 16, 20]
+        );
+    }
+
+    #[test]
+    fn permuted_axes_identity() {
+        let array = Array::init((0..6).collect(), [2, 3]);
+
+        let permuted = array.permuted_axes([0, 1]);
+
+        assert_eq!(permuted.shape(), array.shape());
+        assert_eq!(
+            permuted.flat().copied().collect::<Vec<usize>>(),
+            vec![0, 1, 2, 3, 4, 5]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn permuted_axes_repeated_axis() {
+        let array = Array::init((0..24).collect::<Vec<usize>>(), [2, 3, 4]);
+
+        array.permuted_axes([0, 0, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn permuted_axes_out_of_bounds() {
+        let array = Array::init((0..6).collect::<Vec<usize>>(), [2, 3]);
+
+        array.permuted_axes([0, 2]);
+    }
+
+    #[test]
+    fn insert_axis() {
+        let array = Array::init(vec![1, 2, 3], [3]);
+
+        let column: Array<usize, 2> = array.clone().insert_axis(1);
+        assert_eq!(column.shape(), &[3, 1]);
+        assert_eq!(column[[2, 0]], 3);
+
+        let row: Array<usize, 2> = array.insert_axis(0);
+        assert_eq!(row.shape(), &[1, 3]);
+        assert_eq!(row[[0, 2]], 3);
+
+        let sum = &column + &row;
+        assert_eq!(sum.shape(), &[3, 3]);
+        assert_eq!(sum[[2, 0]], 4);
+    }
+
+    #[test]
+    fn insert_axis_view() {
+        // 3-D array of shape 2x3x4 holding 0..24
+        let array = Array::init((0..24).collect(), [2, 3, 4]);
+
+        let flipped = array.flip(1);
+        let view = flipped.insert_axis::<4>(2);
+
+        assert_eq!(view.shape(), &[2, 3, 1, 4]);
+        assert_eq!(view[[1, 0, 0, 3]], array[[1, 2, 3]]);
+        assert_eq!(
+            view.flat().copied().collect::<Vec<usize>>(),
+            flipped.flat().copied().collect::<Vec<usize>>()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_axis_wrong_rank() {
+        let array = Array::init(vec![1, 2, 3], [3]);
+
+        let _: Array<usize, 3> = array.insert_axis(0);
+    }
+
+    #[test]
+    fn index_axis() {
+        // 3-D array of shape 2x3x4 holding 0..24
+        let array = Array::init((0..24).collect(), [2, 3, 4]);
+
+        let matrix: View<usize, 2> = array.index_axis(1, 2);
+        assert_eq!(matrix.shape(), &[2, 4]);
+        assert_eq!(
+            matrix.flat().copied().collect::<Vec<usize>>(),
+            vec![8, 9, 10, 11, 20, 21, 22, 23]
+        );
+
+        let row: View<usize, 1> = matrix.index_axis(0, 1);
+        assert_eq!(row.shape(), &[4]);
+        assert_eq!(
+            row.flat().copied().collect::<Vec<usize>>(),
+            vec![20, 21, 22, 23]
+        );
+    }
+
+    #[test]
+    fn index_axis_strided() {
+        // 0  1  2  3
+        // 4  5  6  7
+        // 8  9 10 11
+        let array = Array::init((0..12).collect(), [3, 4]);
+
+        let flipped = array.flip(0);
+        let block = flipped.slice(&[0..2, 1..4]);
+
+        let column: View<usize, 1> = block.index_axis(1, 1);
+        assert_eq!(column.flat().copied().collect::<Vec<usize>>(), vec![10, 6]);
+
+        let transposed = array.t();
+        let row: View<usize, 1> = transposed.index_axis(0, 3);
+        assert_eq!(row.flat().copied().collect::<Vec<usize>>(), vec![3, 7, 11]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_axis_out_of_bounds() {
+        let array = Array::init((0..12).collect::<Vec<usize>>(), [3, 4]);
+
+        let _: View<usize, 1> = array.index_axis(0, 3);
+    }
+
+    #[test]
+    fn roll() {
+        let array = Array::arange(0..5);
+
+        assert_eq!(
+            array.roll(0, 2).flat().copied().collect::<Vec<usize>>(),
+            vec![3, 4, 0, 1, 2]
+        );
+        assert_eq!(
+            array.roll(0, -1).flat().copied().collect::<Vec<usize>>(),
+            vec![1, 2, 3, 4, 0]
+        );
+        assert_eq!(
+            array.roll(0, 10).flat().copied().collect::<Vec<usize>>(),
+            vec![0, 1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn roll_2d() {
+        // 0 1 2
+        // 3 4 5
+        let array = Array::init((0..6).collect(), [2, 3]);
+
+        assert_eq!(
+            array.roll(1, 1).flat().copied().collect::<Vec<usize>>(),
+            vec![2, 0, 1, 5, 3, 4]
+        );
+        assert_eq!(
+            array.roll(0, 1).flat().copied().collect::<Vec<usize>>(),
+            vec![3, 4, 5, 0, 1, 2]
+        );
+
+        let transposed = array.t();
+        assert_eq!(
+            transposed
+                .roll(0, -1)
+                .flat()
+                .copied()
+                .collect::<Vec<usize>>(),
+            vec![1, 4, 2, 5, 0, 3]
+        );
+    }
+
     #[test]
     fn flatten() {
         // 2-D array:
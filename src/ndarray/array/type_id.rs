@@ -0,0 +1,89 @@
+use std::any::TypeId;
+use std::marker::PhantomData;
+use std::mem;
+
+// The faster paths for some element types pick them out by their type id. `TypeId::of`
+// only takes `'static` types, which would then be needed by every operation with such a
+// path, so the id is read through a trait object whose lifetime is erased instead. Types
+// that only differ in their lifetimes share an id, which is why the casts below are only
+// ever asked for element types without lifetimes, like the floats and integers.
+trait NonStaticAny {
+    fn type_id(&self) -> TypeId
+    where
+        Self: 'static;
+}
+
+impl<T: ?Sized> NonStaticAny for PhantomData<T> {
+    fn type_id(&self) -> TypeId
+    where
+        Self: 'static,
+    {
+        TypeId::of::<T>()
+    }
+}
+
+pub(crate) fn of<T: ?Sized>() -> TypeId {
+    let phantom = PhantomData::<T>;
+    let any: &dyn NonStaticAny = &phantom;
+
+    // Safety: only the lifetime of the trait object changes, which `type_id` does not use
+    let any: &(dyn NonStaticAny + 'static) = unsafe { mem::transmute(any) };
+
+    any.type_id()
+}
+
+pub(crate) fn is<T, E: 'static>() -> bool {
+    of::<T>() == TypeId::of::<E>()
+}
+
+#[cfg(any(feature = "half", feature = "simd"))]
+pub(crate) fn cast<E: 'static, T>(val: E) -> T {
+    assert!(is::<T, E>(), "Element must be of the dispatched type");
+
+    // Safety: `T` and `E` are the same type
+    unsafe { mem::transmute_copy(&*mem::ManuallyDrop::new(val)) }
+}
+
+#[cfg(any(feature = "half", feature = "rayon", feature = "simd"))]
+pub(crate) fn cast_slice<T, E: 'static>(vals: &[T]) -> &[E] {
+    assert!(is::<T, E>(), "Elements must be of the dispatched type");
+
+    // Safety: `T` and `E` are the same type
+    unsafe { &*(vals as *const [T] as *const [E]) }
+}
+
+#[cfg(feature = "rayon")]
+pub(crate) fn cast_slice_mut<T, E: 'static>(vals: &mut [T]) -> &mut [E] {
+    assert!(is::<T, E>(), "Elements must be of the dispatched type");
+
+    // Safety: `T` and `E` are the same type
+    unsafe { &mut *(vals as *mut [T] as *mut [E]) }
+}
+
+#[cfg(any(feature = "half", feature = "simd"))]
+pub(crate) fn cast_vec<E: 'static, T>(vec: Vec<E>) -> Vec<T> {
+    assert!(is::<T, E>(), "Elements must be of the dispatched type");
+
+    let mut vec = mem::ManuallyDrop::new(vec);
+
+    // Safety: `T` and `E` are the same type, so the allocation is handed over unchanged
+    unsafe { Vec::from_raw_parts(vec.as_mut_ptr() as *mut T, vec.len(), vec.capacity()) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn borrowed_types() {
+        fn id_of<T>(_: &T) -> TypeId {
+            of::<T>()
+        }
+
+        let text = String::from("text");
+
+        assert_eq!(id_of(&text.as_str()), TypeId::of::<&'static str>());
+        assert!(is::<f32, f32>());
+        assert!(!is::<(f32, &str), f32>());
+    }
+}
@@ -0,0 +1,176 @@
+use std::iter::FusedIterator;
+
+use super::iter::Indices;
+use crate::{ArrayBase, ShapeError, Storage, View};
+
+impl<T, S: Storage<Elem = T>, const D: usize> ArrayBase<S, D> {
+    // Every position a window of shape `window` fits in, each window sharing the memory and
+    // strides of the array. Yields nothing if the window is larger than the array.
+    pub fn windows(&self, window: [usize; D]) -> Windows<'_, T, D> {
+        if window.contains(&0) {
+            panic!("Window has an empty axis: {:?}", window);
+        }
+
+        Windows::init(self.view(), window, [1; D])
+    }
+
+    // Tiles the array into blocks of shape `chunk` that do not overlap, dropping the
+    // elements at the end of an axis that do not fill a whole block
+    pub fn exact_chunks(&self, chunk: [usize; D]) -> Windows<'_, T, D> {
+        if chunk.contains(&0) {
+            panic!("Chunk has an empty axis: {:?}", chunk);
+        }
+
+        Windows::init(self.view(), chunk, chunk)
+    }
+
+    // Like `exact_chunks`, but fails instead of dropping elements if `chunk` does not divide
+    // the shape of the array
+    pub fn try_exact_chunks(&self, chunk: [usize; D]) -> Result<Windows<'_, T, D>, ShapeError> {
+        for (axis, (&len, &chunk)) in self.shape.iter().zip(&chunk).enumerate() {
+            if chunk == 0 || len % chunk != 0 {
+                return Err(ShapeError::Indivisible { axis, len, chunk });
+            }
+        }
+
+        Ok(Windows::init(self.view(), chunk, chunk))
+    }
+}
+
+pub struct Windows<'a, T, const D: usize> {
+    view: View<'a, T, D>,
+    window: [usize; D],
+    // Distance along each axis between the starts of neighbouring windows
+    step: [usize; D],
+    positions: Indices<[usize; D]>,
+}
+
+impl<'a, T, const D: usize> Windows<'a, T, D> {
+    pub(crate) fn init(view: View<'a, T, D>, window: [usize; D], step: [usize; D]) -> Self {
+        let counts = std::array::from_fn(|axis| {
+            view.shape[axis]
+                .checked_sub(window[axis])
+                .map_or(0, |rest| rest / step[axis] + 1)
+        });
+
+        Windows {
+            view,
+            window,
+            step,
+            positions: Indices::init(counts),
+        }
+    }
+
+    fn window_at(&self, position: [usize; D]) -> View<'a, T, D> {
+        let mut offset = self.view.offset as isize;
+        for (axis, &idx) in position.iter().enumerate() {
+            offset += (idx * self.step[axis]) as isize * self.view.strides[axis];
+        }
+
+        ArrayBase {
+            data: self.view.data,
+            shape: self.window,
+            strides: self.view.strides,
+            offset: offset as usize,
+        }
+    }
+}
+
+impl<'a, T, const D: usize> Iterator for Windows<'a, T, D> {
+    type Item = View<'a, T, D>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let position = self.positions.next_front()?;
+
+        Some(self.window_at(position))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.positions.remaining;
+
+        (len, Some(len))
+    }
+}
+
+impl<'a, T, const D: usize> DoubleEndedIterator for Windows<'a, T, D> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let position = self.positions.next_back()?;
+
+        Some(self.window_at(position))
+    }
+}
+
+impl<'a, T, const D: usize> ExactSizeIterator for Windows<'a, T, D> {}
+
+impl<'a, T, const D: usize> FusedIterator for Windows<'a, T, D> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{s, Array, ShapeError};
+
+    #[test]
+    fn windows() {
+        // 2-D array:
+        // 0  1  2  3
+        // 4  5  6  7
+        // 8  9 10 11
+        let array = Array::from_shape_fn([3, 4], |[i, j]| i * 4 + j);
+
+        let windows = array.windows([2, 3]);
+        assert_eq!(windows.len(), 4);
+
+        let sums = windows.map(|window| window.sum()).collect::<Vec<usize>>();
+        assert_eq!(sums, vec![18, 24, 42, 48]);
+
+        let last = array.windows([2, 3]).next_back().unwrap();
+        assert_eq!(last, array.slice(&s![1..3, 1..4]));
+
+        assert_eq!(array.windows([1, 1]).count(), 12);
+        assert_eq!(array.windows([4, 1]).count(), 0);
+    }
+
+    #[test]
+    fn windows_of_strided_view() {
+        let array = Array::from_shape_fn([10], |[i]| i as i32);
+        let view = array.slice_move(&s![..;2]).flip_move(0);
+
+        // 8 6 4 2 0
+        let windows = view
+            .windows([3])
+            .map(|window| window.flat().copied().collect::<Vec<i32>>())
+            .collect::<Vec<_>>();
+
+        assert_eq!(windows, vec![vec![8, 6, 4], vec![6, 4, 2], vec![4, 2, 0]]);
+    }
+
+    #[test]
+    fn exact_chunks() {
+        let array = Array::from_shape_fn([5, 4], |[i, j]| i * 4 + j);
+
+        // the last row does not fill a block
+        let chunks = array.exact_chunks([2, 2]).collect::<Vec<_>>();
+
+        assert_eq!(chunks.len(), 4);
+        assert_eq!(chunks[0], array.slice(&s![0..2, 0..2]));
+        assert_eq!(chunks[3], array.slice(&s![2..4, 2..4]));
+
+        assert_eq!(
+            array.try_exact_chunks([2, 2]).err(),
+            Some(ShapeError::Indivisible {
+                axis: 0,
+                len: 5,
+                chunk: 2
+            })
+        );
+
+        let rows = array.try_exact_chunks([1, 4]).unwrap();
+        assert_eq!(rows.len(), 5);
+        assert_eq!(rows.map(|row| row.sum()).sum::<usize>(), array.sum());
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_window() {
+        Array::<f64, 2>::zeros([2, 2]).windows([0, 1]);
+    }
+}
@@ -0,0 +1,257 @@
+use std::marker::PhantomData;
+
+use super::{broadcast::broadcast_shape, iter::Indices};
+use crate::{Array, ArrayBase, Storage, StorageMut, View};
+
+pub trait Producer<const D: usize> {
+    type Item;
+
+    fn shape(&self) -> [usize; D];
+
+    fn broadcast(self, shape: [usize; D]) -> Self;
+
+    /// # Safety
+    /// `indices` must be in bounds and every multi-index may only be requested once
+    /// for producers handing out mutable references.
+    unsafe fn item(&self, indices: &[usize; D]) -> Self::Item;
+}
+
+pub trait IntoProducer<const D: usize> {
+    type Producer: Producer<D>;
+
+    fn into_producer(self) -> Self::Producer;
+}
+
+impl<'a, T, const D: usize> Producer<D> for View<'a, T, D> {
+    type Item = &'a T;
+
+    fn shape(&self) -> [usize; D] {
+        self.shape
+    }
+
+    fn broadcast(self, shape: [usize; D]) -> Self {
+        self.broadcast_move(shape)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    unsafe fn item(&self, indices: &[usize; D]) -> Self::Item {
+        &self.data[self.offset_of(indices)]
+    }
+}
+
+pub struct ProducerMut<'a, T, const D: usize> {
+    view: ArrayBase<*mut T, D>,
+    marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T, const D: usize> Producer<D> for ProducerMut<'a, T, D> {
+    type Item = &'a mut T;
+
+    fn shape(&self) -> [usize; D] {
+        self.view.shape
+    }
+
+    fn broadcast(self, shape: [usize; D]) -> Self {
+        if self.view.shape != shape {
+            panic!(
+                "Mutable array of shape: {:?} can not be broadcast to shape: {:?}",
+                self.view.shape, shape
+            )
+        }
+
+        self
+    }
+
+    unsafe fn item(&self, indices: &[usize; D]) -> Self::Item {
+        &mut *self.view.data.add(self.view.offset_of(indices))
+    }
+}
+
+impl<'a, T, const D: usize> IntoProducer<D> for View<'a, T, D> {
+    type Producer = View<'a, T, D>;
+
+    fn into_producer(self) -> Self::Producer {
+        self
+    }
+}
+
+impl<'a, T: 'a, S: Storage<Elem = T>, const D: usize> IntoProducer<D> for &'a ArrayBase<S, D> {
+    type Producer = View<'a, T, D>;
+
+    fn into_producer(self) -> Self::Producer {
+        self.view()
+    }
+}
+
+impl<'a, T: 'a, S: StorageMut<Elem = T>, const D: usize> IntoProducer<D>
+    for &'a mut ArrayBase<S, D>
+{
+    type Producer = ProducerMut<'a, T, D>;
+
+    fn into_producer(self) -> Self::Producer {
+        let view = ArrayBase {
+            data: self.data.as_mut_slice().as_mut_ptr(),
+            shape: self.shape,
+            strides: self.strides,
+            offset: self.offset,
+        };
+
+        ProducerMut {
+            view,
+            marker: PhantomData,
+        }
+    }
+}
+
+pub struct Zip<P, const D: usize> {
+    parts: P,
+    shape: [usize; D],
+}
+
+impl<P1: Producer<D>, const D: usize> Zip<(P1,), D> {
+    pub fn from<I: IntoProducer<D, Producer = P1>>(p1: I) -> Self {
+        let p1 = p1.into_producer();
+        let shape = p1.shape();
+
+        Zip {
+            parts: (p1,),
+            shape,
+        }
+    }
+}
+
+macro_rules! impl_zip {
+    ($(($p:ident, $v:ident)),+) => {
+        impl<$($p: Producer<D>,)+ const D: usize> Zip<($($p,)+), D> {
+            pub fn for_each<F: FnMut($($p::Item),+)>(self, mut f: F) {
+                let ($($v,)+) = self.parts;
+                let mut indices = Indices::init(self.shape);
+
+                while let Some(idx) = indices.next_front() {
+                    // SAFETY: `idx` is in bounds of the broadcast shape that every part has
+                    // been expanded to, and `Indices` yields every multi-index exactly once.
+                    unsafe { f($($v.item(&idx)),+) }
+                }
+            }
+
+            pub fn map_collect<U, F: FnMut($($p::Item),+) -> U>(self, mut f: F) -> Array<U, D> {
+                let shape = self.shape;
+                let mut vec = Vec::with_capacity(shape.iter().product());
+
+                self.for_each(|$($v),+| vec.push(f($($v),+)));
+
+                Array::init(vec, shape)
+            }
+        }
+    };
+}
+
+macro_rules! impl_zip_and {
+    ($(($p:ident, $v:ident)),+; ($last_p:ident, $last_v:ident)) => {
+        impl<$($p: Producer<D>,)+ const D: usize> Zip<($($p,)+), D> {
+            pub fn and<$last_p: Producer<D>, I: IntoProducer<D, Producer = $last_p>>(
+                self,
+                $last_v: I,
+            ) -> Zip<($($p,)+ $last_p,), D> {
+                let $last_v = $last_v.into_producer();
+
+                let shape = broadcast_shape(&self.shape, &$last_v.shape())
+                    .unwrap_or_else(|error| panic!("{}", error));
+
+                let ($($v,)+) = self.parts;
+
+                Zip {
+                    parts: ($($v.broadcast(shape),)+ $last_v.broadcast(shape),),
+                    shape,
+                }
+            }
+        }
+    };
+}
+
+impl_zip!((P1, p1));
+impl_zip!((P1, p1), (P2, p2));
+impl_zip!((P1, p1), (P2, p2), (P3, p3));
+impl_zip!((P1, p1), (P2, p2), (P3, p3), (P4, p4));
+impl_zip!((P1, p1), (P2, p2), (P3, p3), (P4, p4), (P5, p5));
+impl_zip!((P1, p1), (P2, p2), (P3, p3), (P4, p4), (P5, p5), (P6, p6));
+
+impl_zip_and!((P1, p1); (P2, p2));
+impl_zip_and!((P1, p1), (P2, p2); (P3, p3));
+impl_zip_and!((P1, p1), (P2, p2), (P3, p3); (P4, p4));
+impl_zip_and!((P1, p1), (P2, p2), (P3, p3), (P4, p4); (P5, p5));
+impl_zip_and!((P1, p1), (P2, p2), (P3, p3), (P4, p4), (P5, p5); (P6, p6));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn for_each() {
+        let mut array = Array::zeros([2, 3]);
+        let array2 = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+        let array3 = Array::init(vec![6, 5, 4, 3, 2, 1], [2, 3]);
+
+        Zip::from(&mut array)
+            .and(&array2)
+            .and(&array3)
+            .for_each(|x, y, z| *x = y * z);
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<i32>>(),
+            vec![6, 10, 12, 12, 10, 6]
+        );
+    }
+
+    #[test]
+    fn for_each_strided() {
+        // 2-D array:
+        // 1 2 3
+        // 4 5 6
+        let array = Array::init(vec![1, 2, 3, 4, 5, 6], [2, 3]);
+        let array2 = Array::init(vec![1, 2, 3, 4, 5, 6], [3, 2]);
+
+        let mut pairs = vec![];
+        Zip::from(array.t())
+            .and(&array2)
+            .for_each(|x, y| pairs.push((*x, *y)));
+
+        assert_eq!(pairs, vec![(1, 1), (4, 2), (2, 3), (5, 4), (3, 5), (6, 6)]);
+    }
+
+    #[test]
+    fn map_collect_broadcast() {
+        let column = Array::init(vec![0, 10, 20], [3, 1]);
+        let row = Array::init(vec![0, 1, 2, 3], [1, 4]);
+
+        let sum = Zip::from(&column).and(&row).map_collect(|x, y| x + y);
+
+        assert_eq!(sum.shape(), &[3, 4]);
+        assert_eq!(
+            sum.flat().copied().collect::<Vec<i32>>(),
+            vec![0, 1, 2, 3, 10, 11, 12, 13, 20, 21, 22, 23]
+        );
+    }
+
+    #[test]
+    fn broadcast_into_mutable() {
+        let mut array = Array::zeros([2, 3]);
+        let row = Array::init(vec![1, 2, 3], [1, 3]);
+
+        Zip::from(&mut array).and(&row).for_each(|x, y| *x += y);
+
+        assert_eq!(
+            array.flat().copied().collect::<Vec<i32>>(),
+            vec![1, 2, 3, 1, 2, 3]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn broadcast_mutable() {
+        let mut row = Array::init(vec![1, 2, 3], [1, 3]);
+        let array: Array<i32, 2> = Array::zeros([2, 3]);
+
+        Zip::from(&mut row).and(&array).for_each(|x, y| *x += y);
+    }
+}
@@ -1,3 +1,47 @@
 mod array;
 
-pub use array::Array;
+pub use array::{
+    broadcast_shape, meshgrid, meshgrid_nd, ArcArray, Array, ArrayBase, ArrayD, BroadcastError,
+    ChunkError, ChunkLoader, ChunkedArray, ChunkedFlat, ChunkedView, CowArray, CsvError, Dot,
+    DynArrayBase, Error, Expr, Expression, IndexError, IntoProducer, NpyChunks, NpyElement,
+    NpyError, Order, PadMode, Producer, ProducerMut, Scalar, ShapeError, Slice, SliceBound,
+    SliceError, Storage, StorageMut, View, ViewD, ViewMut, ViewMutD, Zip,
+};
+
+#[cfg(feature = "allocator")]
+pub use array::ArrayIn;
+
+#[cfg(feature = "arrow")]
+pub use array::{ArrowConversionError, ArrowElement};
+
+#[cfg(feature = "bytemuck")]
+pub use array::CastError;
+
+#[cfg(feature = "dlpack")]
+pub use array::{
+    DLDataType, DLDevice, DLManagedTensor, DLTensor, DlpackElement, DlpackError, DL_CPU,
+};
+
+#[cfg(feature = "gpu")]
+pub use array::{GpuArray, GpuDevice, GpuElement, GpuError};
+
+#[cfg(feature = "hdf5")]
+pub use array::{Hdf5Element, Hdf5Error, Hdf5Reader, Hdf5Writer};
+
+#[cfg(feature = "image")]
+pub use array::{ChannelOrder, ImageConversionError};
+
+#[cfg(feature = "mmap")]
+pub use array::{MmapArray, MmapArrayMut, MmapError, MmapStorage, MmapStorageMut};
+
+#[cfg(feature = "nalgebra")]
+pub use array::NalgebraShapeError;
+
+#[cfg(feature = "npz")]
+pub use array::{NpzReader, NpzWriter};
+
+#[cfg(feature = "safetensors")]
+pub use array::{SafeTensorsError, SafeTensorsReader, SafeTensorsWriter, TensorElement};
+
+#[cfg(feature = "simd")]
+pub use array::SimdElement;